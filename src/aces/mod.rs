@@ -0,0 +1,127 @@
+use crate::ColorUnitsIter;
+use crate::converters::{ACES_AP0_PRIMARIES, ACES_AP1_PRIMARIES, ACES_WHITE};
+use crate::transfer::{acescct_to_linear, linear_to_acescct};
+use crate::units::Units;
+
+#[cfg(test)]
+mod tests;
+
+// ACES2065-1 and ACEScg store linear light as is
+fn identity(c: f64) -> f64 {
+  c
+}
+
+/// The ACES2065-1 color space (Academy Color Encoding System).
+///
+/// Scene-linear interchange and archival encoding with AP0 primaries which enclose
+/// the whole visible gamut and the ACES white (~6000K). Values are linear light,
+/// `1.0` is scene diffuse white, so values are not restricted.
+///
+/// Conversion to `Rgb` clips colors which are out of sRGB gamut,
+/// use `in_srgb_gamut` to check it before.
+///
+/// Ranges:
+/// * red: not restricted
+/// * green: not restricted
+/// * blue: not restricted
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{Aces2065, ApproxEq, Rgb};
+///
+/// let aces = Aces2065::from(Rgb::from([255, 255, 255]));
+/// assert!(aces.approx_eq(&Aces2065::new(1.0, 1.0, 1.0, None)));
+///
+/// let highlight = Aces2065::new(4.0, 4.0, 4.0, None);
+/// assert!(!highlight.in_srgb_gamut());
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Aces2065 {
+  pub(crate) units: Units,
+}
+
+rgb_space_base_def!(Aces2065, Unit::new_unbounded, ACES_AP0_PRIMARIES, ACES_WHITE, identity, identity);
+
+/// The ACEScg color space.
+///
+/// Scene-linear working space of rendering and compositing with AP1 primaries and
+/// the ACES white (~6000K). Values are linear light, `1.0` is scene diffuse white,
+/// so values are not restricted.
+///
+/// Conversion to `Rgb` clips colors which are out of sRGB gamut,
+/// use `in_srgb_gamut` to check it before.
+///
+/// Ranges:
+/// * red: not restricted
+/// * green: not restricted
+/// * blue: not restricted
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{AcesCct, AcesCg, ApproxEq, Rgb};
+///
+/// let acescg = AcesCg::from(Rgb::from([255, 0, 0]));
+/// assert!(acescg.approx_eq_clarify(&AcesCg::new(0.6131, 0.0702, 0.0206, None), 0.0001));
+///
+/// let grey = AcesCg::new(0.18, 0.18, 0.18, None);
+/// assert!(AcesCct::from(&grey).approx_eq_clarify(&AcesCct::new(0.4136, 0.4136, 0.4136, None), 0.0001));
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AcesCg {
+  pub(crate) units: Units,
+}
+
+rgb_space_base_def!(AcesCg, Unit::new_unbounded, ACES_AP1_PRIMARIES, ACES_WHITE, identity, identity);
+
+/// The ACEScct color space.
+///
+/// Log encoding of ACEScg values (AP1 primaries) with a linear toe, used for color grading.
+/// Linear-light values (the same as of `AcesCg`) can be got with `to_linear`
+/// and `from_linear` methods. Values are not restricted, usual range is `-0.36..1.47`.
+///
+/// Conversion to `Rgb` clips colors which are out of sRGB gamut,
+/// use `in_srgb_gamut` to check it before.
+///
+/// Ranges:
+/// * red: not restricted
+/// * green: not restricted
+/// * blue: not restricted
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{AcesCct, ApproxEq, Rgb};
+///
+/// let black = AcesCct::from(Rgb::from([0, 0, 0]));
+/// assert!(black.approx_eq_clarify(&AcesCct::new(0.0729, 0.0729, 0.0729, None), 0.0001));
+///
+/// let (r, _, _) = AcesCct::new(0.5548, 0.5, 0.5, None).to_linear();
+/// assert!((r - 1.0).abs() < 0.001);
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AcesCct {
+  pub(crate) units: Units,
+}
+
+rgb_space_base_def!(
+  AcesCct,
+  Unit::new_unbounded,
+  ACES_AP1_PRIMARIES,
+  ACES_WHITE,
+  acescct_to_linear,
+  linear_to_acescct
+);
+
+from_for_some!(AcesCg, AcesCct, color, {
+  let (r, g, b) = color.to_linear();
+  AcesCct::from_linear(r, g, b, color.units.alpha.get())
+});
+from_for_some!(AcesCct, AcesCg, color, {
+  let (r, g, b) = color.to_linear();
+  AcesCg::new(r, g, b, color.units.alpha.get())
+});
@@ -0,0 +1,55 @@
+use crate::{Aces2065, AcesCct, AcesCg, ApproxEq, Rgb, WhitePoint, Xyz};
+
+#[test]
+fn aces_from_rgb() {
+  let asserts = [
+    ([255, 0, 0], (0.439_633, 0.089_776, 0.017_541), (0.613_097, 0.070_194, 0.020_616)),
+    ([0, 255, 0], (0.382_989, 0.813_439, 0.111_547), (0.339_523, 0.916_354, 0.109_570)),
+    ([34, 111, 235], (0.215_273, 0.211_146, 0.741_540), (0.103_140, 0.157_963, 0.740_363)),
+    ([255, 255, 255], (1.0, 1.0, 1.0), (1.0, 1.0, 1.0)),
+    ([0, 0, 0], (0.0, 0.0, 0.0), (0.0, 0.0, 0.0)),
+  ];
+  for (rgb_arr, ap0_t, ap1_t) in asserts.iter() {
+    let rgb = Rgb::from(rgb_arr);
+    let aces = Aces2065::from(&rgb);
+    let acescg = AcesCg::from(&rgb);
+    let acescct = AcesCct::from(&rgb);
+    assert!(aces.approx_eq_clarify(&Aces2065::from(ap0_t), 0.000_001));
+    assert!(acescg.approx_eq_clarify(&AcesCg::from(ap1_t), 0.000_001));
+    assert!(acescct.approx_eq(&AcesCct::from(&acescg)));
+    assert!(aces.in_srgb_gamut() && acescg.in_srgb_gamut() && acescct.in_srgb_gamut());
+
+    assert!(Rgb::from(&aces).approx_eq_clarify(&rgb, 0.000_001));
+    assert!(Rgb::from(&acescg).approx_eq_clarify(&rgb, 0.000_001));
+    assert!(Rgb::from(&acescct).approx_eq_clarify(&rgb, 0.000_001));
+  }
+}
+
+#[test]
+fn aces_scene_linear() {
+  // values are not clipped
+  let acescg = AcesCg::new(8.0, -0.05, 0.5, Some(0.5));
+  assert_eq!(acescg.to_linear(), (8.0, -0.05, 0.5));
+  assert!(!acescg.in_srgb_gamut());
+
+  let aces = Aces2065::from(Xyz::from(&acescg));
+  assert!(AcesCg::from(Xyz::from(&aces)).approx_eq(&acescg));
+
+  let acescct = AcesCct::from(&acescg);
+  assert!(AcesCg::from(&acescct).approx_eq(&acescg));
+  assert!(acescct.red() > 0.726 && acescct.green() < 0.072_905);
+
+  let mut acescg = AcesCg::default();
+  acescg.set_green(123.0);
+  assert_eq!(acescg.green(), 123.0);
+}
+
+#[test]
+fn aces_white() {
+  let (x, y, z) = Xyz::from(AcesCg::new(1.0, 1.0, 1.0, None)).into();
+  assert!(Xyz::new(x, y, z, None).approx_eq_clarify(&Xyz::new(0.952_646, 1.0, 1.008_825, None), 0.000_001));
+
+  let white = Xyz::from(Aces2065::new(1.0, 1.0, 1.0, None));
+  assert_eq!(white.white_point(), WhitePoint::Custom(0.321_68, 0.337_67));
+  assert!(Rgb::from(&white).approx_eq(&Rgb::from([255, 255, 255])));
+}
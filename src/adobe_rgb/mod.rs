@@ -0,0 +1,46 @@
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+use crate::{Cmyk, ColorUnitsIter, Rgb, WhitePoint};
+use crate::converters::A98_PRIMARIES;
+use crate::transfer::{a98_to_linear, linear_to_a98};
+use crate::units::Units;
+
+#[cfg(test)]
+mod tests;
+
+/// The Adobe RGB (1998) color space.
+///
+/// Wide-gamut RGB space used in photography and print workflows, with `D65` white and
+/// the `563/256` (~2.2) gamma. Values are gamma-encoded ratios, linear-light values
+/// can be got with `to_linear` and `from_linear` methods.
+///
+/// Conversion to `Rgb` and `Cmyk` clips colors which are out of sRGB gamut,
+/// use `in_srgb_gamut` to check it before.
+///
+/// Ranges:
+/// * red: 0.0 - 1.0
+/// * green: 0.0 - 1.0
+/// * blue: 0.0 - 1.0
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{AdobeRgb, Rgb};
+///
+/// let adobe = AdobeRgb::from(Rgb::from([255, 0, 0]));
+/// assert_eq!(adobe.to_css_string(), "color(a98-rgb 0.8586 0 0)");
+///
+/// let green: AdobeRgb = "color(a98-rgb 0 1 0)".parse().unwrap();
+/// assert!(!green.in_srgb_gamut());
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AdobeRgb {
+  pub(crate) units: Units,
+}
+
+rgb_space_def!(AdobeRgb, "a98-rgb", A98_PRIMARIES, WhitePoint::D65, a98_to_linear, linear_to_a98);
+
+from_for_some!(Cmyk, AdobeRgb, cmyk, { AdobeRgb::from(Rgb::from(cmyk)) });
+from_for_some!(AdobeRgb, Cmyk, color, { Cmyk::from(Rgb::from(color)) });
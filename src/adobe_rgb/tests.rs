@@ -0,0 +1,52 @@
+use crate::{AdobeRgb, ApproxEq, Cmyk, ColorTupleA, ParseError, Rgb, WhitePoint, Xyz};
+
+#[test]
+fn adobe_rgb_from_rgb() {
+  let asserts = [
+    ([255, 0, 0], (0.858_592, 0.0, 0.0)),
+    ([0, 255, 0], (0.564_972, 1.0, 0.234_424)),
+    ([255, 255, 255], (1.0, 1.0, 1.0)),
+    ([0, 0, 0], (0.0, 0.0, 0.0)),
+    ([34, 111, 235], (0.271_223, 0.433_332, 0.905_112)),
+  ];
+  for (rgb_arr, adobe_t) in asserts.iter() {
+    let rgb = Rgb::from(rgb_arr);
+    let adobe = AdobeRgb::from(&rgb);
+    assert!(adobe.approx_eq_clarify(&AdobeRgb::from(adobe_t), 0.000_001));
+    assert!(adobe.in_srgb_gamut());
+    assert!(Rgb::from(&adobe).approx_eq_clarify(&rgb, 0.000_001));
+  }
+}
+
+#[test]
+fn adobe_rgb_conversions() {
+  let green = AdobeRgb::new(0.0, 1.0, 0.0, Some(0.4));
+  assert!(!green.in_srgb_gamut());
+  assert_eq!(Rgb::from(&green).to_css_string(), "rgba(0,255,0,0.4)");
+
+  let white = Xyz::from(AdobeRgb::new(1.0, 1.0, 1.0, None));
+  assert_eq!(white.white_point(), WhitePoint::D65);
+  assert!(white.approx_eq(&Xyz::from(WhitePoint::D65.xyz())));
+
+  let cmyk = Cmyk::new(10.0, 60.0, 0.0, 20.0, None);
+  let adobe = AdobeRgb::from(&cmyk);
+  assert!(Rgb::from(&adobe).approx_eq(&Rgb::from(&cmyk)));
+  let back = Cmyk::from(&adobe);
+  assert!(Rgb::from(&back).approx_eq(&Rgb::from(&cmyk)));
+}
+
+#[test]
+fn from_str_tst() {
+  fn parse_adobe(s: &str) -> Result<AdobeRgb, ParseError> {
+    s.parse::<AdobeRgb>()
+  }
+
+  assert_eq!(
+    Into::<ColorTupleA>::into(parse_adobe("color(a98-rgb 25% 0.5 none / 20%)").unwrap()),
+    (0.25, 0.5, 0.0, 0.2)
+  );
+  assert!(parse_adobe("color(srgb 0.2 0.4 0.6)").is_err());
+  assert!(parse_adobe("color(a98-rgb 0.2 0.4)").is_err());
+
+  assert_eq!(AdobeRgb::new(0.5, 1.0, 0.333_33, None).to_css_string(), "color(a98-rgb 0.5 1 0.3333)");
+}
@@ -1,5 +1,7 @@
 use crate::Rgb;
 
+mod transform;
+
 /// Predefined set of 256 colors to use with ANSI escape sequences,
 /// e.g. in terminal emulators
 ///
@@ -0,0 +1,107 @@
+use crate::{ColorTransform, Rgb, SaturationInSpace};
+
+use super::Ansi256;
+
+// first 16 codes are "system" colors which are usually redefined
+// by terminal color schemes, so they are never picked as a result
+const FIRST_STABLE_CODE: u8 = 16;
+
+fn distance(a: &Rgb, b: &Rgb) -> f64 {
+  let dr = a.red() - b.red();
+  let dg = a.green() - b.green();
+  let db = a.blue() - b.blue();
+  dr * dr + dg * dg + db * db
+}
+
+/// Returns palette entry (from color cube or grayscale ramp) closest to the given color
+fn nearest_ansi256(rgb: &Rgb) -> Ansi256 {
+  let mut result = Ansi256(FIRST_STABLE_CODE);
+  let mut min = f64::MAX;
+  for code in FIRST_STABLE_CODE..=u8::MAX {
+    let d = distance(rgb, &Ansi256(code).as_rgb());
+    if d < min {
+      min = d;
+      result = Ansi256(code);
+    }
+  }
+  result
+}
+
+impl Ansi256 {
+  fn _transform_as_rgb<F: FnOnce(&mut Rgb)>(&mut self, f: F) {
+    let mut rgb = self.as_rgb();
+    f(&mut rgb);
+    *self = nearest_ansi256(&rgb);
+  }
+}
+
+/// Every modification is performed in RGB color model and then the result
+/// is snapped to the nearest color of the palette (codes `16..=255`).
+///
+/// # Example
+/// ```
+/// use colorsys::{Ansi256, ColorTransform};
+///
+/// let mut ansi = Ansi256::new(99);
+/// ansi.invert();
+/// assert_eq!(ansi.code(), 106);
+///
+/// ansi.grayscale_simple();
+/// assert_eq!(ansi.code(), 240);
+/// ```
+impl ColorTransform for Ansi256 {
+  fn lighten(&mut self, amt: f64) {
+    self._transform_as_rgb(|rgb| rgb.lighten(amt));
+  }
+
+  fn saturate(&mut self, sat: SaturationInSpace) {
+    self._transform_as_rgb(|rgb| rgb.saturate(sat));
+  }
+
+  fn adjust_hue(&mut self, hue: f64) {
+    self._transform_as_rgb(|rgb| rgb.adjust_hue(hue));
+  }
+
+  fn grayscale_simple(&mut self) {
+    self._transform_as_rgb(|rgb| rgb.grayscale_simple());
+  }
+
+  fn invert(&mut self) {
+    self._transform_as_rgb(|rgb| rgb.invert());
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::{Ansi256, ColorTransform, Rgb, SaturationInSpace};
+
+  use super::nearest_ansi256;
+
+  #[test]
+  fn nearest_ansi256_test() {
+    for code in 16..=255u8 {
+      let rgb = Ansi256(code).as_rgb();
+      let nearest = nearest_ansi256(&rgb);
+      assert_eq!(nearest.as_rgb(), rgb);
+    }
+    assert_eq!(nearest_ansi256(&Rgb::from([50, 50, 50])).code(), 236);
+    assert_eq!(nearest_ansi256(&Rgb::from([255, 0, 0])).code(), 196);
+  }
+
+  #[test]
+  fn ansi256_transform_test() {
+    let mut ansi = Ansi256::new(196);
+    ansi.adjust_hue(120.0);
+    assert_eq!(ansi.code(), 46);
+
+    ansi.lighten(-25.0);
+    assert_eq!(ansi.code(), 28);
+
+    ansi.saturate(SaturationInSpace::Hsl(-100.0));
+    assert_eq!(ansi.code(), 238);
+
+    let mut black = Ansi256::new(0);
+    black.invert();
+    assert_eq!(black.code(), 231);
+  }
+}
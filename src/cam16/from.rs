@@ -0,0 +1,33 @@
+use crate::{ColorAlpha, Rgb, Xyz};
+use crate::converters::{cam16_to_xyz, xyz_to_cam16};
+use crate::xyz::new_xyz_units;
+
+use super::{new_cam16_units, Cam16, ViewingConditions};
+
+tuples_def!(Cam16, f32);
+tuples_def!(Cam16, f64);
+
+impl Cam16 {
+  /// Creates color from XYZ values seen under given viewing conditions.
+  /// XYZ values are adapted to the white of conditions before
+  pub fn from_xyz_with_conditions(xyz: &Xyz, conditions: ViewingConditions) -> Cam16 {
+    let t = xyz.adapt(conditions.white());
+    let (j, c, h) = xyz_to_cam16(&t.into(), &conditions);
+    let mut units = new_cam16_units(j, c, h);
+    units.alpha.set(xyz.alpha());
+    Cam16::from_units(units, conditions)
+  }
+}
+
+fn to_xyz(cam: &Cam16) -> Xyz {
+  let (x, y, z) = cam16_to_xyz(&cam.into(), &cam.conditions);
+  let mut units = new_xyz_units(x, y, z);
+  units.alpha.set(cam.alpha());
+  Xyz::from_units(units, cam.conditions.white())
+}
+
+from_for_some!(Xyz, Cam16, xyz, { Cam16::from_xyz_with_conditions(xyz, ViewingConditions::default()) });
+from_for_some!(Cam16, Xyz, cam, { to_xyz(cam) });
+
+from_for_some!(Rgb, Cam16, rgb, { Cam16::from(Xyz::from(rgb)) });
+from_for_some!(Cam16, Rgb, cam, { to_xyz(cam).into() });
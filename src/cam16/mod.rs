@@ -0,0 +1,167 @@
+pub use viewing_conditions::{Surround, ViewingConditions};
+
+use crate::ColorUnitsIter;
+use crate::common::f64_sqrt;
+use crate::consts::ALL_MIN;
+use crate::units::{Alpha, GetColorUnits, Unit, Units};
+
+#[cfg(test)]
+mod tests;
+
+mod from;
+pub(crate) mod viewing_conditions;
+
+/// The CAM16 color appearance model.
+///
+/// Describes how a color looks under given `ViewingConditions` (reference white,
+/// luminance of adapting field and background, surround). Stores lightness `J`,
+/// chroma `C` and hue `h`, other correlates (colorfulness `M`, saturation `s`,
+/// brightness `Q`) are derived from them. `Cam16::new` and conversions from other
+/// models use the default conditions, for others see `new_with_conditions`,
+/// `from_xyz_with_conditions` and `to_viewing_conditions`.
+///
+/// Ranges:
+/// * lightness: 0.0 - not restricted, 100.0 is reference white
+/// * chroma: 0.0 - not restricted, usually up to 115.0 for colors of sRGB
+/// * hue: 0.0 - 360.0
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{Cam16, Rgb, Surround, ViewingConditions, WhitePoint};
+///
+/// let red = Cam16::from(Rgb::from([255, 0, 0]));
+/// assert_eq!(red.lightness().round(), 46.0);
+/// assert_eq!(red.chroma().round(), 113.0);
+/// assert_eq!(red.hue().round(), 27.0);
+///
+/// // the same red seen on a bright screen in a dark room looks brighter and more colorful
+/// let dark = ViewingConditions::new(WhitePoint::D65, 300.0, 20.0, Surround::Dark, false);
+/// let red_in_dark = red.to_viewing_conditions(dark);
+/// assert!(red_in_dark.brightness() > red.brightness());
+/// assert!(red_in_dark.colorfulness() > red.colorfulness());
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cam16 {
+  pub(crate) units: Units,
+  pub(crate) conditions: ViewingConditions,
+}
+
+iter_def!(Cam16);
+
+pub(crate) fn new_cam16_units(j: f64, c: f64, h: f64) -> Units {
+  let ul = [
+    Unit::new_unbounded(j.max(ALL_MIN)),
+    Unit::new_unbounded(c.max(ALL_MIN)),
+    Unit::new_hue(h),
+    Unit::default(),
+  ];
+  Units { len: 3, list: ul, alpha: Alpha::default() }
+}
+
+impl Cam16 {
+  /// Creates color seen under the default viewing conditions
+  pub fn new(j: f64, c: f64, h: f64, a: Option<f64>) -> Cam16 {
+    Cam16::new_with_conditions(j, c, h, a, ViewingConditions::default())
+  }
+
+  pub fn new_with_conditions(
+    j: f64,
+    c: f64,
+    h: f64,
+    a: Option<f64>,
+    conditions: ViewingConditions,
+  ) -> Cam16 {
+    let mut units = new_cam16_units(j, c, h);
+    units.alpha.set_opt(a);
+    units.restrict();
+    Cam16 { units, conditions }
+  }
+
+  pub(crate) fn from_units(units: Units, conditions: ViewingConditions) -> Self {
+    Cam16 { units, conditions }
+  }
+
+  /// Lightness `J`
+  pub fn lightness(&self) -> f64 { self.units[0] }
+  /// Chroma `C`
+  pub fn chroma(&self) -> f64 { self.units[1] }
+  /// Hue angle `h` in degrees
+  pub fn hue(&self) -> f64 { self.units[2] }
+
+  pub fn set_lightness(&mut self, val: f64) { self.units.list[0].set(val.max(ALL_MIN)); }
+  pub fn set_chroma(&mut self, val: f64) { self.units.list[1].set(val.max(ALL_MIN)); }
+  pub fn set_hue(&mut self, val: f64) { self.units.list[2].set(val); }
+
+  /// Colorfulness `M`
+  pub fn colorfulness(&self) -> f64 {
+    self.chroma() * self.conditions.fl_root
+  }
+
+  /// Brightness `Q`
+  pub fn brightness(&self) -> f64 {
+    let vc = &self.conditions;
+    4.0 / vc.c * f64_sqrt(self.lightness() / 100.0) * (vc.aw + 4.0) * vc.fl_root
+  }
+
+  /// Saturation `s`
+  pub fn saturation(&self) -> f64 {
+    let q = self.brightness();
+    if q == 0.0 {
+      return 0.0;
+    }
+    100.0 * f64_sqrt(self.colorfulness() / q)
+  }
+
+  /// Returns viewing conditions under which the color is seen
+  pub fn viewing_conditions(&self) -> ViewingConditions {
+    self.conditions
+  }
+
+  /// Returns corresponding color: appearance of the same stimulus (XYZ values)
+  /// under other viewing conditions
+  pub fn to_viewing_conditions(&self, conditions: ViewingConditions) -> Cam16 {
+    Cam16::from_xyz_with_conditions(&self.into(), conditions)
+  }
+
+  /// Returns an iterator over three color units and the possibly alpha value.
+  pub fn iter(&self) -> ColorUnitsIter {
+    ColorUnitsIter::from_units(&self.units)
+  }
+}
+
+//
+//
+//
+// Default
+//
+impl Default for Cam16 {
+  fn default() -> Cam16 {
+    Cam16::new(0.0, 0.0, 0.0, None)
+  }
+}
+
+//
+//
+//
+// AsRef<Cam16>
+//
+impl AsRef<Cam16> for Cam16 {
+  fn as_ref(&self) -> &Cam16 {
+    self
+  }
+}
+
+impl GetColorUnits for Cam16 {
+  fn get_units(&self) -> &Units {
+    &self.units
+  }
+  fn get_units_mut(&mut self) -> &mut Units {
+    &mut self.units
+  }
+
+  fn same_space(&self, other: &Cam16) -> bool {
+    self.conditions == other.conditions
+  }
+}
@@ -0,0 +1,66 @@
+use crate::common::approx::approx;
+use crate::{ApproxEq, Cam16, ColorAlpha, Rgb, Surround, ViewingConditions, WhitePoint, Xyz};
+
+#[test]
+fn cam16_from_rgb() {
+  // J, C, h, M, s, Q of Material Design color utilities,
+  // which use slightly different D65 white
+  let asserts = [
+    ([255, 0, 0], [46.445, 113.357, 27.408, 89.494, 91.889, 105.988]),
+    ([0, 0, 255], [25.465, 87.230, 282.788, 68.867, 93.675, 78.481]),
+    ([255, 255, 255], [100.0, 2.869, 209.492, 2.265, 12.068, 155.521]),
+    ([0, 0, 0], [0.0, 0.0, 0.0, 0.0, 0.0, 0.0]),
+  ];
+  for (rgb_arr, [j, c, h, m, s, q]) in asserts.iter() {
+    let rgb = Rgb::from(rgb_arr);
+    let cam = Cam16::from(&rgb);
+    assert!(approx(cam.lightness(), *j, 0.01));
+    assert!(approx(cam.chroma(), *c, 0.05));
+    assert!(approx(cam.hue(), *h, 0.1));
+    assert!(approx(cam.colorfulness(), *m, 0.05));
+    assert!(approx(cam.saturation(), *s, 0.05));
+    assert!(approx(cam.brightness(), *q, 0.05));
+    assert!(Rgb::from(&cam).approx_eq_clarify(&rgb, 0.000_001));
+  }
+}
+
+#[test]
+fn cam16_viewing_conditions() {
+  let rgb = Rgb::new(34.0, 111.0, 235.0, Some(0.5));
+  let cam = Cam16::from(&rgb);
+  assert_eq!(cam.alpha(), 0.5);
+  assert_eq!(cam.viewing_conditions(), ViewingConditions::default());
+
+  let dark = ViewingConditions::new(WhitePoint::D65, 300.0, 20.0, Surround::Dark, false);
+  let cam_dark = Cam16::from_xyz_with_conditions(&Xyz::from(&rgb), dark);
+  assert_eq!(cam_dark.viewing_conditions(), dark);
+  assert!(cam_dark.approx_eq(&cam.to_viewing_conditions(dark)));
+  let (j, c, h) = (cam_dark.lightness(), cam_dark.chroma(), cam_dark.hue());
+  assert!(!cam_dark.approx_eq(&Cam16::new(j, c, h, Some(0.5))));
+  // same stimulus
+  assert!(Rgb::from(&cam_dark).approx_eq_clarify(&rgb, 0.000_001));
+  assert!(cam_dark.to_viewing_conditions(ViewingConditions::default()).approx_eq(&cam));
+
+  // white of conditions is always fully light, discounting removes its chroma
+  let d50 = ViewingConditions::new(WhitePoint::D50, 64.0, 20.0, Surround::Average, true);
+  let white = Cam16::from_xyz_with_conditions(&Xyz::from(&Rgb::from([255, 255, 255])), d50);
+  assert!(approx(white.lightness(), 100.0, 1e-9));
+  assert!(white.chroma() < 1e-9);
+  assert_eq!(Xyz::from(&white).white_point(), WhitePoint::D50);
+}
+
+#[test]
+fn cam16_values() {
+  let mut cam = Cam16::new(50.0, -10.0, 400.0, None);
+  assert_eq!((cam.lightness(), cam.chroma(), cam.hue()), (50.0, 0.0, 360.0));
+  cam.set_lightness(-1.0);
+  cam.set_chroma(20.0);
+  cam.set_hue(120.0);
+  assert_eq!((cam.lightness(), cam.chroma(), cam.hue()), (0.0, 20.0, 120.0));
+  assert_eq!((cam.brightness(), cam.saturation()), (0.0, 0.0));
+
+  // zero chroma is neutral only if the illuminant is discounted
+  let vc = ViewingConditions::new(WhitePoint::D65, 64.0, 20.0, Surround::Average, true);
+  let rgb = Rgb::from(Cam16::new_with_conditions(60.0, 0.0, 0.0, None, vc));
+  assert!(approx(rgb.red(), rgb.green(), 1e-6) && approx(rgb.green(), rgb.blue(), 1e-6));
+}
@@ -0,0 +1,173 @@
+use crate::{ColorTuple, WhitePoint};
+use crate::common::matrix::{mat3_mul_tuple, Mat3};
+use crate::common::{f64_abs, f64_exp, f64_powf, f64_sqrt};
+
+/// XYZ to cone responses matrix of CAM16 (CAT16)
+pub(crate) static M16: Mat3 = [
+  [0.401_288, 0.650_173, -0.051_461],
+  [-0.250_268, 1.204_414, 0.045_854],
+  [-0.002_079, 0.048_952, 0.953_127],
+];
+
+/// Inverse of `M16`
+pub(crate) static M16_INV: Mat3 = [
+  [1.862_067_855_087_232_7, -1.011_254_630_531_684_3, 0.149_186_775_444_451_72],
+  [0.387_526_543_236_137_17, 0.621_447_441_931_475_4, -0.008_973_985_167_612_518],
+  [-0.015_841_498_849_333_856, -0.034_122_938_028_515_56, 1.049_964_436_877_849_3],
+];
+
+/// Relative luminance of surround of the viewing field.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Surround {
+  /// Viewing film projected in a dark room
+  Dark,
+  /// Viewing television or monitor in a dim room
+  Dim,
+  /// Viewing surface colors or monitor in an office
+  #[default]
+  Average,
+}
+
+impl Surround {
+  // factor of degree of adaptation `F`, impact of surround `c`, chromatic induction factor `Nc`
+  fn factors(&self) -> ColorTuple {
+    match *self {
+      Surround::Dark => (0.8, 0.525, 0.8),
+      Surround::Dim => (0.9, 0.59, 0.9),
+      Surround::Average => (1.0, 0.69, 1.0),
+    }
+  }
+}
+
+/// Viewing conditions of CAM16 color appearance model.
+///
+/// Default conditions are the ones of Material Design color utilities:
+/// `D65` white, adapting luminance ~11.73 cd/m² (gray world of 200 lux),
+/// background of L\* 50, average surround, no discounting of the illuminant.
+///
+/// # Example
+/// ```
+/// use colorsys::{Surround, ViewingConditions, WhitePoint};
+///
+/// // watching a monitor in a dim room
+/// let dim = ViewingConditions::new(WhitePoint::D65, 16.0, 20.0, Surround::Dim, false);
+/// assert_eq!(dim.surround(), Surround::Dim);
+/// assert_eq!(ViewingConditions::default().white(), WhitePoint::D65);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ViewingConditions {
+  white: WhitePoint,
+  adapting_luminance: f64,
+  background_luminance: f64,
+  surround: Surround,
+  discounting: bool,
+
+  pub(crate) n: f64,
+  pub(crate) aw: f64,
+  pub(crate) nbb: f64,
+  pub(crate) ncb: f64,
+  pub(crate) c: f64,
+  pub(crate) nc: f64,
+  pub(crate) z: f64,
+  pub(crate) fl: f64,
+  pub(crate) fl_root: f64,
+  pub(crate) rgb_d: ColorTuple,
+}
+
+/// Post-adaptation non-linear compression of a cone response
+pub(crate) fn adapt_response(v: f64, fl: f64) -> f64 {
+  let f = f64_powf(fl * f64_abs(v) / 100.0, 0.42);
+  let r = 400.0 * f / (f + 27.13);
+  if v < 0.0 { -r } else { r }
+}
+
+/// Inverse of `adapt_response`
+pub(crate) fn unadapt_response(v: f64, fl: f64) -> f64 {
+  let abs = f64_abs(v);
+  let base = (27.13 * abs / (400.0 - abs)).max(0.0);
+  let r = 100.0 / fl * f64_powf(base, 1.0 / 0.42);
+  if v < 0.0 { -r } else { r }
+}
+
+impl ViewingConditions {
+  /// Creates viewing conditions.
+  /// * `white` - reference white, colors are adapted to it
+  /// * `adapting_luminance` - luminance of adapting field in cd/m², usually 20% of white luminance
+  /// * `background_luminance` - relative luminance of background, `0.0..100.0`
+  /// * `surround` - relative luminance of surround
+  /// * `discounting` - whether the illuminant is fully discounted (adaptation is complete)
+  pub fn new(
+    white: WhitePoint,
+    adapting_luminance: f64,
+    background_luminance: f64,
+    surround: Surround,
+    discounting: bool,
+  ) -> ViewingConditions {
+    let la = adapting_luminance.max(0.0);
+    // zero background makes `n` zero, which is a singularity of the model
+    let yb = background_luminance.clamp(0.1, 100.0);
+    let (f, c, nc) = surround.factors();
+
+    let (xw, yw, zw) = white.xyz();
+    let rgb_w = mat3_mul_tuple(&M16, &(xw * 100.0, yw * 100.0, zw * 100.0));
+
+    let d = if discounting {
+      1.0
+    } else {
+      (f * (1.0 - (1.0 / 3.6) * f64_exp((-la - 42.0) / 92.0))).clamp(0.0, 1.0)
+    };
+    let rgb_d = (
+      d * 100.0 / rgb_w.0 + 1.0 - d,
+      d * 100.0 / rgb_w.1 + 1.0 - d,
+      d * 100.0 / rgb_w.2 + 1.0 - d,
+    );
+
+    let k = 1.0 / (5.0 * la + 1.0);
+    let k4 = k * k * k * k;
+    let fl = k4 * la + 0.1 * (1.0 - k4) * (1.0 - k4) * f64_powf(5.0 * la, 1.0 / 3.0);
+
+    let n = yb / 100.0;
+    let z = 1.48 + f64_sqrt(n);
+    let nbb = 0.725 / f64_powf(n, 0.2);
+
+    let ra = adapt_response(rgb_d.0 * rgb_w.0, fl);
+    let ga = adapt_response(rgb_d.1 * rgb_w.1, fl);
+    let ba = adapt_response(rgb_d.2 * rgb_w.2, fl);
+    let aw = (2.0 * ra + ga + 0.05 * ba) * nbb;
+
+    ViewingConditions {
+      white,
+      adapting_luminance: la,
+      background_luminance: yb,
+      surround,
+      discounting,
+      n,
+      aw,
+      nbb,
+      ncb: nbb,
+      c,
+      nc,
+      z,
+      fl,
+      fl_root: f64_powf(fl, 0.25),
+      rgb_d,
+    }
+  }
+
+  pub fn white(&self) -> WhitePoint { self.white }
+  pub fn adapting_luminance(&self) -> f64 { self.adapting_luminance }
+  pub fn background_luminance(&self) -> f64 { self.background_luminance }
+  pub fn surround(&self) -> Surround { self.surround }
+  pub fn discounting(&self) -> bool { self.discounting }
+}
+
+impl Default for ViewingConditions {
+  fn default() -> ViewingConditions {
+    // Y of L* 50
+    let background = 18.418_651_851_244_416;
+    let adapting = 200.0 / core::f64::consts::PI * background / 100.0;
+    ViewingConditions::new(WhitePoint::D65, adapting, background, Surround::Average, false)
+  }
+}
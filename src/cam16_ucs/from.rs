@@ -0,0 +1,31 @@
+use crate::{Cam16, ColorAlpha, Rgb, Xyz};
+use crate::cam16::new_cam16_units;
+use crate::converters::{cam16_to_ucs, ucs_to_cam16};
+
+use super::{new_cam16_ucs_units, Cam16Ucs};
+
+tuples_def!(Cam16Ucs, f32);
+tuples_def!(Cam16Ucs, f64);
+
+fn from_cam16(cam: &Cam16) -> Cam16Ucs {
+  let (j, a, b) = cam16_to_ucs(&cam.into(), &cam.conditions);
+  let mut units = new_cam16_ucs_units(j, a, b);
+  units.alpha.set(cam.alpha());
+  Cam16Ucs::from_units(units, cam.conditions)
+}
+
+fn to_cam16(ucs: &Cam16Ucs) -> Cam16 {
+  let (j, c, h) = ucs_to_cam16(&ucs.into(), &ucs.conditions);
+  let mut units = new_cam16_units(j, c, h);
+  units.alpha.set(ucs.alpha());
+  Cam16::from_units(units, ucs.conditions)
+}
+
+from_for_some!(Cam16, Cam16Ucs, cam, { from_cam16(cam) });
+from_for_some!(Cam16Ucs, Cam16, ucs, { to_cam16(ucs) });
+
+from_for_some!(Xyz, Cam16Ucs, xyz, { from_cam16(&Cam16::from(xyz)) });
+from_for_some!(Cam16Ucs, Xyz, ucs, { Xyz::from(to_cam16(ucs)) });
+
+from_for_some!(Rgb, Cam16Ucs, rgb, { from_cam16(&Cam16::from(rgb)) });
+from_for_some!(Cam16Ucs, Rgb, ucs, { Rgb::from(to_cam16(ucs)) });
@@ -0,0 +1,133 @@
+use crate::{ColorUnitsIter, ViewingConditions};
+use crate::common::f64_sqrt;
+use crate::units::{Alpha, GetColorUnits, Unit, Units};
+
+#[cfg(test)]
+mod tests;
+
+mod from;
+
+/// The CAM16-UCS uniform color space.
+///
+/// Rectangular form of `Cam16` with lightness `J'` and colorfulness `M'` rescaled so that
+/// euclidean distance between colors (see `delta_e`) corresponds to perceived difference.
+/// Like `Cam16` values depend on viewing conditions.
+///
+/// Ranges:
+/// * lightness: 0.0 - not restricted, 100.0 is reference white
+/// * a, b: not restricted, usually -50.0 - 50.0
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{Cam16Ucs, Rgb};
+///
+/// let blue = Cam16Ucs::from(Rgb::from([34, 111, 235]));
+/// let similar = Cam16Ucs::from(Rgb::from([40, 115, 235]));
+/// let red = Cam16Ucs::from(Rgb::from([235, 34, 34]));
+///
+/// assert!(blue.delta_e(&similar) < 2.0);
+/// assert!(blue.delta_e(&red) > 40.0);
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cam16Ucs {
+  pub(crate) units: Units,
+  pub(crate) conditions: ViewingConditions,
+}
+
+iter_def!(Cam16Ucs);
+
+pub(crate) fn new_cam16_ucs_units(j: f64, a: f64, b: f64) -> Units {
+  let u = Unit::new_unbounded;
+  let ul = [u(j.max(0.0)), u(a), u(b), Unit::default()];
+  Units { len: 3, list: ul, alpha: Alpha::default() }
+}
+
+impl Cam16Ucs {
+  /// Creates color seen under the default viewing conditions
+  pub fn new(j: f64, a: f64, b: f64, alpha: Option<f64>) -> Cam16Ucs {
+    Cam16Ucs::new_with_conditions(j, a, b, alpha, ViewingConditions::default())
+  }
+
+  pub fn new_with_conditions(
+    j: f64,
+    a: f64,
+    b: f64,
+    alpha: Option<f64>,
+    conditions: ViewingConditions,
+  ) -> Cam16Ucs {
+    let mut units = new_cam16_ucs_units(j, a, b);
+    units.alpha.set_opt(alpha);
+    Cam16Ucs { units, conditions }
+  }
+
+  pub(crate) fn from_units(units: Units, conditions: ViewingConditions) -> Self {
+    Cam16Ucs { units, conditions }
+  }
+
+  /// Lightness `J'`
+  pub fn lightness(&self) -> f64 { self.units[0] }
+  pub fn a(&self) -> f64 { self.units[1] }
+  pub fn b(&self) -> f64 { self.units[2] }
+
+  pub fn set_lightness(&mut self, val: f64) { self.units.list[0].set(val.max(0.0)); }
+  pub fn set_a(&mut self, val: f64) { self.units.list[1].set(val); }
+  pub fn set_b(&mut self, val: f64) { self.units.list[2].set(val); }
+
+  /// Returns viewing conditions under which the color is seen
+  pub fn viewing_conditions(&self) -> ViewingConditions {
+    self.conditions
+  }
+
+  /// Color difference ΔE: euclidean distance in CAM16-UCS.
+  /// Both colors must be seen under the same viewing conditions (checked in
+  /// debug builds), use `Cam16::to_viewing_conditions` to convert one of them
+  pub fn delta_e(&self, other: &Cam16Ucs) -> f64 {
+    debug_assert!(self.same_space(other), "different viewing conditions");
+    let dj = self.lightness() - other.lightness();
+    let da = self.a() - other.a();
+    let db = self.b() - other.b();
+    f64_sqrt(dj * dj + da * da + db * db)
+  }
+
+  /// Returns an iterator over three color units and the possibly alpha value.
+  pub fn iter(&self) -> ColorUnitsIter {
+    ColorUnitsIter::from_units(&self.units)
+  }
+}
+
+//
+//
+//
+// Default
+//
+impl Default for Cam16Ucs {
+  fn default() -> Cam16Ucs {
+    Cam16Ucs::new(0.0, 0.0, 0.0, None)
+  }
+}
+
+//
+//
+//
+// AsRef<Cam16Ucs>
+//
+impl AsRef<Cam16Ucs> for Cam16Ucs {
+  fn as_ref(&self) -> &Cam16Ucs {
+    self
+  }
+}
+
+impl GetColorUnits for Cam16Ucs {
+  fn get_units(&self) -> &Units {
+    &self.units
+  }
+  fn get_units_mut(&mut self) -> &mut Units {
+    &mut self.units
+  }
+
+  fn same_space(&self, other: &Cam16Ucs) -> bool {
+    self.conditions == other.conditions
+  }
+}
@@ -0,0 +1,71 @@
+use crate::common::approx::approx;
+use crate::{ApproxEq, Cam16, Cam16Ucs, ColorAlpha, Rgb, Surround, ViewingConditions, WhitePoint, Xyz};
+
+#[test]
+fn cam16_ucs_from_rgb() {
+  let asserts = [
+    [255, 0, 0],
+    [0, 255, 0],
+    [0, 0, 255],
+    [34, 111, 235],
+    [255, 255, 255],
+    [0, 0, 0],
+  ];
+  for rgb_arr in asserts.iter() {
+    let rgb = Rgb::from(rgb_arr);
+    let ucs = Cam16Ucs::from(&rgb);
+    assert!(Rgb::from(&ucs).approx_eq_clarify(&rgb, 0.000_001));
+
+    let cam = Cam16::from(&rgb);
+    let j = cam.lightness();
+    assert!(approx(ucs.lightness(), 1.7 * j / (1.0 + 0.007 * j), 1e-9));
+    assert!(Cam16::from(&ucs).approx_eq(&cam));
+  }
+
+  let red = Cam16Ucs::from(Rgb::from([255, 0, 0]));
+  assert!(red.approx_eq_clarify(&Cam16Ucs::new(59.585, 43.298, 22.451, None), 0.05));
+}
+
+#[test]
+fn cam16_ucs_delta_e() {
+  let blue = Cam16Ucs::from(Rgb::from([34, 111, 235]));
+  assert_eq!(blue.delta_e(&blue), 0.0);
+
+  let white = Cam16Ucs::from(Rgb::from([255, 255, 255]));
+  let black = Cam16Ucs::from(Rgb::from([0, 0, 0]));
+  assert!(approx(white.delta_e(&black), black.delta_e(&white), 1e-12));
+  assert!(white.delta_e(&black) > 100.0);
+
+  let similar = Cam16Ucs::from(Rgb::from([40, 115, 235]));
+  assert!(blue.delta_e(&similar) < 2.0);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn cam16_ucs_delta_e_conditions() {
+  let dim =
+    ViewingConditions::new(WhitePoint::D65, 16.0, 20.0, Surround::Dim, false);
+  let cam = Cam16::from(Rgb::from([34, 111, 235]));
+  let blue = Cam16Ucs::from(&cam);
+  blue.delta_e(&Cam16Ucs::from(cam.to_viewing_conditions(dim)));
+}
+
+#[test]
+fn cam16_ucs_conversions() {
+  let dim = ViewingConditions::new(WhitePoint::D65, 16.0, 20.0, Surround::Dim, false);
+  let xyz = Xyz::new(0.3, 0.2, 0.5, Some(0.3));
+  let cam = Cam16::from_xyz_with_conditions(&xyz, dim);
+  let ucs = Cam16Ucs::from(&cam);
+  assert_eq!(ucs.viewing_conditions(), dim);
+  assert_eq!(ucs.alpha(), 0.3);
+  assert!(Xyz::from(&ucs).approx_eq(&xyz));
+  assert!(!ucs.approx_eq(&Cam16Ucs::new(ucs.lightness(), ucs.a(), ucs.b(), Some(0.3))));
+
+  let mut ucs = Cam16Ucs::new(-5.0, 1.0, -2.0, None);
+  assert_eq!(ucs.lightness(), 0.0);
+  ucs.set_lightness(50.0);
+  ucs.set_a(0.0);
+  ucs.set_b(0.0);
+  assert_eq!(Cam16::from(&ucs).chroma(), 0.0);
+}
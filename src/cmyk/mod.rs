@@ -8,6 +8,7 @@ use crate::units::{Alpha, GetColorUnits, Unit, Units};
 
 mod ratio;
 mod from;
+mod transform;
 
 
 /// The CMYK color model.
@@ -0,0 +1,113 @@
+use crate::{ColorTransform, Rgb, SaturationInSpace};
+use crate::consts::{ALL_MIN, PERCENT_MAX, RGB_UNIT_MAX};
+
+use super::Cmyk;
+
+impl Cmyk {
+  fn _transform_as_rgb<F: FnOnce(&mut Rgb)>(&mut self, f: F) {
+    let mut rgb = self.as_rgb();
+    f(&mut rgb);
+    *self = Cmyk::from(rgb);
+  }
+}
+
+/// All modifications except `grayscale_simple` are performed in RGB color
+/// model. The result is separated into inks again, so the gray component
+/// of a color always goes to the key (black) channel.
+impl ColorTransform for Cmyk {
+  fn lighten(&mut self, amt: f64) {
+    self._transform_as_rgb(|rgb| rgb.lighten(amt));
+  }
+
+  fn saturate(&mut self, sat: SaturationInSpace) {
+    self._transform_as_rgb(|rgb| rgb.saturate(sat));
+  }
+
+  fn adjust_hue(&mut self, hue: f64) {
+    self._transform_as_rgb(|rgb| rgb.adjust_hue(hue));
+  }
+
+  /// Removes all color inks and puts whole color into the key (black) channel.
+  /// # Example
+  /// ```
+  /// use colorsys::{Cmyk, ColorTransform};
+  ///
+  /// let mut cmyk = Cmyk::new(60.0, 0.0, 20.0, 10.0, None);
+  /// cmyk.grayscale_simple();
+  /// let units: [f64; 4] = cmyk.into();
+  /// assert_eq!(units, [0.0, 0.0, 0.0, 37.0]);
+  /// ```
+  fn grayscale_simple(&mut self) {
+    let mut rgb = self.as_rgb();
+    rgb.grayscale_simple();
+    let k = PERCENT_MAX - rgb.red() / RGB_UNIT_MAX * PERCENT_MAX;
+    for i in 0..3 {
+      self.units.list[i].value = ALL_MIN;
+    }
+    self.units.list[3].set(k);
+  }
+
+  /// Inverts the printed color, not the every single ink.
+  /// For example inverted pure black (`0,0,0,100`) is paper white (`0,0,0,0`)
+  /// instead of a four-ink black (`100,100,100,0`).
+  /// # Example
+  /// ```
+  /// use colorsys::{Cmyk, ColorTransform};
+  ///
+  /// let mut cmyk = Cmyk::new(0.0, 0.0, 0.0, 100.0, None);
+  /// cmyk.invert();
+  /// let units: [f64; 4] = cmyk.into();
+  /// assert_eq!(units, [0.0, 0.0, 0.0, 0.0]);
+  /// ```
+  fn invert(&mut self) {
+    self._transform_as_rgb(|rgb| rgb.invert());
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::{ApproxEq, Cmyk, ColorAlpha, ColorTransform, Rgb, SaturationInSpace};
+
+  #[test]
+  fn cmyk_transform_like_rgb_test() {
+    let origin = Cmyk::new(70.0, 23.0, 11.0, 55.0, Some(0.5));
+    let mut cmyk = origin.clone();
+    let mut rgb = origin.as_rgb();
+
+    cmyk.lighten(12.0);
+    rgb.lighten(12.0);
+    assert!(cmyk.as_rgb().approx_eq_clarify(&rgb, 0.000_001));
+
+    cmyk.saturate(SaturationInSpace::Hsl(-30.0));
+    rgb.saturate(SaturationInSpace::Hsl(-30.0));
+    assert!(cmyk.as_rgb().approx_eq_clarify(&rgb, 0.000_001));
+
+    cmyk.adjust_hue(97.0);
+    rgb.adjust_hue(97.0);
+    assert!(cmyk.as_rgb().approx_eq_clarify(&rgb, 0.000_001));
+    assert_eq!(cmyk.alpha(), 0.5);
+  }
+
+  #[test]
+  fn cmyk_invert_test() {
+    let mut white = Cmyk::new(0.0, 0.0, 0.0, 0.0, None);
+    white.invert();
+    assert_eq!(white, Cmyk::new(0.0, 0.0, 0.0, 100.0, None));
+
+    let mut cmyk = Cmyk::new(0.0, 100.0, 100.0, 0.0, None);
+    cmyk.invert();
+    assert!(cmyk.as_rgb().approx_eq(&Rgb::from((0.0, 255.0, 255.0))));
+    assert!(cmyk.approx_eq(&Cmyk::new(100.0, 0.0, 0.0, 0.0, None)));
+  }
+
+  #[test]
+  fn cmyk_grayscale_test() {
+    let mut cmyk = Cmyk::new(0.0, 0.0, 0.0, 0.0, Some(0.3));
+    cmyk.grayscale_simple();
+    assert_eq!(cmyk, Cmyk::new(0.0, 0.0, 0.0, 0.0, Some(0.3)));
+
+    let mut cmyk = Cmyk::new(100.0, 0.0, 0.0, 0.0, None);
+    cmyk.grayscale_simple();
+    assert!(cmyk.approx_eq(&Cmyk::new(0.0, 0.0, 0.0, 50.0, None)));
+  }
+}
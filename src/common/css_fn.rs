@@ -0,0 +1,196 @@
+//! Parsing and serializing of modern (CSS Color 4) functional notation
+//! like `hwb(194 0% 0% / 0.5)`: space-separated components and optional
+//! alpha value after a slash. Legacy comma-separated syntax is supported too.
+#[cfg(not(feature = "std"))]
+use alloc::string::{String, ToString};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::common::{f64_abs, f64_powf, f64_round};
+use crate::consts::{HUE_MAX, PERCENT_MAX, RATIO_MAX};
+use crate::normalize::{bound_hue, round_ratio};
+
+const NONE: &str = "none";
+
+/// Splits string like `name(x y z / a)` into components and alpha.
+/// Returns `None` if string is not a `name(..)` function with `len` components.
+pub fn css_fn_from_str(
+  s: &str,
+  names: &[&str],
+  len: usize,
+) -> Option<(Vec<String>, Option<String>)> {
+  let s = s.trim().to_lowercase();
+  let open = s.find('(')?;
+  let name = s[..open].trim();
+  if !names.contains(&name) || !s.ends_with(')') {
+    return None;
+  }
+
+  let body = s[open + 1..s.len() - 1].replace(',', " ");
+  let (values, alpha) = match body.split_once('/') {
+    Some((v, a)) => (v, Some(a.trim().to_string())),
+    None => (body.as_str(), None),
+  };
+
+  let mut components: Vec<String> =
+    values.split_whitespace().map(String::from).collect();
+
+  // legacy syntax: `name(x, y, z, a)`
+  let mut alpha = alpha;
+  if alpha.is_none() && components.len() == len + 1 {
+    alpha = components.pop();
+  }
+  if components.len() != len || alpha.as_deref() == Some("") {
+    return None;
+  }
+  Some((components, alpha))
+}
+
+/// Splits string like `color(space x y z / a)` into components and alpha.
+/// Returns `None` if string is not a `color()` function with one of `spaces` and `len` components.
+pub fn css_color_fn_from_str(
+  s: &str,
+  spaces: &[&str],
+  len: usize,
+) -> Option<(Vec<String>, Option<String>)> {
+  let (mut components, alpha) = css_fn_from_str(s, &["color"], len + 1)?;
+  if !spaces.contains(&components[0].as_str()) {
+    return None;
+  }
+  components.remove(0);
+  Some((components, alpha))
+}
+
+/// Parses number or percentage. `percent_ref` is the value which corresponds to `100%`.
+/// Keyword `none` is treated as zero.
+pub fn css_number(s: &str, percent_ref: f64) -> Option<f64> {
+  if s == NONE {
+    return Some(0.0);
+  }
+  if let Some(p) = s.strip_suffix('%') {
+    return p.parse::<f64>().ok().map(|n| n / PERCENT_MAX * percent_ref);
+  }
+  s.parse::<f64>().ok()
+}
+
+/// Parses hue which can be a number (degrees) or an angle with `deg`, `grad`, `rad` or `turn`
+/// unit. Returned value is bounded to `0.0..360.0`.
+pub fn css_hue(s: &str) -> Option<f64> {
+  if s == NONE {
+    return Some(0.0);
+  }
+  let units: [(&str, f64); 4] = [
+    ("deg", 1.0),
+    ("grad", HUE_MAX / 400.0),
+    ("rad", HUE_MAX / (2.0 * core::f64::consts::PI)),
+    ("turn", HUE_MAX),
+  ];
+  let mut num = s;
+  let mut mul = 1.0;
+  for (unit, m) in units.iter() {
+    if let Some(n) = s.strip_suffix(unit) {
+      num = n;
+      mul = *m;
+      break;
+    }
+  }
+  num.parse::<f64>().ok().map(|n| bound_hue(n * mul))
+}
+
+/// Parses alpha value: number from `0.0` to `1.0` or percentage
+pub fn css_alpha(s: &str) -> Option<f64> {
+  let a = css_number(s, RATIO_MAX)?;
+  if !(0.0..=RATIO_MAX).contains(&a) {
+    return None;
+  }
+  Some(a)
+}
+
+/// Formats number with at most `precision` digits after the decimal point
+pub fn css_num_to_string(n: f64, precision: i32) -> String {
+  let m = f64_powf(10.0, precision as f64);
+  // `+ 0.0` turns negative zero into positive one
+  format!("{}", f64_round(n * m) / m + 0.0)
+}
+
+/// Makes string like `color(space x y z)` or `color(space x y z / a)`
+pub fn css_color_fn_to_string(space: &str, components: &[String], alpha: f64) -> String {
+  let mut with_space = vec![String::from(space)];
+  with_space.extend_from_slice(components);
+  css_fn_to_string("color", &with_space, alpha)
+}
+
+/// Makes string like `name(x y z)` or `name(x y z / a)`
+pub fn css_fn_to_string(name: &str, components: &[String], alpha: f64) -> String {
+  let mut result = format!("{}({}", name, components.join(" "));
+  if f64_abs(alpha - RATIO_MAX) >= f64::EPSILON {
+    result.push_str(&format!(" / {}", round_ratio(alpha)));
+  }
+  result.push(')');
+  result
+}
+
+#[cfg(test)]
+mod test {
+  #[cfg(not(feature = "std"))]
+  use alloc::string::String;
+
+  use super::{
+    css_color_fn_from_str, css_color_fn_to_string, css_fn_from_str, css_hue, css_num_to_string,
+    css_number,
+  };
+
+  #[test]
+  fn css_fn_from_str_test() {
+    let (c, a) = css_fn_from_str(" HWB(194 0% 0%) ", &["hwb"], 3).unwrap();
+    assert_eq!(c, vec!["194", "0%", "0%"]);
+    assert!(a.is_none());
+
+    let (c, a) = css_fn_from_str("hwb(194deg 10% 5% / 50%)", &["hwb"], 3).unwrap();
+    assert_eq!(c, vec!["194deg", "10%", "5%"]);
+    assert_eq!(a.unwrap(), "50%");
+
+    let (_, a) = css_fn_from_str("hwba(1, 2%, 3%, 0.3)", &["hwb", "hwba"], 3).unwrap();
+    assert_eq!(a.unwrap(), "0.3");
+
+    assert!(css_fn_from_str("hwb(1 2 3 4 5)", &["hwb"], 3).is_none());
+    assert!(css_fn_from_str("hwb(1 2 3 /)", &["hwb"], 3).is_none());
+    assert!(css_fn_from_str("hsl(1 2 3)", &["hwb"], 3).is_none());
+    assert!(css_fn_from_str("hwb(1 2 3", &["hwb"], 3).is_none());
+  }
+
+  #[test]
+  fn css_color_fn_test() {
+    let (c, a) = css_color_fn_from_str("color(Display-P3 1 0.5 0% / 0.5)", &["display-p3"], 3).unwrap();
+    assert_eq!(c, vec!["1", "0.5", "0%"]);
+    assert_eq!(a.unwrap(), "0.5");
+
+    assert!(css_color_fn_from_str("color(srgb 1 0.5 0)", &["display-p3"], 3).is_none());
+    assert!(css_color_fn_from_str("color(display-p3 1 0.5)", &["display-p3"], 3).is_none());
+    assert!(css_color_fn_from_str("rgb(display-p3 1 0.5 0)", &["display-p3"], 3).is_none());
+
+    let components = [String::from("1"), String::from("0"), String::from("0.25")];
+    assert_eq!(css_color_fn_to_string("rec2020", &components, 0.3), "color(rec2020 1 0 0.25 / 0.3)");
+  }
+
+  #[test]
+  fn css_values_test() {
+    assert_eq!(css_number("50%", 1.0), Some(0.5));
+    assert_eq!(css_number("none", 1.0), Some(0.0));
+    assert_eq!(css_number("-1.5", 1.0), Some(-1.5));
+    assert!(css_number("1.5px", 1.0).is_none());
+
+    assert_eq!(css_hue("0.5turn"), Some(180.0));
+    assert_eq!(css_hue("-90deg"), Some(270.0));
+    assert_eq!(css_hue("200grad"), Some(180.0));
+    assert_eq!(css_hue("400"), Some(40.0));
+  }
+
+  #[test]
+  fn css_num_to_string_test() {
+    assert_eq!(css_num_to_string(54.290_5, 2), "54.29");
+    assert_eq!(css_num_to_string(-0.000_1, 2), "0");
+    assert_eq!(css_num_to_string(100.0, 2), "100");
+    assert_eq!(css_num_to_string(0.627_96, 4), "0.628");
+  }
+}
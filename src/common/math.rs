@@ -0,0 +1,82 @@
+//! Floating point functions which are not available in `core`.
+//! Without `std` feature implementations from `libm` are used.
+
+#[cfg(feature = "std")]
+pub(crate) fn f64_powf(n: f64, e: f64) -> f64 {
+  n.powf(e)
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn f64_powf(n: f64, e: f64) -> f64 {
+  libm::pow(n, e)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn f64_sqrt(n: f64) -> f64 {
+  n.sqrt()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn f64_sqrt(n: f64) -> f64 {
+  libm::sqrt(n)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn f64_cbrt(n: f64) -> f64 {
+  n.cbrt()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn f64_cbrt(n: f64) -> f64 {
+  libm::cbrt(n)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn f64_sin(n: f64) -> f64 {
+  n.sin()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn f64_sin(n: f64) -> f64 {
+  libm::sin(n)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn f64_cos(n: f64) -> f64 {
+  n.cos()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn f64_cos(n: f64) -> f64 {
+  libm::cos(n)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn f64_atan2(y: f64, x: f64) -> f64 {
+  y.atan2(x)
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn f64_atan2(y: f64, x: f64) -> f64 {
+  libm::atan2(y, x)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn f64_ln(n: f64) -> f64 {
+  n.ln()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn f64_ln(n: f64) -> f64 {
+  libm::log(n)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn f64_exp(n: f64) -> f64 {
+  n.exp()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn f64_exp(n: f64) -> f64 {
+  libm::exp(n)
+}
@@ -0,0 +1,63 @@
+use crate::ColorTuple;
+
+/// 3x3 matrix, row-major
+pub type Mat3 = [[f64; 3]; 3];
+
+pub fn mat3_mul_tuple(m: &Mat3, t: &ColorTuple) -> ColorTuple {
+  let (x, y, z) = *t;
+  (
+    m[0][0] * x + m[0][1] * y + m[0][2] * z,
+    m[1][0] * x + m[1][1] * y + m[1][2] * z,
+    m[2][0] * x + m[2][1] * y + m[2][2] * z,
+  )
+}
+
+pub fn mat3_mul(a: &Mat3, b: &Mat3) -> Mat3 {
+  let mut result = [[0.0; 3]; 3];
+  for (i, row) in result.iter_mut().enumerate() {
+    for (j, v) in row.iter_mut().enumerate() {
+      *v = a[i][0] * b[0][j] + a[i][1] * b[1][j] + a[i][2] * b[2][j];
+    }
+  }
+  result
+}
+
+pub fn mat3_diag(t: &ColorTuple) -> Mat3 {
+  [[t.0, 0.0, 0.0], [0.0, t.1, 0.0], [0.0, 0.0, t.2]]
+}
+
+/// Returns inverse matrix or `None` if it is singular
+pub fn mat3_inverse(m: &Mat3) -> Option<Mat3> {
+  let [[a, b, c], [d, e, f], [g, h, i]] = *m;
+  let co_a = e * i - f * h;
+  let co_b = f * g - d * i;
+  let co_c = d * h - e * g;
+  let det = a * co_a + b * co_b + c * co_c;
+  if det == 0.0 {
+    return None;
+  }
+  Some([
+    [co_a / det, (c * h - b * i) / det, (b * f - c * e) / det],
+    [co_b / det, (a * i - c * g) / det, (c * d - a * f) / det],
+    [co_c / det, (b * g - a * h) / det, (a * e - b * d) / det],
+  ])
+}
+
+#[cfg(test)]
+mod test {
+  use super::{mat3_inverse, mat3_mul, Mat3};
+  use crate::common::approx::approx;
+
+  #[test]
+  fn mat3_inverse_test() {
+    let m: Mat3 = [[0.8951, 0.2664, -0.1614], [-0.7502, 1.7135, 0.0367], [0.0389, -0.0685, 1.0296]];
+    let identity = mat3_mul(&m, &mat3_inverse(&m).unwrap());
+    for (i, row) in identity.iter().enumerate() {
+      for (j, v) in row.iter().enumerate() {
+        let expected = if i == j { 1.0 } else { 0.0 };
+        assert!(approx(*v, expected, 1e-12));
+      }
+    }
+    assert!(mat3_inverse(&[[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 0.0, 1.0]]).is_none());
+  }
+}
@@ -1,18 +1,21 @@
 pub use alpha::ColorAlpha;
 pub use hsv_hsl_from_str::hsl_hsv_from_str;
 pub use tuple_to_string::tuple_to_string;
+pub(crate) use math::*;
 
 pub use crate::units::iter::ColorUnitsIter;
 
 mod alpha;
 mod hsv_hsl_from_str;
+mod math;
 mod tuple_to_string;
 
 pub mod approx;
+pub mod css_fn;
+pub mod matrix;
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Hs {
-  #[allow(dead_code)]
   Hsv,
 
   Hsl,
@@ -14,14 +14,14 @@ pub fn tuple_to_string(tuple: &ColorTupleA, prefix: &str) -> String {
     format!("{}", round_ratio(*a))
   };
 
-  let is_hsl = prefix == "hsl";
+  let with_percents = prefix == "hsl" || prefix == "hsv";
   let mut result = String::new();
   [x, y, z]
     .iter()
     .enumerate()
     .for_each(|(ind, u)| {
       let _ = write!(result, "{}", f64_round(**u));
-      if is_hsl && (ind == 1 || ind == 2) {
+      if with_percents && (ind == 1 || ind == 2) {
         result.push('%');
       }
       if ind != 2 {
@@ -0,0 +1,158 @@
+use crate::ColorTuple;
+use crate::common::{f64_exp, f64_sqrt};
+use crate::consts::RGB_UNIT_MAX;
+use crate::rgb::new_rgb_units;
+use crate::transfer::linear_to_srgb;
+use crate::units::Units;
+
+use super::chromaticity::xy_to_uv_prime;
+use super::cmf::{CIE1931_2, CMF_START, CMF_STEP};
+use super::rgb_xyz::xyz_to_linear_rgb;
+
+/// Range of temperatures (K) of the Planckian locus
+pub(crate) const PLANCK_MIN_KELVIN: f64 = 1_000.0;
+pub(crate) const PLANCK_MAX_KELVIN: f64 = 40_000.0;
+// range of temperatures (K) of the CIE daylight locus
+const DAYLIGHT_MIN_KELVIN: f64 = 4_000.0;
+const DAYLIGHT_MAX_KELVIN: f64 = 25_000.0;
+
+// second radiation constant (m·K), ITS-90
+const C2: f64 = 1.4388e-2;
+// CCT is not meaningful for chromaticities farther from the Planckian locus (ANSI C78.377)
+const DUV_MAX: f64 = 0.05;
+// ratio of neighbour temperatures of the coarse search
+const SEARCH_STEP: f64 = 1.01;
+const REFINE_STEPS: usize = 64;
+
+/// Returns spectral radiance of black body radiator at wavelength `nm`, in relative units:
+/// Planck's law without the first radiation constant
+pub(crate) fn blackbody(nm: f64, kelvin: f64) -> f64 {
+  let wl = nm * 1e-9;
+  1.0 / (wl * wl * wl * wl * wl * (f64_exp(C2 / (wl * kelvin)) - 1.0))
+}
+
+/// Returns chromaticity `(x, y)` of black body radiator (CIE 1931 2° observer)
+pub(crate) fn planck_xy(kelvin: f64) -> (f64, f64) {
+  let t = kelvin.clamp(PLANCK_MIN_KELVIN, PLANCK_MAX_KELVIN);
+  let (mut x, mut y, mut z) = (0.0, 0.0, 0.0);
+  for (i, [xb, yb, zb]) in CIE1931_2.iter().enumerate() {
+    let m = blackbody(CMF_START + i as f64 * CMF_STEP, t);
+    x += m * xb;
+    y += m * yb;
+    z += m * zb;
+  }
+  let sum = x + y + z;
+  (x / sum, y / sum)
+}
+
+/// Returns chromaticity `(x, y)` of CIE daylight illuminant (CIE 15:2004)
+pub(crate) fn daylight_xy(kelvin: f64) -> (f64, f64) {
+  let t = kelvin.clamp(DAYLIGHT_MIN_KELVIN, DAYLIGHT_MAX_KELVIN);
+  let (t2, t3) = (t * t, t * t * t);
+  let x = if t <= 7_000.0 {
+    -4.6070e9 / t3 + 2.9678e6 / t2 + 0.099_11e3 / t + 0.244_063
+  } else {
+    -2.0064e9 / t3 + 1.9018e6 / t2 + 0.247_48e3 / t + 0.237_040
+  };
+  (x, -3.0 * x * x + 2.870 * x - 0.275)
+}
+
+// XYZ with luminance `Y` equal to `1.0`
+fn xy_to_xyz(xy: &(f64, f64)) -> ColorTuple {
+  let (x, y) = *xy;
+  (x / y, 1.0, (1.0 - x - y) / y)
+}
+
+/// Returns sRGB color of chromaticity `xy`, as bright as possible.
+/// Components which are out of gamut are clipped
+pub(crate) fn xy_to_brightest_rgb(xy: &(f64, f64)) -> Units {
+  let (r, g, b) = xyz_to_linear_rgb(&xy_to_xyz(xy));
+  let max = r.max(g).max(b);
+  // the brightest component is set exactly, sRGB curve gives a bit less than 1.0 at 1.0
+  let encode = |c: f64| if c == max { RGB_UNIT_MAX } else { linear_to_srgb((c / max).max(0.0)) * RGB_UNIT_MAX };
+  let mut units = new_rgb_units(encode(r), encode(g), encode(b));
+  units.restrict();
+  units
+}
+
+// CIE 1960 (u, v) chromaticity
+fn uv(xy: &(f64, f64)) -> (f64, f64) {
+  let (u, v) = xy_to_uv_prime(xy);
+  (u, v * 2.0 / 3.0)
+}
+
+fn planck_distance(target: &(f64, f64), kelvin: f64) -> f64 {
+  let (u, v) = uv(&planck_xy(kelvin));
+  f64_sqrt((target.0 - u) * (target.0 - u) + (target.1 - v) * (target.1 - v))
+}
+
+/// Returns correlated color temperature (K) and Duv of chromaticity `xy`: temperature
+/// of the closest point of the Planckian locus in CIE 1960 UCS and the signed distance
+/// to it, positive above the locus. `None` if the temperature is out of
+/// 1000K..40000K, or the chromaticity is too far from the locus (|Duv| > 0.05)
+pub(crate) fn xy_to_cct_duv(xy: &(f64, f64)) -> Option<(f64, f64)> {
+  let target = uv(xy);
+
+  // coarse search on geometric grid of temperatures
+  let mut best = (PLANCK_MIN_KELVIN, planck_distance(&target, PLANCK_MIN_KELVIN));
+  let mut t = PLANCK_MIN_KELVIN;
+  while t < PLANCK_MAX_KELVIN {
+    t = (t * SEARCH_STEP).min(PLANCK_MAX_KELVIN);
+    let d = planck_distance(&target, t);
+    if d < best.1 {
+      best = (t, d);
+    }
+  }
+  if best.0 == PLANCK_MIN_KELVIN || best.0 == PLANCK_MAX_KELVIN {
+    return None;
+  }
+
+  // golden section search between neighbours of the coarse minimum
+  let ratio = (f64_sqrt(5.0) - 1.0) / 2.0;
+  let (mut lo, mut hi) = (best.0 / SEARCH_STEP, best.0 * SEARCH_STEP);
+  for _ in 0..REFINE_STEPS {
+    let t1 = hi - ratio * (hi - lo);
+    let t2 = lo + ratio * (hi - lo);
+    if planck_distance(&target, t1) < planck_distance(&target, t2) {
+      hi = t2;
+    } else {
+      lo = t1;
+    }
+  }
+  let cct = (lo + hi) / 2.0;
+
+  let d = planck_distance(&target, cct);
+  let duv = if target.1 < uv(&planck_xy(cct)).1 { -d } else { d };
+  (d <= DUV_MAX).then_some((cct, duv))
+}
+
+#[cfg(test)]
+mod test {
+  use super::{daylight_xy, planck_xy, xy_to_cct_duv};
+  use crate::common::approx::approx;
+
+  #[test]
+  fn locus_test() {
+    // CIE illuminant A
+    let (x, y) = planck_xy(2856.0);
+    assert!(approx(x, 0.447_57, 0.0001) && approx(y, 0.407_45, 0.0001));
+    // CIE illuminant D65
+    let (x, y) = daylight_xy(6504.0);
+    assert!(approx(x, 0.312_7, 0.0001) && approx(y, 0.329_1, 0.0001));
+  }
+
+  #[test]
+  fn cct_test() {
+    let (cct, duv) = xy_to_cct_duv(&(0.447_57, 0.407_45)).unwrap();
+    assert!(approx(cct, 2856.0, 1.0) && approx(duv, 0.0, 0.0001));
+    let (cct, duv) = xy_to_cct_duv(&(0.312_7, 0.329)).unwrap();
+    assert!(approx(cct, 6504.0, 2.0) && approx(duv, 0.0032, 0.0001));
+    for t in [1500.0, 3000.0, 5000.0, 10_000.0, 30_000.0] {
+      let (cct, duv) = xy_to_cct_duv(&planck_xy(t)).unwrap();
+      assert!(approx(cct, t, t * 1e-6) && approx(duv, 0.0, 1e-9));
+    }
+    // far from the locus
+    assert_eq!(xy_to_cct_duv(&(0.64, 0.33)), None);
+    assert_eq!(xy_to_cct_duv(&(0.15, 0.06)), None);
+  }
+}
@@ -0,0 +1,156 @@
+use crate::common::f64_sqrt;
+
+use super::cmf::{CIE1931_2, CMF_START, CMF_STEP};
+
+// ray parameters closer than this are treated as the same boundary point
+const RAY_EPSILON: f64 = 1e-12;
+// chromaticities closer to white than this are treated as white
+const WHITE_EPSILON: f64 = 1e-9;
+
+/// Converts CIE 1931 `(x, y)` chromaticity coordinates to CIE 1976 `(u', v')`
+pub(crate) fn xy_to_uv_prime(xy: &(f64, f64)) -> (f64, f64) {
+  let (x, y) = *xy;
+  let d = -2.0 * x + 12.0 * y + 3.0;
+  if d == 0.0 {
+    return (0.0, 0.0);
+  }
+  (4.0 * x / d, 9.0 * y / d)
+}
+
+/// Inverse of `xy_to_uv_prime`
+pub(crate) fn uv_prime_to_xy(uv: &(f64, f64)) -> (f64, f64) {
+  let (u, v) = *uv;
+  let d = 6.0 * u - 16.0 * v + 12.0;
+  if d == 0.0 {
+    return (0.0, 0.0);
+  }
+  (9.0 * u / d, 4.0 * v / d)
+}
+
+// chromaticity of monochromatic light of i-th row of CMF table
+fn locus_xy(i: usize) -> (f64, f64) {
+  let [x, y, z] = CIE1931_2[i];
+  let sum = x + y + z;
+  (x / sum, y / sum)
+}
+
+/// Part of the boundary of chromaticity diagram
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) enum LocusBoundary {
+  /// Spectral locus, wavelength in nm
+  Spectral(f64),
+  /// Line of purples, which joins ends of spectral locus
+  Purple,
+}
+
+/// Casts a ray from `origin` in direction `dir` and finds where it crosses the boundary
+/// of chromaticity diagram (CIE 1931 2° observer). Returns the boundary and the ray parameter `t`,
+/// the crossing point is `origin + t * dir`. `None` if the ray does not cross it
+pub(crate) fn cross_locus(origin: &(f64, f64), dir: &(f64, f64)) -> Option<(LocusBoundary, f64)> {
+  let last = CIE1931_2.len() - 1;
+  let mut found: Option<(LocusBoundary, f64)> = None;
+  for i in 0..=last {
+    // the last segment is the line of purples
+    let (j, boundary) = if i == last { (0, None) } else { (i + 1, Some(i)) };
+    let p = locus_xy(i);
+    let e = (locus_xy(j).0 - p.0, locus_xy(j).1 - p.1);
+    let denom = dir.0 * e.1 - dir.1 * e.0;
+    if denom == 0.0 {
+      continue;
+    }
+    let w = (p.0 - origin.0, p.1 - origin.1);
+    let t = (w.0 * e.1 - w.1 * e.0) / denom;
+    let s = (w.0 * dir.1 - w.1 * dir.0) / denom;
+    if t <= RAY_EPSILON || !(0.0..=1.0).contains(&s) {
+      continue;
+    }
+    if found.is_none_or(|(_, ft)| t < ft) {
+      let hit = match boundary {
+        Some(i) => LocusBoundary::Spectral(CMF_START + (i as f64 + s) * CMF_STEP),
+        None => LocusBoundary::Purple,
+      };
+      found = Some((hit, t));
+    }
+  }
+  found
+}
+
+// direction from white to chromaticity `xy`, `None` if they are the same
+fn from_white(xy: &(f64, f64), white: &(f64, f64)) -> Option<(f64, f64)> {
+  let dir = (xy.0 - white.0, xy.1 - white.1);
+  (f64_sqrt(dir.0 * dir.0 + dir.1 * dir.1) >= WHITE_EPSILON).then_some(dir)
+}
+
+fn spectral(hit: Option<(LocusBoundary, f64)>) -> Option<f64> {
+  match hit {
+    Some((LocusBoundary::Spectral(wl), _)) => Some(wl),
+    _ => None,
+  }
+}
+
+/// Returns dominant wavelength (nm) of chromaticity `xy` relative to `white`.
+/// `None` for purples and for white itself
+pub(crate) fn dominant_wavelength(xy: &(f64, f64), white: &(f64, f64)) -> Option<f64> {
+  let dir = from_white(xy, white)?;
+  spectral(cross_locus(white, &dir))
+}
+
+/// Returns complementary wavelength (nm) of chromaticity `xy` relative to `white`.
+/// `None` if the opposite direction from white points to purples, and for white itself
+pub(crate) fn complementary_wavelength(xy: &(f64, f64), white: &(f64, f64)) -> Option<f64> {
+  let dir = from_white(xy, white)?;
+  spectral(cross_locus(white, &(-dir.0, -dir.1)))
+}
+
+/// Returns excitation purity of chromaticity `xy` relative to `white`: `0.0` for white,
+/// `1.0` for colors on the boundary of chromaticity diagram
+pub(crate) fn excitation_purity(xy: &(f64, f64), white: &(f64, f64)) -> f64 {
+  let Some(dir) = from_white(xy, white) else {
+    return 0.0;
+  };
+  // direction is the distance to color, so t is a ratio of distances
+  match cross_locus(white, &dir) {
+    Some((_, t)) => 1.0 / t,
+    None => 0.0,
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::{
+    complementary_wavelength, dominant_wavelength, excitation_purity, locus_xy, uv_prime_to_xy,
+    xy_to_uv_prime,
+  };
+  use crate::common::approx::approx;
+
+  #[test]
+  fn uv_prime_test() {
+    let asserts = [((0.3127, 0.329), (0.197_830_007, 0.468_319_995)), ((0.64, 0.33), (0.450_704_225, 0.522_887_324))];
+    for (xy, uv) in asserts.iter() {
+      let (u, v) = xy_to_uv_prime(xy);
+      assert!(approx(u, uv.0, 1e-9) && approx(v, uv.1, 1e-9));
+      let (x, y) = uv_prime_to_xy(uv);
+      assert!(approx(x, xy.0, 1e-9) && approx(y, xy.1, 1e-9));
+    }
+  }
+
+  #[test]
+  fn locus_test() {
+    let white = (1.0 / 3.0, 1.0 / 3.0);
+    // monochromatic lights
+    for (i, wl) in [(10, 430.0), (28, 520.0), (44, 600.0)] {
+      let xy = locus_xy(i);
+      assert!(approx(dominant_wavelength(&xy, &white).unwrap(), wl, 1e-9));
+      assert!(approx(excitation_purity(&xy, &white), 1.0, 1e-9));
+    }
+    assert_eq!(dominant_wavelength(&white, &white), None);
+    assert_eq!(complementary_wavelength(&white, &white), None);
+    assert_eq!(excitation_purity(&white, &white), 0.0);
+
+    // purple: no dominant wavelength
+    let purple = (0.35, 0.15);
+    assert_eq!(dominant_wavelength(&purple, &white), None);
+    assert!(complementary_wavelength(&purple, &white).unwrap() > 500.0);
+    assert!(excitation_purity(&purple, &white) > 0.0);
+  }
+}
@@ -0,0 +1,174 @@
+/// Wavelength (nm) of the first row of color matching functions tables
+pub(crate) const CMF_START: f64 = 380.0;
+/// Wavelength step (nm) of color matching functions tables
+pub(crate) const CMF_STEP: f64 = 5.0;
+
+/// CIE 1931 2° standard observer: `x̄`, `ȳ`, `z̄` from 380 to 780 nm
+pub(crate) static CIE1931_2: [[f64; 3]; 81] = [
+  [0.001_368, 0.000_039, 0.006_450], // 380
+  [0.002_236, 0.000_064, 0.010_550], // 385
+  [0.004_243, 0.000_120, 0.020_050], // 390
+  [0.007_650, 0.000_217, 0.036_210], // 395
+  [0.014_310, 0.000_396, 0.067_850], // 400
+  [0.023_190, 0.000_640, 0.110_200], // 405
+  [0.043_510, 0.001_210, 0.207_400], // 410
+  [0.077_630, 0.002_180, 0.371_300], // 415
+  [0.134_380, 0.004_000, 0.645_600], // 420
+  [0.214_770, 0.007_300, 1.039_050], // 425
+  [0.283_900, 0.011_600, 1.385_600], // 430
+  [0.328_500, 0.016_840, 1.622_960], // 435
+  [0.348_280, 0.023_000, 1.747_060], // 440
+  [0.348_060, 0.029_800, 1.782_600], // 445
+  [0.336_200, 0.038_000, 1.772_110], // 450
+  [0.318_700, 0.048_000, 1.744_100], // 455
+  [0.290_800, 0.060_000, 1.669_200], // 460
+  [0.251_100, 0.073_900, 1.528_100], // 465
+  [0.195_360, 0.090_980, 1.287_640], // 470
+  [0.142_100, 0.112_600, 1.041_900], // 475
+  [0.095_640, 0.139_020, 0.812_950], // 480
+  [0.057_950, 0.169_300, 0.616_200], // 485
+  [0.032_010, 0.208_020, 0.465_180], // 490
+  [0.014_700, 0.258_600, 0.353_300], // 495
+  [0.004_900, 0.323_000, 0.272_000], // 500
+  [0.002_400, 0.407_300, 0.212_300], // 505
+  [0.009_300, 0.503_000, 0.158_200], // 510
+  [0.029_100, 0.608_200, 0.111_700], // 515
+  [0.063_270, 0.710_000, 0.078_250], // 520
+  [0.109_600, 0.793_200, 0.057_250], // 525
+  [0.165_500, 0.862_000, 0.042_160], // 530
+  [0.225_750, 0.914_850, 0.029_840], // 535
+  [0.290_400, 0.954_000, 0.020_300], // 540
+  [0.359_700, 0.980_300, 0.013_400], // 545
+  [0.433_450, 0.994_950, 0.008_750], // 550
+  [0.512_050, 1.000_000, 0.005_750], // 555
+  [0.594_500, 0.995_000, 0.003_900], // 560
+  [0.678_400, 0.978_600, 0.002_750], // 565
+  [0.762_100, 0.952_000, 0.002_100], // 570
+  [0.842_500, 0.915_400, 0.001_800], // 575
+  [0.916_300, 0.870_000, 0.001_650], // 580
+  [0.978_600, 0.816_300, 0.001_400], // 585
+  [1.026_300, 0.757_000, 0.001_100], // 590
+  [1.056_700, 0.694_900, 0.001_000], // 595
+  [1.062_200, 0.631_000, 0.000_800], // 600
+  [1.045_600, 0.566_800, 0.000_600], // 605
+  [1.002_600, 0.503_000, 0.000_340], // 610
+  [0.938_400, 0.441_200, 0.000_240], // 615
+  [0.854_450, 0.381_000, 0.000_190], // 620
+  [0.751_400, 0.321_000, 0.000_100], // 625
+  [0.642_400, 0.265_000, 0.000_050], // 630
+  [0.541_900, 0.217_000, 0.000_030], // 635
+  [0.447_900, 0.175_000, 0.000_020], // 640
+  [0.360_800, 0.138_200, 0.000_010], // 645
+  [0.283_500, 0.107_000, 0.000_000], // 650
+  [0.218_700, 0.081_600, 0.000_000], // 655
+  [0.164_900, 0.061_000, 0.000_000], // 660
+  [0.121_200, 0.044_580, 0.000_000], // 665
+  [0.087_400, 0.032_000, 0.000_000], // 670
+  [0.063_600, 0.023_200, 0.000_000], // 675
+  [0.046_770, 0.017_000, 0.000_000], // 680
+  [0.032_900, 0.011_920, 0.000_000], // 685
+  [0.022_700, 0.008_210, 0.000_000], // 690
+  [0.015_840, 0.005_723, 0.000_000], // 695
+  [0.011_359, 0.004_102, 0.000_000], // 700
+  [0.008_111, 0.002_929, 0.000_000], // 705
+  [0.005_790, 0.002_091, 0.000_000], // 710
+  [0.004_109, 0.001_484, 0.000_000], // 715
+  [0.002_899, 0.001_047, 0.000_000], // 720
+  [0.002_049, 0.000_740, 0.000_000], // 725
+  [0.001_440, 0.000_520, 0.000_000], // 730
+  [0.001_000, 0.000_361, 0.000_000], // 735
+  [0.000_690, 0.000_249, 0.000_000], // 740
+  [0.000_476, 0.000_172, 0.000_000], // 745
+  [0.000_332, 0.000_120, 0.000_000], // 750
+  [0.000_235, 0.000_085, 0.000_000], // 755
+  [0.000_166, 0.000_060, 0.000_000], // 760
+  [0.000_117, 0.000_042, 0.000_000], // 765
+  [0.000_083, 0.000_030, 0.000_000], // 770
+  [0.000_059, 0.000_021, 0.000_000], // 775
+  [0.000_042, 0.000_015, 0.000_000], // 780
+];
+
+/// CIE 1964 10° standard observer: `x̄₁₀`, `ȳ₁₀`, `z̄₁₀` from 380 to 780 nm
+pub(crate) static CIE1964_10: [[f64; 3]; 81] = [
+  [0.000_160, 0.000_017, 0.000_705], // 380
+  [0.000_662, 0.000_072, 0.002_928], // 385
+  [0.002_362, 0.000_253, 0.010_482], // 390
+  [0.007_242, 0.000_769, 0.032_344], // 395
+  [0.019_110, 0.002_004, 0.086_011], // 400
+  [0.043_400, 0.004_509, 0.197_120], // 405
+  [0.084_736, 0.008_756, 0.389_366], // 410
+  [0.140_638, 0.014_456, 0.656_760], // 415
+  [0.204_492, 0.021_391, 0.972_542], // 420
+  [0.264_737, 0.029_497, 1.282_500], // 425
+  [0.314_679, 0.038_676, 1.553_480], // 430
+  [0.357_719, 0.049_602, 1.798_500], // 435
+  [0.383_734, 0.062_077, 1.967_280], // 440
+  [0.386_726, 0.074_704, 2.027_300], // 445
+  [0.370_702, 0.089_456, 1.994_800], // 450
+  [0.342_957, 0.106_256, 1.900_700], // 455
+  [0.302_273, 0.128_201, 1.745_370], // 460
+  [0.254_085, 0.152_761, 1.554_900], // 465
+  [0.195_618, 0.185_190, 1.317_560], // 470
+  [0.132_349, 0.219_940, 1.030_200], // 475
+  [0.080_507, 0.253_589, 0.772_125], // 480
+  [0.041_072, 0.297_665, 0.570_060], // 485
+  [0.016_172, 0.339_133, 0.415_254], // 490
+  [0.005_132, 0.395_379, 0.302_356], // 495
+  [0.003_816, 0.460_777, 0.218_502], // 500
+  [0.015_444, 0.531_360, 0.159_249], // 505
+  [0.037_465, 0.606_741, 0.112_044], // 510
+  [0.071_358, 0.685_660, 0.082_248], // 515
+  [0.117_749, 0.761_757, 0.060_709], // 520
+  [0.172_953, 0.823_330, 0.043_050], // 525
+  [0.236_491, 0.875_211, 0.030_451], // 530
+  [0.304_213, 0.923_810, 0.020_584], // 535
+  [0.376_772, 0.961_988, 0.013_676], // 540
+  [0.451_584, 0.982_200, 0.007_918], // 545
+  [0.529_826, 0.991_761, 0.003_988], // 550
+  [0.616_053, 0.999_110, 0.001_091], // 555
+  [0.705_224, 0.997_340, 0.000_000], // 560
+  [0.793_832, 0.982_380, 0.000_000], // 565
+  [0.878_655, 0.955_552, 0.000_000], // 570
+  [0.951_162, 0.915_175, 0.000_000], // 575
+  [1.014_160, 0.868_934, 0.000_000], // 580
+  [1.074_300, 0.825_623, 0.000_000], // 585
+  [1.118_520, 0.777_405, 0.000_000], // 590
+  [1.134_300, 0.720_353, 0.000_000], // 595
+  [1.123_990, 0.658_341, 0.000_000], // 600
+  [1.089_100, 0.593_878, 0.000_000], // 605
+  [1.030_480, 0.527_963, 0.000_000], // 610
+  [0.950_740, 0.461_834, 0.000_000], // 615
+  [0.856_297, 0.398_057, 0.000_000], // 620
+  [0.754_930, 0.339_554, 0.000_000], // 625
+  [0.647_467, 0.283_493, 0.000_000], // 630
+  [0.535_110, 0.228_254, 0.000_000], // 635
+  [0.431_567, 0.179_828, 0.000_000], // 640
+  [0.343_690, 0.140_211, 0.000_000], // 645
+  [0.268_329, 0.107_633, 0.000_000], // 650
+  [0.204_300, 0.081_187, 0.000_000], // 655
+  [0.152_568, 0.060_281, 0.000_000], // 660
+  [0.112_210, 0.044_096, 0.000_000], // 665
+  [0.081_261, 0.031_800, 0.000_000], // 670
+  [0.057_930, 0.022_602, 0.000_000], // 675
+  [0.040_851, 0.015_905, 0.000_000], // 680
+  [0.028_623, 0.011_130, 0.000_000], // 685
+  [0.019_941, 0.007_749, 0.000_000], // 690
+  [0.013_842, 0.005_375, 0.000_000], // 695
+  [0.009_577, 0.003_718, 0.000_000], // 700
+  [0.006_605, 0.002_565, 0.000_000], // 705
+  [0.004_553, 0.001_768, 0.000_000], // 710
+  [0.003_145, 0.001_222, 0.000_000], // 715
+  [0.002_175, 0.000_846, 0.000_000], // 720
+  [0.001_506, 0.000_586, 0.000_000], // 725
+  [0.001_045, 0.000_407, 0.000_000], // 730
+  [0.000_727, 0.000_284, 0.000_000], // 735
+  [0.000_508, 0.000_199, 0.000_000], // 740
+  [0.000_356, 0.000_140, 0.000_000], // 745
+  [0.000_251, 0.000_098, 0.000_000], // 750
+  [0.000_178, 0.000_070, 0.000_000], // 755
+  [0.000_126, 0.000_050, 0.000_000], // 760
+  [0.000_090, 0.000_036, 0.000_000], // 765
+  [0.000_065, 0.000_025, 0.000_000], // 770
+  [0.000_046, 0.000_018, 0.000_000], // 775
+  [0.000_033, 0.000_013, 0.000_000], // 780
+];
@@ -135,11 +135,11 @@ mod test {
     for (s, t, a) in valid.iter() {
       let rgb = from_hex(s.as_bytes()).unwrap();
       assert_eq!(&rgb.0, t);
-      if rgb.1.is_none() {
-        assert!(a.is_none());
+      if let Some(rgb_a) = rgb.1 {
+        assert!(approx(rgb_a, a.unwrap(), 0.001));
       } else {
-        assert!(approx(rgb.1.unwrap(), a.unwrap(), 0.001));
-      } 
+        assert!(a.is_none());
+      }
     }
 
   }
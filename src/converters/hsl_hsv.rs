@@ -0,0 +1,45 @@
+use crate::{Hsl, Hsv};
+use crate::consts::{ALL_MIN, PERCENT_MAX};
+use crate::hsl::new_hsl_units;
+use crate::hsv::new_hsv_units;
+use crate::units::Units;
+
+pub(crate) fn hsl_to_hsv(hsl: &Hsl) -> Units {
+  let [_, s, l]: [f64; 3] = hsl.units.as_ratio().into();
+  let v = l + s * l.min(1.0 - l);
+  let sv = if v == ALL_MIN { ALL_MIN } else { 2.0 * (1.0 - l / v) };
+  new_hsv_units(hsl.units[0], sv * PERCENT_MAX, v * PERCENT_MAX)
+}
+
+pub(crate) fn hsv_to_hsl(hsv: &Hsv) -> Units {
+  let [_, s, v]: [f64; 3] = hsv.units.as_ratio().into();
+  let l = v * (1.0 - s / 2.0);
+  let m = l.min(1.0 - l);
+  let sl = if m == ALL_MIN { ALL_MIN } else { (v - l) / m };
+  new_hsl_units(hsv.units[0], sl * PERCENT_MAX, l * PERCENT_MAX)
+}
+
+
+#[cfg(test)]
+mod test {
+  use crate::{ApproxEq, Hsl, Hsv};
+  use crate::converters::{hsl_to_hsv, hsv_to_hsl};
+
+  #[test]
+  fn hsl_hsv_tst() {
+    let asserts = [
+      ((0.0, 0.0, 0.0), (0.0, 0.0, 0.0)),
+      ((0.0, 0.0, 100.0), (0.0, 0.0, 100.0)),
+      ((194.0, 36.0, 88.0), (194.0, 9.36, 92.32)),
+      ((97.0, 76.0, 51.0), (97.0, 84.41, 88.24)),
+      ((332.0, 100.0, 42.0), (332.0, 100.0, 84.0)),
+    ];
+
+    for (hsl_t, hsv_t) in asserts.iter() {
+      let hsl = Hsl::from(hsl_t);
+      let hsv = Hsv::from(hsv_t);
+      assert!(hsl_to_hsv(&hsl).approx_eq_clarify(&hsv.units, 0.5));
+      assert!(hsv_to_hsl(&hsv).approx_eq_clarify(&hsl.units, 0.5));
+    }
+  }
+}
@@ -0,0 +1,61 @@
+use crate::{Hsv, Hwb};
+use crate::consts::{ALL_MIN, PERCENT_MAX, RATIO_MAX};
+use crate::hsv::new_hsv_units;
+use crate::hwb::new_hwb_units;
+use crate::units::Units;
+
+pub(crate) fn hsv_to_hwb(hsv: &Hsv) -> Units {
+  let [_, s, v]: [f64; 3] = hsv.units.as_ratio().into();
+  let w = (RATIO_MAX - s) * v;
+  let b = RATIO_MAX - v;
+  new_hwb_units(hsv.units[0], w * PERCENT_MAX, b * PERCENT_MAX)
+}
+
+/// If the sum of whiteness and blackness is greater than 100%
+/// they are normalized to make that sum 100% and result is a shade of gray
+pub(crate) fn hwb_to_hsv(hwb: &Hwb) -> Units {
+  let [_, w, b]: [f64; 3] = hwb.units.as_ratio().into();
+  if w + b >= RATIO_MAX {
+    let gray = w / (w + b);
+    return new_hsv_units(hwb.units[0], ALL_MIN, gray * PERCENT_MAX);
+  }
+  let v = RATIO_MAX - b;
+  let s = if v == ALL_MIN { ALL_MIN } else { RATIO_MAX - w / v };
+  new_hsv_units(hwb.units[0], s * PERCENT_MAX, v * PERCENT_MAX)
+}
+
+
+#[cfg(test)]
+mod test {
+  use crate::{ApproxEq, Hsv, Hwb};
+  use crate::converters::{hsv_to_hwb, hwb_to_hsv};
+
+  #[test]
+  fn hsv_hwb_tst() {
+    let asserts = [
+      ((0.0, 0.0, 0.0), (0.0, 0.0, 100.0)),
+      ((0.0, 0.0, 100.0), (0.0, 100.0, 0.0)),
+      ((194.0, 8.9, 92.5), (194.0, 84.27, 7.5)),
+      ((97.0, 84.0, 88.0), (97.0, 14.08, 12.0)),
+      ((332.0, 100.0, 84.0), (332.0, 0.0, 16.0)),
+    ];
+
+    for (hsv_t, hwb_t) in asserts.iter() {
+      let hsv = Hsv::from(hsv_t);
+      let hwb = Hwb::from(hwb_t);
+      assert!(hsv_to_hwb(&hsv).approx_eq_clarify(&hwb.units, 0.01));
+      assert!(hwb_to_hsv(&hwb).approx_eq_clarify(&hsv.units, 0.01));
+    }
+  }
+
+  #[test]
+  fn hwb_normalize_tst() {
+    let hwb = Hwb::from((120.0, 60.0, 60.0));
+    let hsv = Hsv::from((120.0, 0.0, 50.0));
+    assert!(hwb_to_hsv(&hwb).approx_eq(&hsv.units));
+
+    let hwb = Hwb::from((0.0, 30.0, 90.0));
+    let hsv = Hsv::from((0.0, 0.0, 25.0));
+    assert!(hwb_to_hsv(&hwb).approx_eq(&hsv.units));
+  }
+}
@@ -0,0 +1,54 @@
+use crate::consts::RGB_UNIT_MAX;
+use crate::Hsv;
+use crate::rgb::new_rgb_units;
+use crate::units::Units;
+
+pub(crate) fn hsv_to_rgb(hsv: &Hsv) -> Units {
+  let [h, s, v]: [f64; 3] = hsv.units.as_ratio().into();
+  if s == 0.0 {
+    let unit = RGB_UNIT_MAX * v;
+    return new_rgb_units(unit, unit, unit);
+  }
+
+  let sector = h * 6.0;
+  let i = sector as u8 % 6;
+  let f = sector - sector as u8 as f64;
+
+  let p = v * (1.0 - s);
+  let q = v * (1.0 - s * f);
+  let t = v * (1.0 - s * (1.0 - f));
+
+  let (r, g, b) = match i {
+    0 => (v, t, p),
+    1 => (q, v, p),
+    2 => (p, v, t),
+    3 => (p, q, v),
+    4 => (t, p, v),
+    _ => (v, p, q),
+  };
+
+  new_rgb_units(r * RGB_UNIT_MAX, g * RGB_UNIT_MAX, b * RGB_UNIT_MAX)
+}
+
+
+#[cfg(test)]
+mod test {
+  use crate::{ApproxEq, ColorTuple, Hsv, Rgb};
+
+  #[test]
+  fn hsv_to_rgb_tst() {
+    use crate::converters::hsv_to_rgb;
+
+    fn a(x: ColorTuple, y: ColorTuple) -> bool {
+      let hsv = Hsv::from(x);
+      let rgb = Rgb::from(y);
+      hsv_to_rgb(&hsv).approx_eq_clarify(&rgb.units, 0.5)
+    }
+
+    assert!(a((200.0, 100.0, 60.0), (0.0, 102.0, 153.0)));
+    assert!(a((192.0, 79.83, 46.67), (24.0, 100.0, 119.0)));
+    assert!(a((48.0, 82.5, 85.0), (217.0, 181.0, 38.0)));
+    assert!(a((359.0, 18.0, 84.7), (216.0, 177.0, 178.0)));
+    assert!(a((360.0, 100.0, 100.0), (255.0, 0.0, 0.0)));
+  }
+}
@@ -0,0 +1,124 @@
+use crate::ColorTuple;
+use crate::common::{f64_abs, f64_cos, f64_sin, f64_sqrt};
+use crate::consts::PERCENT_MAX;
+
+use super::rgb_xyz::XYZ_TO_SRGB;
+use super::xyz_lab::{EPSILON, KAPPA};
+
+// lightness values which are treated as pure white and black
+const L_MAX: f64 = 99.999_999_9;
+const L_MIN: f64 = 0.000_000_01;
+
+/// Returns six lines `(slope, intercept)` in the `u*v*` plane of LCh(uv) with given
+/// lightness, which bound sRGB gamut (every channel of linear sRGB is `0` or `1`)
+fn get_bounds(l: f64) -> [(f64, f64); 6] {
+  let sub1 = (l + 16.0) * (l + 16.0) * (l + 16.0) / 1_560_896.0;
+  let sub2 = if sub1 > EPSILON { sub1 } else { l / KAPPA };
+  let mut bounds = [(0.0, 0.0); 6];
+  for (i, [m1, m2, m3]) in XYZ_TO_SRGB.iter().enumerate() {
+    for t in 0..2 {
+      let t = t as f64;
+      let top1 = (284_517.0 * m1 - 94_839.0 * m3) * sub2;
+      let top2 = (838_422.0 * m3 + 769_860.0 * m2 + 731_718.0 * m1) * l * sub2 - 769_860.0 * t * l;
+      let bottom = (632_260.0 * m3 - 126_452.0 * m2) * sub2 + 126_452.0 * t;
+      bounds[i * 2 + t as usize] = (top1 / bottom, top2 / bottom);
+    }
+  }
+  bounds
+}
+
+/// Maximum chroma of sRGB color with given lightness and hue
+fn max_chroma_for_lh(l: f64, h: f64) -> f64 {
+  let rad = h.to_radians();
+  let (sin, cos) = (f64_sin(rad), f64_cos(rad));
+  get_bounds(l).iter().fold(f64::MAX, |min, (slope, intercept)| {
+    let len = intercept / (sin - slope * cos);
+    if len >= 0.0 && len < min { len } else { min }
+  })
+}
+
+/// Maximum chroma which is in sRGB gamut for any hue with given lightness
+fn max_safe_chroma_for_l(l: f64) -> f64 {
+  get_bounds(l).iter().fold(f64::MAX, |min, (slope, intercept)| {
+    let dist = f64_abs(*intercept) / f64_sqrt(slope * slope + 1.0);
+    if dist < min { dist } else { min }
+  })
+}
+
+fn lchuv_to_hs_l<F: Fn(f64, f64) -> f64>(lch: &ColorTuple, max_chroma: F) -> ColorTuple {
+  let (l, c, h) = *lch;
+  if l > L_MAX {
+    return (h, 0.0, PERCENT_MAX);
+  }
+  if l < L_MIN {
+    return (h, 0.0, 0.0);
+  }
+  (h, c / max_chroma(l, h) * PERCENT_MAX, l)
+}
+
+fn hs_l_to_lchuv<F: Fn(f64, f64) -> f64>(hsl: &ColorTuple, max_chroma: F) -> ColorTuple {
+  let (h, s, l) = *hsl;
+  if l > L_MAX {
+    return (PERCENT_MAX, 0.0, h);
+  }
+  if l < L_MIN {
+    return (0.0, 0.0, h);
+  }
+  (l, max_chroma(l, h) / PERCENT_MAX * s, h)
+}
+
+/// Takes LCh(uv) and returns HSLuv values as `(h, s, l)`
+pub(crate) fn lchuv_to_hsluv(lch: &ColorTuple) -> ColorTuple {
+  lchuv_to_hs_l(lch, max_chroma_for_lh)
+}
+
+/// Inverse of `lchuv_to_hsluv`
+pub(crate) fn hsluv_to_lchuv(hsl: &ColorTuple) -> ColorTuple {
+  hs_l_to_lchuv(hsl, max_chroma_for_lh)
+}
+
+/// Takes LCh(uv) and returns HPLuv values as `(h, p, l)`
+pub(crate) fn lchuv_to_hpluv(lch: &ColorTuple) -> ColorTuple {
+  lchuv_to_hs_l(lch, |l, _| max_safe_chroma_for_l(l))
+}
+
+/// Inverse of `lchuv_to_hpluv`
+pub(crate) fn hpluv_to_lchuv(hpl: &ColorTuple) -> ColorTuple {
+  hs_l_to_lchuv(hpl, |l, _| max_safe_chroma_for_l(l))
+}
+
+#[cfg(test)]
+mod test {
+  use super::{hpluv_to_lchuv, hsluv_to_lchuv, lchuv_to_hpluv, lchuv_to_hsluv};
+  use crate::common::approx::approx;
+
+  #[test]
+  fn lchuv_hsluv_test() {
+    let asserts = [
+      ((53.237_116, 179.038_097, 12.177_051), (12.177_051, 100.0, 53.237_116)),
+      ((100.0, 0.0, 0.0), (0.0, 0.0, 100.0)),
+      ((0.0, 0.0, 120.0), (120.0, 0.0, 0.0)),
+      ((50.0, 30.0, 200.0), (200.0, 74.216_259, 50.0)),
+    ];
+    for (lch, hsl) in asserts.iter() {
+      let t = lchuv_to_hsluv(lch);
+      assert!(approx(t.0, hsl.0, 1e-4) && approx(t.1, hsl.1, 1e-4) && approx(t.2, hsl.2, 1e-4));
+      let l = hsluv_to_lchuv(&t);
+      assert!(approx(l.0, lch.0, 1e-9) && approx(l.1, lch.1, 1e-9) && approx(l.2, lch.2, 1e-9));
+    }
+  }
+
+  #[test]
+  fn lchuv_hpluv_test() {
+    let asserts = [
+      ((53.237_116, 179.038_097, 12.177_051), (12.177_051, 426.746_786, 53.237_116)),
+      ((50.0, 30.0, 200.0), (200.0, 76.136_091, 50.0)),
+    ];
+    for (lch, hpl) in asserts.iter() {
+      let t = lchuv_to_hpluv(lch);
+      assert!(approx(t.0, hpl.0, 1e-4) && approx(t.1, hpl.1, 1e-4) && approx(t.2, hpl.2, 1e-4));
+      let l = hpluv_to_lchuv(&t);
+      assert!(approx(l.0, lch.0, 1e-9) && approx(l.1, lch.1, 1e-9) && approx(l.2, lch.2, 1e-9));
+    }
+  }
+}
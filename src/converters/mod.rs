@@ -1,12 +1,68 @@
+mod cct;
+mod chromaticity;
+mod cmf;
 mod hex_to_rgb;
+mod hsl_hsv;
 mod hsl_to_rgb;
+mod hsv_hwb;
+mod hsv_to_rgb;
+mod lchuv_hsluv;
 mod rgb_to_hex;
 mod rgb_to_hsl;
+mod rgb_to_hsv;
 mod rgb_cmyk;
+mod rgb_ictcp;
+mod rgb_okhsl;
+mod rgb_space;
+mod rgb_xyz;
+mod rgb_ycbcr;
+mod rgb_ycocg;
+mod rgb_yuv;
+mod polar;
+mod spectral;
+mod xyz_cam16;
+mod xyz_hct;
+mod xyz_jzazbz;
+mod xyz_lab;
+mod xyz_luv;
+mod xyz_oklab;
 
+pub(crate) use cct::{
+  blackbody, daylight_xy, planck_xy, xy_to_brightest_rgb, xy_to_cct_duv, PLANCK_MAX_KELVIN,
+  PLANCK_MIN_KELVIN,
+};
+pub(crate) use cmf::{CIE1931_2, CIE1964_10};
+pub(crate) use chromaticity::{
+  complementary_wavelength, dominant_wavelength, excitation_purity, uv_prime_to_xy, xy_to_uv_prime,
+};
 pub(crate) use hex_to_rgb::hex_to_rgb;
+pub(crate) use hsl_hsv::{hsl_to_hsv, hsv_to_hsl};
 pub(crate) use hsl_to_rgb::hsl_to_rgb;
+pub(crate) use hsv_hwb::{hsv_to_hwb, hwb_to_hsv};
+pub(crate) use hsv_to_rgb::hsv_to_rgb;
+pub(crate) use lchuv_hsluv::{hpluv_to_lchuv, hsluv_to_lchuv, lchuv_to_hpluv, lchuv_to_hsluv};
 pub(crate) use rgb_to_hex::rgb_to_hex;
 pub(crate) use rgb_to_hex::rgb_to_hexa;
 pub(crate) use rgb_to_hsl::rgb_to_hsl;
+pub(crate) use rgb_to_hsv::rgb_to_hsv;
 pub(crate) use rgb_cmyk::{rgb_to_cmyk, cmyk_to_rgb};
+pub(crate) use rgb_ictcp::{ictcp_to_linear, linear_to_ictcp};
+pub(crate) use rgb_okhsl::{okhsl_to_rgb, okhsv_to_rgb, rgb_to_okhsl, rgb_to_okhsv};
+pub(crate) use rgb_space::{
+  in_unit_cube, linear_to_xyz, rgb_to_xyz_matrix, xyz_to_linear, A98_PRIMARIES, ACES_AP0_PRIMARIES,
+  ACES_AP1_PRIMARIES, ACES_WHITE, DISPLAY_P3_PRIMARIES, PROPHOTO_PRIMARIES, REC2020_PRIMARIES,
+};
+pub(crate) use rgb_xyz::{linear_rgb_to_xyz, rgb_to_xyz, xyz_to_linear_rgb, xyz_to_rgb};
+pub(crate) use rgb_ycbcr::{rgb_to_ypbpr, ypbpr_to_rgb};
+pub(crate) use rgb_ycocg::{rgb_to_ycocg, rgb_to_ycocg_r, ycocg_r_to_rgb, ycocg_to_rgb};
+pub(crate) use rgb_yuv::{rgb_to_yiq, rgb_to_yuv, yiq_to_rgb, yuv_to_rgb};
+pub(crate) use polar::{polar_to_rect, rect_to_polar};
+pub(crate) use spectral::{
+  cmf_at, integrate_spd, xyz_to_brightest_rgb_desaturated, SPECTRAL_MAX_NM, SPECTRAL_MIN_NM,
+};
+pub(crate) use xyz_cam16::{cam16_to_ucs, cam16_to_xyz, ucs_to_cam16, xyz_to_cam16};
+pub(crate) use xyz_hct::{hct_to_rgb, hct_to_xyz, xyz_to_hct};
+pub(crate) use xyz_jzazbz::{jzazbz_to_xyz, xyz_to_jzazbz};
+pub(crate) use xyz_lab::{lab_to_xyz, lstar_to_y, xyz_to_lab, y_to_lstar};
+pub(crate) use xyz_luv::{luv_to_xyz, xyz_to_luv};
+pub(crate) use xyz_oklab::{oklab_to_xyz, xyz_to_oklab};
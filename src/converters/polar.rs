@@ -0,0 +1,48 @@
+use crate::ColorTuple;
+use crate::common::{f64_atan2, f64_cos, f64_sin, f64_sqrt};
+use crate::consts::HUE_MAX;
+use crate::normalize::bound_hue;
+
+// chroma below this value is considered as zero, hue is powerless then
+const ACHROMATIC_THRESHOLD: f64 = 1e-9;
+
+/// Converts lightness and two opponent axes (`Lab`, `Luv`, `Oklab`..) to
+/// lightness, chroma and hue in degrees
+pub(crate) fn rect_to_polar(t: &ColorTuple) -> ColorTuple {
+  let (l, a, b) = *t;
+  let c = f64_sqrt(a * a + b * b);
+  if c < ACHROMATIC_THRESHOLD {
+    return (l, 0.0, 0.0);
+  }
+  let h = bound_hue(f64_atan2(b, a) * HUE_MAX / (2.0 * core::f64::consts::PI));
+  (l, c, h)
+}
+
+/// Inverse of `rect_to_polar`
+pub(crate) fn polar_to_rect(t: &ColorTuple) -> ColorTuple {
+  let (l, c, h) = *t;
+  let rad = h * 2.0 * core::f64::consts::PI / HUE_MAX;
+  (l, c * f64_cos(rad), c * f64_sin(rad))
+}
+
+#[cfg(test)]
+mod test {
+  use super::{polar_to_rect, rect_to_polar};
+  use crate::common::approx::approx;
+
+  #[test]
+  fn polar_test() {
+    let asserts = [
+      ((50.0, 10.0, 0.0), (50.0, 10.0, 0.0)),
+      ((50.0, 0.0, 10.0), (50.0, 10.0, 90.0)),
+      ((50.0, -3.0, -4.0), (50.0, 5.0, 233.130_102_354)),
+      ((50.0, 0.0, 0.0), (50.0, 0.0, 0.0)),
+    ];
+    for (rect, polar) in asserts.iter() {
+      let p = rect_to_polar(rect);
+      assert!(approx(p.1, polar.1, 1e-9) && approx(p.2, polar.2, 1e-9));
+      let r = polar_to_rect(polar);
+      assert!(approx(r.1, rect.1, 1e-9) && approx(r.2, rect.2, 1e-9));
+    }
+  }
+}
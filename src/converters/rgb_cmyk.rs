@@ -1,6 +1,6 @@
 use crate::{Cmyk, Rgb};
 use crate::cmyk::CmykRatio;
-use crate::consts::{RATIO_MAX, RGB_UNIT_MAX};
+use crate::consts::{ALL_MIN, RATIO_MAX, RGB_UNIT_MAX};
 
 pub(crate) fn rgb_to_cmyk(rgb: &Rgb) -> Cmyk {
   let rgb_r = rgb.units.as_ratio();
@@ -8,6 +8,10 @@ pub(crate) fn rgb_to_cmyk(rgb: &Rgb) -> Cmyk {
   let k = RATIO_MAX - rgb_r.max().0;
   let x = RATIO_MAX - k;
 
+  if x == ALL_MIN {
+    return CmykRatio::new(ALL_MIN, ALL_MIN, ALL_MIN, k, rgb.units.alpha.get_f64()).into();
+  }
+
   let c = (x - r) / x;
   let m = (x - g) / x;
   let y = (x - b) / x;
@@ -45,5 +49,8 @@ mod test {
     assert_eq!(cmyk.magenta().round(), 92.0);
     assert_eq!(cmyk.yellow().round(), 70.0);
     assert_eq!(cmyk.key().round(), 10.0);
+
+    let black: [f64; 4] = rgb_to_cmyk(&Rgb::default()).into();
+    assert_eq!(black, [0.0, 0.0, 0.0, 100.0]);
   }
 }
@@ -0,0 +1,102 @@
+use crate::ColorTuple;
+use crate::common::matrix::{mat3_mul_tuple, Mat3};
+use crate::ictcp::ICtCpTransfer;
+use crate::rec2100::{hlg_to_relative, pq_to_relative, relative_to_hlg, relative_to_pq};
+
+// ITU-R BT.2100, linear Rec.2020 RGB to LMS
+static RGB_TO_LMS: Mat3 = [
+  [1688.0 / 4096.0, 2146.0 / 4096.0, 262.0 / 4096.0],
+  [683.0 / 4096.0, 2951.0 / 4096.0, 462.0 / 4096.0],
+  [99.0 / 4096.0, 309.0 / 4096.0, 3688.0 / 4096.0],
+];
+
+static PQ_LMS_TO_ICTCP: Mat3 = [
+  [0.5, 0.5, 0.0],
+  [6610.0 / 4096.0, -13613.0 / 4096.0, 7003.0 / 4096.0],
+  [17933.0 / 4096.0, -17390.0 / 4096.0, -543.0 / 4096.0],
+];
+
+static HLG_LMS_TO_ICTCP: Mat3 = [
+  [0.5, 0.5, 0.0],
+  [3625.0 / 4096.0, -7465.0 / 4096.0, 3840.0 / 4096.0],
+  [9500.0 / 4096.0, -9212.0 / 4096.0, -288.0 / 4096.0],
+];
+
+// inverses of the matrices above
+static LMS_TO_RGB: Mat3 = [
+  [3.436_606_694_333_078_4, -2.506_452_118_656_27, 0.069_845_424_323_191_48],
+  [-0.791_329_555_598_928_7, 1.983_600_451_792_290_7, -0.192_270_896_193_362],
+  [-0.025_949_899_690_592_672, -0.098_913_714_711_726_44, 1.124_863_614_402_319_2],
+];
+
+static ICTCP_TO_PQ_LMS: Mat3 = [
+  [1.0, 0.008_609_037_037_932_756, 0.111_029_625_003_025_96],
+  [1.0, -0.008_609_037_037_932_756, -0.111_029_625_003_025_96],
+  [1.0, 0.560_031_335_710_679_1, -0.320_627_174_987_318_85],
+];
+
+static ICTCP_TO_HLG_LMS: Mat3 = [
+  [1.0, 0.015_718_580_108_730_413, 0.209_581_068_116_405_5],
+  [1.0, -0.015_718_580_108_730_413, -0.209_581_068_116_405_5],
+  [1.0, 1.021_271_079_842_234_2, -0.605_274_490_992_431_5],
+];
+
+fn lms_to_ictcp_matrix(transfer: &ICtCpTransfer) -> &'static Mat3 {
+  match transfer {
+    ICtCpTransfer::Pq => &PQ_LMS_TO_ICTCP,
+    ICtCpTransfer::Hlg => &HLG_LMS_TO_ICTCP,
+  }
+}
+
+fn ictcp_to_lms_matrix(transfer: &ICtCpTransfer) -> &'static Mat3 {
+  match transfer {
+    ICtCpTransfer::Pq => &ICTCP_TO_PQ_LMS,
+    ICtCpTransfer::Hlg => &ICTCP_TO_HLG_LMS,
+  }
+}
+
+/// Takes linear Rec.2020 RGB values relative to HDR reference white,
+/// returns `I`, `Ct`, `Cp`
+pub(crate) fn linear_to_ictcp(lin: &ColorTuple, transfer: &ICtCpTransfer) -> ColorTuple {
+  let encode = match transfer {
+    ICtCpTransfer::Pq => relative_to_pq,
+    ICtCpTransfer::Hlg => relative_to_hlg,
+  };
+  let (l, m, s) = mat3_mul_tuple(&RGB_TO_LMS, lin);
+  mat3_mul_tuple(lms_to_ictcp_matrix(transfer), &(encode(l), encode(m), encode(s)))
+}
+
+/// Inverse of `linear_to_ictcp`
+pub(crate) fn ictcp_to_linear(ictcp: &ColorTuple, transfer: &ICtCpTransfer) -> ColorTuple {
+  let decode = match transfer {
+    ICtCpTransfer::Pq => pq_to_relative,
+    ICtCpTransfer::Hlg => hlg_to_relative,
+  };
+  let (l, m, s) = mat3_mul_tuple(ictcp_to_lms_matrix(transfer), ictcp);
+  mat3_mul_tuple(&LMS_TO_RGB, &(decode(l), decode(m), decode(s)))
+}
+
+#[cfg(test)]
+mod test {
+  use super::{ictcp_to_linear, linear_to_ictcp};
+  use crate::common::approx::approx;
+  use crate::ictcp::ICtCpTransfer;
+
+  #[test]
+  fn linear_ictcp_test() {
+    for transfer in [ICtCpTransfer::Pq, ICtCpTransfer::Hlg] {
+      let asserts = [(0.0, 0.0, 0.0), (1.0, 1.0, 1.0), (0.3, 0.6, 0.1), (2.5, 0.2, 0.05), (0.001, 0.002, 0.01)];
+      for lin in asserts.iter() {
+        let back = ictcp_to_linear(&linear_to_ictcp(lin, &transfer), &transfer);
+        assert!(approx(back.0, lin.0, 1e-9) && approx(back.1, lin.1, 1e-9) && approx(back.2, lin.2, 1e-9));
+      }
+      // neutrals have no chroma
+      let (_, ct, cp) = linear_to_ictcp(&(0.4, 0.4, 0.4), &transfer);
+      assert!(approx(ct, 0.0, 1e-12) && approx(cp, 0.0, 1e-12));
+    }
+    let white_pq = linear_to_ictcp(&(1.0, 1.0, 1.0), &ICtCpTransfer::Pq);
+    assert!(approx(white_pq.0, 0.580_688, 1e-6));
+    let white_hlg = linear_to_ictcp(&(1.0, 1.0, 1.0), &ICtCpTransfer::Hlg);
+    assert!(approx(white_hlg.0, 0.75, 1e-9));
+  }
+}
@@ -0,0 +1,359 @@
+//! Okhsl and Okhsv by Björn Ottosson, see <https://bottosson.github.io/posts/colorpicker/>.
+//! Functions fit the sRGB gamut with polynomial approximations, which are valid for the
+//! original Oklab matrices, so those are used here instead of the ones of `xyz_oklab`.
+use crate::{ColorTuple, Rgb};
+use crate::common::{f64_atan2, f64_cbrt, f64_cos, f64_sin, f64_sqrt};
+use crate::common::matrix::{mat3_mul_tuple, Mat3};
+use crate::consts::{HUE_MAX, PERCENT_MAX, RGB_UNIT_MAX};
+use crate::normalize::bound_hue;
+use crate::rgb::new_rgb_units;
+use crate::transfer::{linear_to_srgb, srgb_to_linear};
+use crate::units::Units;
+
+static LINEAR_SRGB_TO_LMS: Mat3 = [
+  [0.412_221_470_8, 0.536_332_536_3, 0.051_445_992_9],
+  [0.211_903_498_2, 0.680_699_545_1, 0.107_396_956_6],
+  [0.088_302_461_9, 0.281_718_837_6, 0.629_978_700_5],
+];
+
+static LMS_TO_OKLAB: Mat3 = [
+  [0.210_454_255_3, 0.793_617_785, -0.004_072_046_8],
+  [1.977_998_495_1, -2.428_592_205, 0.450_593_709_9],
+  [0.025_904_037_1, 0.782_771_766_2, -0.808_675_766],
+];
+
+static OKLAB_TO_LMS: Mat3 = [
+  [1.0, 0.396_337_777_4, 0.215_803_757_3],
+  [1.0, -0.105_561_345_8, -0.063_854_172_8],
+  [1.0, -0.089_484_177_5, -1.291_485_548],
+];
+
+static LMS_TO_LINEAR_SRGB: Mat3 = [
+  [4.076_741_662_1, -3.307_711_591_3, 0.230_969_929_2],
+  [-1.268_438_004_6, 2.609_757_401_1, -0.341_319_396_5],
+  [-0.004_196_086_3, -0.703_418_614_7, 1.707_614_701],
+];
+
+// grays get a chroma of about 1e-8 because of rounded matrices,
+// chroma below this value is considered as zero, hue is powerless then
+const ACHROMATIC_THRESHOLD: f64 = 1e-6;
+
+// lightness estimate which makes Okhsl lightness close to CIE L*
+const TOE_K1: f64 = 0.206;
+const TOE_K2: f64 = 0.03;
+const TOE_K3: f64 = (1.0 + TOE_K1) / (1.0 + TOE_K2);
+
+fn toe(x: f64) -> f64 {
+  let d = TOE_K3 * x - TOE_K1;
+  0.5 * (d + f64_sqrt(d * d + 4.0 * TOE_K2 * TOE_K3 * x))
+}
+
+fn toe_inv(x: f64) -> f64 {
+  (x * x + TOE_K1 * x) / (TOE_K3 * (x + TOE_K2))
+}
+
+fn linear_srgb_to_oklab(lin: &ColorTuple) -> ColorTuple {
+  let (l, m, s) = mat3_mul_tuple(&LINEAR_SRGB_TO_LMS, lin);
+  mat3_mul_tuple(&LMS_TO_OKLAB, &(f64_cbrt(l), f64_cbrt(m), f64_cbrt(s)))
+}
+
+fn oklab_to_linear_srgb(lab: &ColorTuple) -> ColorTuple {
+  let (l, m, s) = mat3_mul_tuple(&OKLAB_TO_LMS, lab);
+  mat3_mul_tuple(&LMS_TO_LINEAR_SRGB, &(l * l * l, m * m * m, s * s * s))
+}
+
+// maximum saturation `C / L` within sRGB for hue given by `a`, `b` with `a² + b² = 1`
+fn max_saturation(a: f64, b: f64) -> f64 {
+  // which of linear r, g or b goes below zero first
+  let (k, w) = if -1.881_703_28 * a - 0.809_364_93 * b > 1.0 {
+    ([1.190_862_77, 1.765_767_28, 0.596_626_41, 0.755_151_97, 0.567_712_45], LMS_TO_LINEAR_SRGB[0])
+  } else if 1.814_441_04 * a - 1.194_452_76 * b > 1.0 {
+    ([0.739_565_15, -0.459_544_04, 0.082_854_27, 0.125_410_7, 0.145_032_04], LMS_TO_LINEAR_SRGB[1])
+  } else {
+    ([1.357_336_52, -0.009_157_99, -1.151_302_1, -0.505_596_06, 0.006_921_67], LMS_TO_LINEAR_SRGB[2])
+  };
+  let s = k[0] + k[1] * a + k[2] * b + k[3] * a * a + k[4] * a * b;
+
+  // one step of Halley's method
+  let (kl, km, ks) = mat3_mul_tuple(&OKLAB_TO_LMS, &(0.0, a, b));
+  let (l_, m_, s_) = (1.0 + s * kl, 1.0 + s * km, 1.0 + s * ks);
+  let f = w[0] * l_ * l_ * l_ + w[1] * m_ * m_ * m_ + w[2] * s_ * s_ * s_;
+  let f1 = 3.0 * (w[0] * kl * l_ * l_ + w[1] * km * m_ * m_ + w[2] * ks * s_ * s_);
+  let f2 = 6.0 * (w[0] * kl * kl * l_ + w[1] * km * km * m_ + w[2] * ks * ks * s_);
+  s - f * f1 / (f1 * f1 - 0.5 * f * f2)
+}
+
+// lightness and chroma of the most saturated color of sRGB with given hue
+fn find_cusp(a: f64, b: f64) -> (f64, f64) {
+  let s_cusp = max_saturation(a, b);
+  let (r, g, b) = oklab_to_linear_srgb(&(1.0, s_cusp * a, s_cusp * b));
+  let l_cusp = f64_cbrt(1.0 / r.max(g).max(b));
+  (l_cusp, l_cusp * s_cusp)
+}
+
+// finds `t` where the line `L = L0 * (1 - t) + t * L1, C = t * C1` leaves sRGB gamut
+fn gamut_intersection(a: f64, b: f64, l1: f64, c1: f64, l0: f64, cusp: (f64, f64)) -> f64 {
+  let (cusp_l, cusp_c) = cusp;
+  if (l1 - l0) * cusp_c - (cusp_l - l0) * c1 <= 0.0 {
+    // lower half
+    return cusp_c * l0 / (c1 * cusp_l + cusp_c * (l0 - l1));
+  }
+
+  // upper half: intersection with triangle, then one step of Halley's method
+  let t = cusp_c * (l0 - 1.0) / (c1 * (cusp_l - 1.0) + cusp_c * (l0 - l1));
+  let (kl, km, ks) = mat3_mul_tuple(&OKLAB_TO_LMS, &(0.0, a, b));
+  let dl = l1 - l0;
+  let (l_dt, m_dt, s_dt) = (dl + c1 * kl, dl + c1 * km, dl + c1 * ks);
+
+  let l = l0 * (1.0 - t) + t * l1;
+  let c = t * c1;
+  let (l_, m_, s_) = (l + c * kl, l + c * km, l + c * ks);
+  let lms = (l_ * l_ * l_, m_ * m_ * m_, s_ * s_ * s_);
+  let lms_dt = (3.0 * l_dt * l_ * l_, 3.0 * m_dt * m_ * m_, 3.0 * s_dt * s_ * s_);
+  let lms_dt2 = (6.0 * l_dt * l_dt * l_, 6.0 * m_dt * m_dt * m_, 6.0 * s_dt * s_dt * s_);
+
+  let step = |w: &[f64; 3]| {
+    let dot = |v: &ColorTuple| w[0] * v.0 + w[1] * v.1 + w[2] * v.2;
+    let (f, f1, f2) = (dot(&lms) - 1.0, dot(&lms_dt), dot(&lms_dt2));
+    let u = f1 / (f1 * f1 - 0.5 * f * f2);
+    if u >= 0.0 { -f * u } else { f64::MAX }
+  };
+  let [wr, wg, wb] = &LMS_TO_LINEAR_SRGB;
+  t + step(wr).min(step(wg)).min(step(wb))
+}
+
+// cusp as ratios `C / L` and `C / (1 - L)`
+fn to_st(cusp: (f64, f64)) -> (f64, f64) {
+  let (l, c) = cusp;
+  (c / l, c / (1.0 - l))
+}
+
+// smooth approximation of the cusp as ratios `C / L` and `C / (1 - L)`
+fn st_mid(a: f64, b: f64) -> (f64, f64) {
+  let s = 0.115_169_93
+    + 1.0
+      / (7.447_789_7
+        + 4.159_012_4 * b
+        + a * (-2.195_573_47
+          + 1.751_984_01 * b
+          + a * (-2.137_049_48 - 10.023_010_43 * b + a * (-4.248_945_61 + 5.387_708_19 * b + 4.698_910_13 * a))));
+  let t = 0.112_396_42
+    + 1.0
+      / (1.613_203_2 - 0.681_243_79 * b
+        + a * (0.403_706_12
+          + 0.901_481_23 * b
+          + a * (-0.270_879_43 + 0.612_239_9 * b + a * (0.002_992_15 - 0.453_995_68 * b - 0.146_618_72 * a))));
+  (s, t)
+}
+
+// chroma of Okhsl saturation `0.0`(as a limit), `0.8` and `1.0` at lightness `l`
+fn chroma_stops(l: f64, a: f64, b: f64) -> (f64, f64, f64) {
+  let cusp = find_cusp(a, b);
+  let c_max = gamut_intersection(a, b, l, 1.0, l, cusp);
+  let (s_max, t_max) = to_st(cusp);
+  // compensates curved part of the gamut
+  let k = c_max / (l * s_max).min((1.0 - l) * t_max);
+
+  let (s_mid, t_mid) = st_mid(a, b);
+  let (ca, cb) = (l * s_mid, (1.0 - l) * t_mid);
+  let c_mid = 0.9 * k * f64_sqrt(f64_sqrt(1.0 / (1.0 / (ca * ca * ca * ca) + 1.0 / (cb * cb * cb * cb))));
+
+  let (ca, cb) = (l * 0.4, (1.0 - l) * 0.8);
+  let c_0 = f64_sqrt(1.0 / (1.0 / (ca * ca) + 1.0 / (cb * cb)));
+  (c_0, c_mid, c_max)
+}
+
+// Okhsl saturation at which chroma reaches `c_mid`
+const MID: f64 = 0.8;
+
+// normalized `a`, `b`, chroma and hue in degrees of Oklab color
+fn hue_of(lab: &ColorTuple) -> (f64, f64, f64, f64) {
+  let c = f64_sqrt(lab.1 * lab.1 + lab.2 * lab.2);
+  if c < ACHROMATIC_THRESHOLD {
+    return (1.0, 0.0, 0.0, 0.0);
+  }
+  let h = bound_hue(f64_atan2(lab.2, lab.1) * HUE_MAX / (2.0 * core::f64::consts::PI));
+  (lab.1 / c, lab.2 / c, c, h)
+}
+
+fn hue_to_ab(h: f64) -> (f64, f64) {
+  let rad = h * 2.0 * core::f64::consts::PI / HUE_MAX;
+  (f64_cos(rad), f64_sin(rad))
+}
+
+fn encode(lin: &ColorTuple) -> ColorTuple {
+  (linear_to_srgb(lin.0), linear_to_srgb(lin.1), linear_to_srgb(lin.2))
+}
+
+fn decode(rgb: &ColorTuple) -> ColorTuple {
+  (srgb_to_linear(rgb.0), srgb_to_linear(rgb.1), srgb_to_linear(rgb.2))
+}
+
+// takes sRGB ratios, returns Okhsl hue in degrees, saturation and lightness in `0.0..1.0`
+fn srgb_to_okhsl(rgb: &ColorTuple) -> ColorTuple {
+  let lab = linear_srgb_to_oklab(&decode(rgb));
+  let l = lab.0;
+  let (a, b, c, h) = hue_of(&lab);
+  if c == 0.0 || l <= 0.0 || l >= 1.0 {
+    return (h, 0.0, toe(l).clamp(0.0, 1.0));
+  }
+
+  let (c_0, c_mid, c_max) = chroma_stops(l, a, b);
+  let s = if c < c_mid {
+    let k1 = MID * c_0;
+    let k2 = 1.0 - k1 / c_mid;
+    MID * c / (k1 + k2 * c)
+  } else {
+    let k1 = (1.0 - MID) * c_mid * c_mid / (MID * MID * c_0);
+    let k2 = 1.0 - k1 / (c_max - c_mid);
+    let t = (c - c_mid) / (k1 + k2 * (c - c_mid));
+    MID + (1.0 - MID) * t
+  };
+  (h, s, toe(l))
+}
+
+fn okhsl_to_srgb(hsl: &ColorTuple) -> ColorTuple {
+  let (h, s, l) = *hsl;
+  if l >= 1.0 {
+    return (1.0, 1.0, 1.0);
+  }
+  if l <= 0.0 {
+    return (0.0, 0.0, 0.0);
+  }
+  let (a, b) = hue_to_ab(h);
+  let big_l = toe_inv(l);
+  if s <= 0.0 {
+    return encode(&oklab_to_linear_srgb(&(big_l, 0.0, 0.0)));
+  }
+
+  let (c_0, c_mid, c_max) = chroma_stops(big_l, a, b);
+  let c = if s < MID {
+    let t = s / MID;
+    let k1 = MID * c_0;
+    let k2 = 1.0 - k1 / c_mid;
+    t * k1 / (1.0 - k2 * t)
+  } else {
+    let t = (s - MID) / (1.0 - MID);
+    let k1 = (1.0 - MID) * c_mid * c_mid / (MID * MID * c_0);
+    let k2 = 1.0 - k1 / (c_max - c_mid);
+    c_mid + t * k1 / (1.0 - k2 * t)
+  };
+  encode(&oklab_to_linear_srgb(&(big_l, c * a, c * b)))
+}
+
+// Okhsv saturation of the cusp
+const S_0: f64 = 0.5;
+
+// scale of lightness which compensates curved top of the gamut
+fn scale_l(l_vt: f64, c_vt: f64, a: f64, b: f64) -> f64 {
+  let (r, g, b) = oklab_to_linear_srgb(&(l_vt, a * c_vt, b * c_vt));
+  f64_cbrt(1.0 / r.max(g).max(b).max(0.0))
+}
+
+// takes sRGB ratios, returns Okhsv hue in degrees, saturation and value in `0.0..1.0`
+fn srgb_to_okhsv(rgb: &ColorTuple) -> ColorTuple {
+  let lab = linear_srgb_to_oklab(&decode(rgb));
+  let (a, b, c, h) = hue_of(&lab);
+  let l = lab.0;
+  if l <= 0.0 {
+    return (h, 0.0, 0.0);
+  }
+
+  let (s_max, t_max) = to_st(find_cusp(a, b));
+  let k = 1.0 - S_0 / s_max;
+
+  // L and C at value 1.0
+  let t = t_max / (c + l * t_max);
+  let (l_v, c_v) = (t * l, t * c);
+  let l_vt = toe_inv(l_v);
+  let c_vt = c_v * l_vt / l_v;
+
+  let scale = scale_l(l_vt, c_vt, a, b);
+  let l = l / scale;
+  let v = toe(l) / l_v;
+  let s = (S_0 + t_max) * c_v / (t_max * S_0 + t_max * k * c_v);
+  (h, s, v)
+}
+
+fn okhsv_to_srgb(hsv: &ColorTuple) -> ColorTuple {
+  let (h, s, v) = *hsv;
+  if v <= 0.0 {
+    return (0.0, 0.0, 0.0);
+  }
+  let (a, b) = hue_to_ab(h);
+  let (s_max, t_max) = to_st(find_cusp(a, b));
+  let k = 1.0 - S_0 / s_max;
+
+  // L and C at value 1.0, as if the gamut was a triangle
+  let d = S_0 + t_max - t_max * k * s;
+  let l_v = 1.0 - s * S_0 / d;
+  let c_v = s * t_max * S_0 / d;
+
+  // compensate the toe and the curved top of the gamut
+  let l_vt = toe_inv(l_v);
+  let c_vt = c_v * l_vt / l_v;
+  let l = toe_inv(v * l_v);
+  let c = v * c_v * l / (v * l_v);
+
+  let scale = scale_l(l_vt, c_vt, a, b);
+  encode(&oklab_to_linear_srgb(&(l * scale, c * scale * a, c * scale * b)))
+}
+
+fn to_percents(t: &ColorTuple) -> ColorTuple {
+  (t.0, t.1 * PERCENT_MAX, t.2 * PERCENT_MAX)
+}
+
+fn to_rgb_units(t: &ColorTuple) -> Units {
+  let mut units = new_rgb_units(t.0 * RGB_UNIT_MAX, t.1 * RGB_UNIT_MAX, t.2 * RGB_UNIT_MAX);
+  units.restrict();
+  units
+}
+
+/// Returns Okhsl hue, saturation and lightness of sRGB color
+pub(crate) fn rgb_to_okhsl(rgb: &Rgb) -> ColorTuple {
+  let ratio: [f64; 3] = rgb.units.as_ratio().into();
+  to_percents(&srgb_to_okhsl(&ratio.into()))
+}
+
+/// Inverse of `rgb_to_okhsl`
+pub(crate) fn okhsl_to_rgb(hsl: &ColorTuple) -> Units {
+  let (h, s, l) = *hsl;
+  to_rgb_units(&okhsl_to_srgb(&(h, s / PERCENT_MAX, l / PERCENT_MAX)))
+}
+
+/// Returns Okhsv hue, saturation and value of sRGB color
+pub(crate) fn rgb_to_okhsv(rgb: &Rgb) -> ColorTuple {
+  let ratio: [f64; 3] = rgb.units.as_ratio().into();
+  to_percents(&srgb_to_okhsv(&ratio.into()))
+}
+
+/// Inverse of `rgb_to_okhsv`
+pub(crate) fn okhsv_to_rgb(hsv: &ColorTuple) -> Units {
+  let (h, s, v) = *hsv;
+  to_rgb_units(&okhsv_to_srgb(&(h, s / PERCENT_MAX, v / PERCENT_MAX)))
+}
+
+#[cfg(test)]
+mod test {
+  use super::{okhsl_to_srgb, okhsv_to_srgb, srgb_to_okhsl, srgb_to_okhsv, toe, toe_inv};
+  use crate::common::approx::approx;
+
+  #[test]
+  fn toe_test() {
+    for x in [0.0, 0.01, 0.2, 0.5, 0.9, 1.0] {
+      assert!(approx(toe_inv(toe(x)), x, 1e-12));
+    }
+    assert!(approx(toe(1.0), 1.0, 1e-12));
+  }
+
+  #[test]
+  fn okhsl_okhsv_round_trip_test() {
+    let asserts = [(1.0, 0.0, 0.0), (0.2, 0.9, 0.4), (0.13, 0.44, 0.92), (0.5, 0.5, 0.5), (0.01, 0.02, 0.0)];
+    for rgb in asserts.iter() {
+      let back = okhsl_to_srgb(&srgb_to_okhsl(rgb));
+      assert!(approx(back.0, rgb.0, 1e-6) && approx(back.1, rgb.1, 1e-6) && approx(back.2, rgb.2, 1e-6));
+      let back = okhsv_to_srgb(&srgb_to_okhsv(rgb));
+      assert!(approx(back.0, rgb.0, 1e-6) && approx(back.1, rgb.1, 1e-6) && approx(back.2, rgb.2, 1e-6));
+    }
+  }
+}
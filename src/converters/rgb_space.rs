@@ -0,0 +1,84 @@
+use crate::ColorTuple;
+use crate::common::matrix::{mat3_diag, mat3_inverse, mat3_mul, mat3_mul_tuple, Mat3};
+use crate::WhitePoint;
+
+/// CIE 1931 chromaticity coordinates `(x, y)` of red, green and blue primaries
+pub(crate) type Primaries = [(f64, f64); 3];
+
+pub(crate) static DISPLAY_P3_PRIMARIES: Primaries = [(0.680, 0.320), (0.265, 0.690), (0.150, 0.060)];
+pub(crate) static REC2020_PRIMARIES: Primaries = [(0.708, 0.292), (0.170, 0.797), (0.131, 0.046)];
+pub(crate) static A98_PRIMARIES: Primaries = [(0.640, 0.330), (0.210, 0.710), (0.150, 0.060)];
+pub(crate) static PROPHOTO_PRIMARIES: Primaries =
+  [(0.734_699, 0.265_301), (0.159_597, 0.840_403), (0.036_598, 0.000_105)];
+pub(crate) static ACES_AP0_PRIMARIES: Primaries = [(0.734_7, 0.265_3), (0.0, 1.0), (0.000_1, -0.077_0)];
+pub(crate) static ACES_AP1_PRIMARIES: Primaries = [(0.713, 0.293), (0.165, 0.830), (0.128, 0.044)];
+
+/// White of ACES color spaces (~6000K)
+pub(crate) const ACES_WHITE: WhitePoint = WhitePoint::Custom(0.321_68, 0.337_67);
+
+// linear values which differ from `0.0..1.0` less than this are treated as in gamut
+const GAMUT_EPSILON: f64 = 1e-7;
+
+/// Returns matrix which converts linear RGB values with given primaries and white point
+/// to XYZ values relative to the same white. Returns `None` if primaries are collinear
+pub(crate) fn rgb_to_xyz_matrix(primaries: &Primaries, white: &WhitePoint) -> Option<Mat3> {
+  let [r, g, b] = primaries.map(|(x, y)| (x / y, 1.0, (1.0 - x - y) / y));
+  let m: Mat3 = [[r.0, g.0, b.0], [r.1, g.1, b.1], [r.2, g.2, b.2]];
+  let scale = mat3_mul_tuple(&mat3_inverse(&m)?, &white.xyz());
+  Some(mat3_mul(&m, &mat3_diag(&scale)))
+}
+
+/// Inverse of `rgb_to_xyz_matrix`
+pub(crate) fn xyz_to_rgb_matrix(primaries: &Primaries, white: &WhitePoint) -> Option<Mat3> {
+  mat3_inverse(&rgb_to_xyz_matrix(primaries, white)?)
+}
+
+/// Converts linear RGB values of built-in color space to XYZ values relative to `white`
+pub(crate) fn linear_to_xyz(lin: &ColorTuple, primaries: &Primaries, white: &WhitePoint) -> ColorTuple {
+  let m = rgb_to_xyz_matrix(primaries, white).expect("primaries of built-in space are valid");
+  mat3_mul_tuple(&m, lin)
+}
+
+/// Inverse of `linear_to_xyz`. Values are not clipped
+pub(crate) fn xyz_to_linear(xyz: &ColorTuple, primaries: &Primaries, white: &WhitePoint) -> ColorTuple {
+  let m = xyz_to_rgb_matrix(primaries, white).expect("primaries of built-in space are valid");
+  mat3_mul_tuple(&m, xyz)
+}
+
+/// Checks that all linear RGB values are in `0.0..1.0`
+pub(crate) fn in_unit_cube(t: &ColorTuple) -> bool {
+  let range = -GAMUT_EPSILON..=1.0 + GAMUT_EPSILON;
+  range.contains(&t.0) && range.contains(&t.1) && range.contains(&t.2)
+}
+
+#[cfg(test)]
+mod test {
+  use super::{in_unit_cube, rgb_to_xyz_matrix, xyz_to_rgb_matrix, DISPLAY_P3_PRIMARIES};
+  use crate::common::approx::approx;
+  use crate::WhitePoint;
+
+  #[test]
+  fn display_p3_matrix_test() {
+    // CSS Color Module Level 4
+    let expected = [
+      [608_311.0 / 1_250_200.0, 189_793.0 / 714_400.0, 198_249.0 / 1_000_160.0],
+      [35_783.0 / 156_275.0, 247_089.0 / 357_200.0, 198_249.0 / 2_500_400.0],
+      [0.0, 32_229.0 / 714_400.0, 5_220_557.0 / 5_000_800.0],
+    ];
+    let m = rgb_to_xyz_matrix(&DISPLAY_P3_PRIMARIES, &WhitePoint::D65).unwrap();
+    for (row, exp_row) in m.iter().zip(expected.iter()) {
+      for (v, exp) in row.iter().zip(exp_row.iter()) {
+        assert!(approx(*v, *exp, 1e-12));
+      }
+    }
+    assert!(xyz_to_rgb_matrix(&[(0.1, 0.1), (0.2, 0.2), (0.3, 0.3)], &WhitePoint::D65).is_none());
+  }
+
+  #[test]
+  fn in_unit_cube_test() {
+    assert!(in_unit_cube(&(0.0, 0.5, 1.0)));
+    assert!(in_unit_cube(&(-1e-12, 0.5, 1.0 + 1e-12)));
+    assert!(!in_unit_cube(&(-0.01, 0.5, 1.0)));
+    assert!(!in_unit_cube(&(0.0, 1.01, 1.0)));
+  }
+}
@@ -0,0 +1,58 @@
+use crate::Rgb;
+use crate::consts::{ALL_MIN, PERCENT_MAX};
+use crate::hsv::new_hsv_units;
+use crate::units::Units;
+
+pub fn rgb_to_hsv(rgb: &Rgb) -> Units {
+  let rgb_r = rgb.units.as_ratio();
+
+  let (max, max_unit) = rgb_r.max();
+  let (min, _) = rgb_r.min();
+  let value = max;
+
+  if max.eq(&min) {
+    return new_hsv_units(ALL_MIN, ALL_MIN, value * PERCENT_MAX);
+  }
+
+  let max_min_delta = max - min;
+  let saturation = max_min_delta / max;
+
+  let [red, green, blue]: [f64; 3] = rgb_r.into();
+
+  let hue = match max_unit {
+    // red
+    0 => {
+      let x = if green < blue { 6.0 } else { ALL_MIN };
+      (green - blue) / max_min_delta + x
+    }
+    // green
+    1 => (blue - red) / max_min_delta + 2.0,
+    // blue
+    2 => (red - green) / max_min_delta + 4.0,
+    _ => { unreachable!() }
+  };
+
+  new_hsv_units(hue * 60.0, saturation * PERCENT_MAX, value * PERCENT_MAX)
+}
+
+#[test]
+fn rgb_to_hsv_tst() {
+  use crate::{ColorTuple, Rgb, ApproxEq};
+  fn a(x: ColorTuple, y: ColorTuple) -> bool {
+    let from_rgb_u = rgb_to_hsv(&Rgb::from(x));
+    let hsv_u = new_hsv_units(y.0, y.1, y.2);
+    from_rgb_u.approx_eq_clarify(&hsv_u, 0.5)
+  }
+  let asserts = [
+    ((255.0, 255.0, 255.0), (0.0, 0.0, 100.0)),
+    ((0.0, 0.0, 0.0), (0.0, 0.0, 0.0)),
+    ((215.0, 231.0, 236.0), (194.0, 8.9, 92.5)),
+    ((108.0, 225.0, 36.0), (97.1, 84.0, 88.2)),
+    ((215.0, 0.0, 99.0), (332.4, 100.0, 84.3)),
+    ((10.0, 10.0, 10.0), (0.0, 0.0, 3.9)),
+  ];
+
+  asserts.iter().for_each(|tuples| {
+    assert!(a(tuples.0, tuples.1));
+  });
+}
@@ -0,0 +1,73 @@
+use crate::{ColorTuple, Rgb};
+use crate::common::matrix::{mat3_mul_tuple, Mat3};
+use crate::consts::RGB_UNIT_MAX;
+use crate::rgb::new_rgb_units;
+use crate::transfer::{linear_to_srgb, srgb_to_linear};
+use crate::units::Units;
+
+// linear sRGB <-> CIE XYZ (D65), as in CSS Color Module Level 4
+static SRGB_TO_XYZ: Mat3 = [
+  [506_752.0 / 1_228_815.0, 87_881.0 / 245_763.0, 12_673.0 / 70_218.0],
+  [87_098.0 / 409_605.0, 175_762.0 / 245_763.0, 12_673.0 / 175_545.0],
+  [7_918.0 / 409_605.0, 87_881.0 / 737_289.0, 1_001_167.0 / 1_053_270.0],
+];
+
+pub(super) static XYZ_TO_SRGB: Mat3 = [
+  [12_831.0 / 3_959.0, -329.0 / 214.0, -1_974.0 / 3_959.0],
+  [-851_781.0 / 878_810.0, 1_648_619.0 / 878_810.0, 36_519.0 / 878_810.0],
+  [705.0 / 12_673.0, -2_585.0 / 12_673.0, 705.0 / 667.0],
+];
+
+/// Takes linear sRGB ratios and returns XYZ (D65) values
+pub(crate) fn linear_rgb_to_xyz(lin: &ColorTuple) -> ColorTuple {
+  mat3_mul_tuple(&SRGB_TO_XYZ, lin)
+}
+
+/// Inverse of `linear_rgb_to_xyz`. Values are not clipped
+pub(crate) fn xyz_to_linear_rgb(xyz: &ColorTuple) -> ColorTuple {
+  mat3_mul_tuple(&XYZ_TO_SRGB, xyz)
+}
+
+/// Returns XYZ (D65) values of color. Luminance `Y` of white is `1.0`
+pub(crate) fn rgb_to_xyz(rgb: &Rgb) -> ColorTuple {
+  let [r, g, b]: [f64; 3] = rgb.units.as_ratio().into();
+  linear_rgb_to_xyz(&(srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b)))
+}
+
+/// Takes XYZ (D65) values. Colors out of sRGB gamut are clipped
+pub(crate) fn xyz_to_rgb(xyz: &ColorTuple) -> Units {
+  let (r, g, b) = xyz_to_linear_rgb(xyz);
+  let mut units = new_rgb_units(
+    linear_to_srgb(r) * RGB_UNIT_MAX,
+    linear_to_srgb(g) * RGB_UNIT_MAX,
+    linear_to_srgb(b) * RGB_UNIT_MAX,
+  );
+  units.restrict();
+  units
+}
+
+#[cfg(test)]
+mod test {
+  use crate::{ApproxEq, Rgb};
+  use crate::common::approx::approx;
+  use super::{rgb_to_xyz, xyz_to_rgb};
+
+  #[test]
+  fn rgb_xyz_test() {
+    let asserts = [
+      ([255.0, 255.0, 255.0], (0.950_456, 1.0, 1.089_058)),
+      ([255.0, 0.0, 0.0], (0.412_391, 0.212_639, 0.019_331)),
+      ([0.0, 255.0, 0.0], (0.357_584, 0.715_169, 0.119_195)),
+      ([0.0, 0.0, 255.0], (0.180_481, 0.072_192, 0.950_532)),
+      ([119.0, 0.0, 0.0], (0.076_076, 0.039_227, 0.003_566)),
+    ];
+    for (rgb_arr, (x, y, z)) in asserts.iter() {
+      let rgb = Rgb::from(rgb_arr);
+      let xyz = rgb_to_xyz(&rgb);
+      assert!(approx(xyz.0, *x, 1e-6));
+      assert!(approx(xyz.1, *y, 1e-6));
+      assert!(approx(xyz.2, *z, 1e-6));
+      assert!(xyz_to_rgb(&xyz).approx_eq_clarify(&rgb.units, 1e-9));
+    }
+  }
+}
@@ -0,0 +1,41 @@
+use crate::ColorTuple;
+
+/// Takes gamma-encoded RGB ratios and luma coefficients `(Kr, Kg, Kb)`,
+/// returns analog `(Y', Pb, Pr)`: luma in `0.0..1.0`, color differences in `-0.5..0.5`
+pub(crate) fn rgb_to_ypbpr(rgb: &ColorTuple, k: &ColorTuple) -> ColorTuple {
+  let (r, g, b) = *rgb;
+  let (kr, kg, kb) = *k;
+  let y = kr * r + kg * g + kb * b;
+  (y, (b - y) / (2.0 * (1.0 - kb)), (r - y) / (2.0 * (1.0 - kr)))
+}
+
+/// Inverse of `rgb_to_ypbpr`. Values are not clipped
+pub(crate) fn ypbpr_to_rgb(ypbpr: &ColorTuple, k: &ColorTuple) -> ColorTuple {
+  let (y, pb, pr) = *ypbpr;
+  let (kr, kg, kb) = *k;
+  let r = y + 2.0 * (1.0 - kr) * pr;
+  let b = y + 2.0 * (1.0 - kb) * pb;
+  (r, (y - kr * r - kb * b) / kg, b)
+}
+
+#[cfg(test)]
+mod test {
+  use super::{rgb_to_ypbpr, ypbpr_to_rgb};
+  use crate::common::approx::approx;
+  use crate::rgb::{REC709_FACTORS, YUV_FACTORS};
+
+  #[test]
+  fn rgb_to_ypbpr_test() {
+    let (y, pb, pr) = rgb_to_ypbpr(&(1.0, 0.0, 0.0), &YUV_FACTORS);
+    assert!(approx(y, 0.299, 1e-12));
+    assert!(approx(pb, -0.168_735_891_647_855_5, 1e-12));
+    assert!(approx(pr, 0.5, 1e-12));
+
+    let (y, pb, pr) = rgb_to_ypbpr(&(1.0, 1.0, 1.0), &REC709_FACTORS);
+    assert!(approx(y, 1.0, 1e-12) && approx(pb, 0.0, 1e-12) && approx(pr, 0.0, 1e-12));
+
+    let rgb = (0.2, 0.7, 0.4);
+    let (r, g, b) = ypbpr_to_rgb(&rgb_to_ypbpr(&rgb, &REC709_FACTORS), &REC709_FACTORS);
+    assert!(approx(r, rgb.0, 1e-12) && approx(g, rgb.1, 1e-12) && approx(b, rgb.2, 1e-12));
+  }
+}
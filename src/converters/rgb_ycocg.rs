@@ -0,0 +1,59 @@
+use crate::ColorTuple;
+
+/// Takes RGB values, returns YCoCg in the same scale
+pub(crate) fn rgb_to_ycocg(rgb: &ColorTuple) -> ColorTuple {
+  let (r, g, b) = *rgb;
+  (r / 4.0 + g / 2.0 + b / 4.0, (r - b) / 2.0, g / 2.0 - (r + b) / 4.0)
+}
+
+/// Inverse of `rgb_to_ycocg`. Values are not clipped
+pub(crate) fn ycocg_to_rgb(ycocg: &ColorTuple) -> ColorTuple {
+  let (y, co, cg) = *ycocg;
+  let t = y - cg;
+  (t + co, y + cg, t - co)
+}
+
+/// Lossless (lifting-based) YCoCg-R transform of integer RGB values.
+/// `co` and `cg` need one bit more than RGB values
+pub(crate) fn rgb_to_ycocg_r(rgb: &[i32; 3]) -> [i32; 3] {
+  let [r, g, b] = *rgb;
+  let co = r - b;
+  let t = b + (co >> 1);
+  let cg = g - t;
+  [t + (cg >> 1), co, cg]
+}
+
+/// Inverse of `rgb_to_ycocg_r`
+pub(crate) fn ycocg_r_to_rgb(ycocg: &[i32; 3]) -> [i32; 3] {
+  let [y, co, cg] = *ycocg;
+  let t = y - (cg >> 1);
+  let g = cg + t;
+  let b = t - (co >> 1);
+  [b + co, g, b]
+}
+
+#[cfg(test)]
+mod test {
+  use super::{rgb_to_ycocg, rgb_to_ycocg_r, ycocg_r_to_rgb, ycocg_to_rgb};
+
+  #[test]
+  fn rgb_to_ycocg_test() {
+    assert_eq!(rgb_to_ycocg(&(1.0, 0.0, 0.0)), (0.25, 0.5, -0.25));
+    assert_eq!(rgb_to_ycocg(&(1.0, 1.0, 1.0)), (1.0, 0.0, 0.0));
+    assert_eq!(ycocg_to_rgb(&rgb_to_ycocg(&(0.25, 0.5, 0.75))), (0.25, 0.5, 0.75));
+  }
+
+  #[test]
+  fn rgb_to_ycocg_r_test() {
+    assert_eq!(rgb_to_ycocg_r(&[255, 0, 0]), [63, 255, -127]);
+    assert_eq!(rgb_to_ycocg_r(&[0, 0, 255]), [63, -255, -127]);
+    assert_eq!(rgb_to_ycocg_r(&[255, 255, 255]), [255, 0, 0]);
+    for r in (0..256).step_by(5) {
+      for g in (0..256).step_by(3) {
+        for b in (0..256).step_by(7) {
+          assert_eq!(ycocg_r_to_rgb(&rgb_to_ycocg_r(&[r, g, b])), [r, g, b]);
+        }
+      }
+    }
+  }
+}
@@ -0,0 +1,67 @@
+use crate::ColorTuple;
+use crate::common::{f64_cos, f64_sin};
+use crate::rgb::YUV_FACTORS;
+
+// largest absolute values of U and V (BT.601)
+const U_MAX: f64 = 0.436;
+const V_MAX: f64 = 0.615;
+
+// angle between UV and IQ axes
+const IQ_ROTATION: f64 = 33.0 * core::f64::consts::PI / 180.0;
+
+/// Takes gamma-encoded RGB ratios, returns analog YUV (BT.601 luma)
+pub(crate) fn rgb_to_yuv(rgb: &ColorTuple) -> ColorTuple {
+  let (r, g, b) = *rgb;
+  let (kr, kg, kb) = YUV_FACTORS;
+  let y = kr * r + kg * g + kb * b;
+  (y, U_MAX * (b - y) / (1.0 - kb), V_MAX * (r - y) / (1.0 - kr))
+}
+
+/// Inverse of `rgb_to_yuv`. Values are not clipped
+pub(crate) fn yuv_to_rgb(yuv: &ColorTuple) -> ColorTuple {
+  let (y, u, v) = *yuv;
+  let (kr, kg, kb) = YUV_FACTORS;
+  let r = y + v * (1.0 - kr) / V_MAX;
+  let b = y + u * (1.0 - kb) / U_MAX;
+  (r, (y - kr * r - kb * b) / kg, b)
+}
+
+/// Takes gamma-encoded RGB ratios, returns YIQ (NTSC). IQ axes are UV axes rotated by 33°
+pub(crate) fn rgb_to_yiq(rgb: &ColorTuple) -> ColorTuple {
+  let (y, u, v) = rgb_to_yuv(rgb);
+  let (sin, cos) = (f64_sin(IQ_ROTATION), f64_cos(IQ_ROTATION));
+  (y, v * cos - u * sin, v * sin + u * cos)
+}
+
+/// Inverse of `rgb_to_yiq`. Values are not clipped
+pub(crate) fn yiq_to_rgb(yiq: &ColorTuple) -> ColorTuple {
+  let (y, i, q) = *yiq;
+  let (sin, cos) = (f64_sin(IQ_ROTATION), f64_cos(IQ_ROTATION));
+  yuv_to_rgb(&(y, q * cos - i * sin, i * cos + q * sin))
+}
+
+#[cfg(test)]
+mod test {
+  use super::{rgb_to_yiq, rgb_to_yuv, yiq_to_rgb, yuv_to_rgb};
+  use crate::common::approx::approx;
+
+  #[test]
+  fn rgb_to_yuv_test() {
+    let (y, u, v) = rgb_to_yuv(&(1.0, 0.0, 0.0));
+    assert!(approx(y, 0.299, 1e-12) && approx(u, -0.147_137_697_516_930_04, 1e-12) && approx(v, 0.615, 1e-12));
+    let (y, u, v) = rgb_to_yuv(&(0.0, 0.0, 1.0));
+    assert!(approx(y, 0.114, 1e-12) && approx(u, 0.436, 1e-12) && approx(v, -0.100_014_265_335_235_39, 1e-12));
+
+    // FCC NTSC matrix
+    let (y, i, q) = rgb_to_yiq(&(1.0, 0.0, 0.0));
+    assert!(approx(y, 0.299, 1e-12) && approx(i, 0.5959, 1e-4) && approx(q, 0.2115, 1e-4));
+    let (_, i, q) = rgb_to_yiq(&(0.0, 1.0, 0.0));
+    assert!(approx(i, -0.2746, 1e-4) && approx(q, -0.5227, 1e-4));
+
+    let rgb = (0.3, 0.8, 0.1);
+    let (r, g, b) = yuv_to_rgb(&rgb_to_yuv(&rgb));
+    assert!(approx(r, rgb.0, 1e-12) && approx(g, rgb.1, 1e-12) && approx(b, rgb.2, 1e-12));
+    let (r, g, b) = yiq_to_rgb(&rgb_to_yiq(&rgb));
+    assert!(approx(r, rgb.0, 1e-12) && approx(g, rgb.1, 1e-12) && approx(b, rgb.2, 1e-12));
+  }
+}
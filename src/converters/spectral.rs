@@ -0,0 +1,90 @@
+use crate::ColorTuple;
+use crate::consts::RGB_UNIT_MAX;
+use crate::rgb::new_rgb_units;
+use crate::transfer::linear_to_srgb;
+use crate::units::Units;
+
+use super::cmf::{CMF_START, CMF_STEP};
+use super::rgb_xyz::xyz_to_linear_rgb;
+
+/// Visible range (nm) covered by color matching functions tables
+pub(crate) const SPECTRAL_MIN_NM: f64 = 380.0;
+pub(crate) const SPECTRAL_MAX_NM: f64 = 780.0;
+
+// wavelength step (nm) of integration
+const INTEGRATION_STEP: f64 = 1.0;
+
+/// Returns values of color matching functions `table` at wavelength `nm`,
+/// linearly interpolated. Zeros out of the visible range
+pub(crate) fn cmf_at(table: &[[f64; 3]], nm: f64) -> ColorTuple {
+  if !(SPECTRAL_MIN_NM..=SPECTRAL_MAX_NM).contains(&nm) {
+    return (0.0, 0.0, 0.0);
+  }
+  let pos = (nm - CMF_START) / CMF_STEP;
+  let i = (pos as usize).min(table.len() - 2);
+  let t = pos - i as f64;
+  let [x0, y0, z0] = table[i];
+  let [x1, y1, z1] = table[i + 1];
+  (x0 + (x1 - x0) * t, y0 + (y1 - y0) * t, z0 + (z1 - z0) * t)
+}
+
+/// Integrates `spd` weighted by color matching functions `table` over the visible range
+pub(crate) fn integrate_spd(table: &[[f64; 3]], spd: impl Fn(f64) -> f64) -> ColorTuple {
+  let steps = ((SPECTRAL_MAX_NM - SPECTRAL_MIN_NM) / INTEGRATION_STEP) as usize;
+  let (mut x, mut y, mut z) = (0.0, 0.0, 0.0);
+  for i in 0..=steps {
+    let nm = SPECTRAL_MIN_NM + i as f64 * INTEGRATION_STEP;
+    let v = spd(nm);
+    let (xb, yb, zb) = cmf_at(table, nm);
+    x += v * xb;
+    y += v * yb;
+    z += v * zb;
+  }
+  (x, y, z)
+}
+
+/// Takes XYZ (D65) values of a color of any luminance, returns sRGB color of the same hue,
+/// as bright as possible. Colors out of gamut are desaturated towards white
+pub(crate) fn xyz_to_brightest_rgb_desaturated(xyz: &ColorTuple) -> Units {
+  let (r, g, b) = xyz_to_linear_rgb(xyz);
+  // adding equal amounts of linear components is adding white, it keeps the hue
+  let white = (-r.min(g).min(b)).max(0.0);
+  let (r, g, b) = (r + white, g + white, b + white);
+  let max = r.max(g).max(b);
+  if max <= 0.0 {
+    return new_rgb_units(0.0, 0.0, 0.0);
+  }
+  // the brightest component is set exactly, sRGB curve gives a bit less than 1.0 at 1.0
+  let encode = |c: f64| if c == max { RGB_UNIT_MAX } else { linear_to_srgb(c / max) * RGB_UNIT_MAX };
+  let mut units = new_rgb_units(encode(r), encode(g), encode(b));
+  units.restrict();
+  units
+}
+
+#[cfg(test)]
+mod test {
+  use super::{cmf_at, integrate_spd};
+  use crate::common::approx::approx;
+  use crate::converters::cmf::{CIE1931_2, CIE1964_10};
+
+  #[test]
+  fn cmf_test() {
+    let (x, y, z) = cmf_at(&CIE1931_2, 555.0);
+    assert!(approx(x, 0.512_05, 1e-9) && approx(y, 1.0, 1e-9) && approx(z, 0.005_75, 1e-9));
+    let (x, _, _) = cmf_at(&CIE1931_2, 557.5);
+    assert!(approx(x, (0.512_05 + 0.594_5) / 2.0, 1e-9));
+    assert_eq!(cmf_at(&CIE1931_2, 379.0), (0.0, 0.0, 0.0));
+    assert_eq!(cmf_at(&CIE1964_10, 781.0), (0.0, 0.0, 0.0));
+    assert!(cmf_at(&CIE1964_10, 780.0).1 > 0.0);
+  }
+
+  #[test]
+  fn equal_energy_test() {
+    // equal energy spectrum is white E
+    for table in [&CIE1931_2, &CIE1964_10] {
+      let (x, y, z) = integrate_spd(table, |_| 1.0);
+      let sum = x + y + z;
+      assert!(approx(x / sum, 1.0 / 3.0, 0.0005) && approx(y / sum, 1.0 / 3.0, 0.0005));
+    }
+  }
+}
@@ -0,0 +1,122 @@
+use crate::ColorTuple;
+use crate::cam16::viewing_conditions::{adapt_response, unadapt_response, M16, M16_INV};
+use crate::cam16::ViewingConditions;
+use crate::common::matrix::mat3_mul_tuple;
+use crate::common::{f64_atan2, f64_cos, f64_exp, f64_ln, f64_powf, f64_sin, f64_sqrt};
+use crate::consts::HUE_MAX;
+use crate::normalize::bound_hue;
+
+// coefficients of CAM16-UCS
+const UCS_C1: f64 = 0.007;
+const UCS_C2: f64 = 0.0228;
+
+fn eccentricity(h: f64) -> f64 {
+  0.25 * (f64_cos(h.to_radians() + 2.0) + 3.8)
+}
+
+/// Takes XYZ values relative to the white of viewing conditions,
+/// returns CAM16 lightness `J`, chroma `C` and hue `h` in degrees
+pub(crate) fn xyz_to_cam16(xyz: &ColorTuple, vc: &ViewingConditions) -> ColorTuple {
+  let (r, g, b) = mat3_mul_tuple(&M16, &(xyz.0 * 100.0, xyz.1 * 100.0, xyz.2 * 100.0));
+  let ra = adapt_response(vc.rgb_d.0 * r, vc.fl);
+  let ga = adapt_response(vc.rgb_d.1 * g, vc.fl);
+  let ba = adapt_response(vc.rgb_d.2 * b, vc.fl);
+
+  let a = ra + (-12.0 * ga + ba) / 11.0;
+  let b = (ra + ga - 2.0 * ba) / 9.0;
+  let h = bound_hue(f64_atan2(b, a) * HUE_MAX / (2.0 * core::f64::consts::PI));
+
+  let achromatic = (2.0 * ra + ga + 0.05 * ba) * vc.nbb;
+  let j = 100.0 * f64_powf((achromatic / vc.aw).max(0.0), vc.c * vc.z);
+
+  let p1 = 50_000.0 / 13.0 * eccentricity(h) * vc.nc * vc.ncb;
+  let t = p1 * f64_sqrt(a * a + b * b) / (ra + ga + 1.05 * ba + 0.305);
+  let alpha = f64_powf(t, 0.9) * f64_powf(1.64 - f64_powf(0.29, vc.n), 0.73);
+  (j, alpha * f64_sqrt(j / 100.0), h)
+}
+
+/// Inverse of `xyz_to_cam16`
+pub(crate) fn cam16_to_xyz(jch: &ColorTuple, vc: &ViewingConditions) -> ColorTuple {
+  let (j, c, h) = *jch;
+  let alpha = if j <= 0.0 { 0.0 } else { c / f64_sqrt(j / 100.0) };
+  let t = f64_powf(alpha / f64_powf(1.64 - f64_powf(0.29, vc.n), 0.73), 1.0 / 0.9);
+
+  let achromatic = vc.aw * f64_powf(j.max(0.0) / 100.0, 1.0 / (vc.c * vc.z));
+  let p1 = 50_000.0 / 13.0 * eccentricity(h) * vc.nc * vc.ncb;
+  let p2 = achromatic / vc.nbb;
+
+  let (sin, cos) = (f64_sin(h.to_radians()), f64_cos(h.to_radians()));
+  let gamma = 23.0 * (p2 + 0.305) * t / (23.0 * p1 + 11.0 * t * cos + 108.0 * t * sin);
+  let a = gamma * cos;
+  let b = gamma * sin;
+
+  let ra = (460.0 * p2 + 451.0 * a + 288.0 * b) / 1403.0;
+  let ga = (460.0 * p2 - 891.0 * a - 261.0 * b) / 1403.0;
+  let ba = (460.0 * p2 - 220.0 * a - 6300.0 * b) / 1403.0;
+
+  let rgb = (
+    unadapt_response(ra, vc.fl) / vc.rgb_d.0,
+    unadapt_response(ga, vc.fl) / vc.rgb_d.1,
+    unadapt_response(ba, vc.fl) / vc.rgb_d.2,
+  );
+  let (x, y, z) = mat3_mul_tuple(&M16_INV, &rgb);
+  (x / 100.0, y / 100.0, z / 100.0)
+}
+
+/// Takes CAM16 `J`, `C`, `h`, returns CAM16-UCS `J'`, `a'`, `b'`
+pub(crate) fn cam16_to_ucs(jch: &ColorTuple, vc: &ViewingConditions) -> ColorTuple {
+  let (j, c, h) = *jch;
+  let m = c * vc.fl_root;
+  let jstar = (1.0 + 100.0 * UCS_C1) * j / (1.0 + UCS_C1 * j);
+  let mstar = f64_ln(1.0 + UCS_C2 * m) / UCS_C2;
+  (jstar, mstar * f64_cos(h.to_radians()), mstar * f64_sin(h.to_radians()))
+}
+
+/// Inverse of `cam16_to_ucs`
+pub(crate) fn ucs_to_cam16(jab: &ColorTuple, vc: &ViewingConditions) -> ColorTuple {
+  let (jstar, astar, bstar) = *jab;
+  let mstar = f64_sqrt(astar * astar + bstar * bstar);
+  let m = (f64_exp(mstar * UCS_C2) - 1.0) / UCS_C2;
+  let h = if mstar == 0.0 {
+    0.0
+  } else {
+    bound_hue(f64_atan2(bstar, astar) * HUE_MAX / (2.0 * core::f64::consts::PI))
+  };
+  let j = jstar / (1.0 - UCS_C1 * (jstar - 100.0));
+  (j, m / vc.fl_root, h)
+}
+
+#[cfg(test)]
+mod test {
+  use super::{cam16_to_ucs, cam16_to_xyz, ucs_to_cam16, xyz_to_cam16};
+  use crate::cam16::ViewingConditions;
+  use crate::common::approx::approx;
+  use crate::WhitePoint;
+
+  #[test]
+  fn xyz_to_cam16_test() {
+    let vc = ViewingConditions::default();
+    let (j, c, _) = xyz_to_cam16(&WhitePoint::D65.xyz(), &vc);
+    assert!(approx(j, 100.0, 1e-9));
+    // white is not fully discounted
+    assert!(c > 0.0 && c < 3.0);
+
+    // sRGB red, values of Material Design color utilities
+    let xyz = (0.412_390_799_265_959_5, 0.212_639_005_871_510_36, 0.019_330_818_715_591_85);
+    let (j, c, h) = xyz_to_cam16(&xyz, &vc);
+    assert!(approx(j, 46.445, 0.01) && approx(c, 113.357, 0.05) && approx(h, 27.408, 0.01));
+    let back = cam16_to_xyz(&(j, c, h), &vc);
+    assert!(approx(back.0, xyz.0, 1e-9) && approx(back.1, xyz.1, 1e-9) && approx(back.2, xyz.2, 1e-9));
+  }
+
+  #[test]
+  fn cam16_to_ucs_test() {
+    let vc = ViewingConditions::default();
+    let (j, a, b) = cam16_to_ucs(&(50.0, 0.0, 120.0), &vc);
+    assert!(approx(j, 50.0 * 1.7 / 1.35, 1e-9) && a == 0.0 && b == 0.0);
+
+    let jch = (46.445, 113.357, 27.408);
+    let (j, c, h) = ucs_to_cam16(&cam16_to_ucs(&jch, &vc), &vc);
+    assert!(approx(j, jch.0, 1e-9) && approx(c, jch.1, 1e-9) && approx(h, jch.2, 1e-9));
+  }
+}
@@ -0,0 +1,107 @@
+use crate::ColorTuple;
+use crate::cam16::ViewingConditions;
+use crate::common::f64_abs;
+use crate::consts::RGB_UNIT_MAX;
+use crate::rgb::new_rgb_units;
+use crate::transfer::linear_to_srgb;
+use crate::units::Units;
+
+use super::{cam16_to_xyz, in_unit_cube, linear_rgb_to_xyz, lstar_to_y, xyz_to_cam16, xyz_to_linear_rgb, y_to_lstar};
+
+// bisection steps, enough to reach f64 precision on the searched ranges
+const SEARCH_STEPS: usize = 48;
+
+/// Takes XYZ (D65) values, returns HCT hue, chroma and tone
+pub(crate) fn xyz_to_hct(xyz: &ColorTuple) -> ColorTuple {
+  let (_, c, h) = xyz_to_cam16(xyz, &ViewingConditions::default());
+  (h, c, y_to_lstar(xyz.1))
+}
+
+// CAM16 color with given hue and chroma which luminance is `y`,
+// None if there is no such color in sRGB gamut
+fn find_in_gamut(h: f64, c: f64, y: f64, vc: &ViewingConditions) -> Option<ColorTuple> {
+  let (mut lo, mut hi) = (0.0, 100.0);
+  for _ in 0..SEARCH_STEPS {
+    let j = (lo + hi) / 2.0;
+    if cam16_to_xyz(&(j, c, h), vc).1 < y { lo = j } else { hi = j }
+  }
+  let xyz = cam16_to_xyz(&((lo + hi) / 2.0, c, h), vc);
+  let lin = xyz_to_linear_rgb(&xyz);
+  let found = f64_abs(xyz.1 - y) <= 1e-6 * y.max(1e-3) && in_unit_cube(&lin);
+  found.then_some(lin)
+}
+
+/// Takes HCT hue, chroma and tone, returns linear sRGB ratios of color
+/// with such hue and tone, and chroma as close to requested as sRGB gamut allows
+pub(crate) fn hct_to_linear_rgb(hct: &ColorTuple) -> ColorTuple {
+  let (h, c, t) = *hct;
+  let y = lstar_to_y(t.clamp(0.0, 100.0));
+  let gray = (y, y, y);
+  if c <= 0.0 || t <= 0.0 || t >= 100.0 {
+    return gray;
+  }
+  let vc = ViewingConditions::default();
+  if let Some(lin) = find_in_gamut(h, c, y, &vc) {
+    return lin;
+  }
+
+  let (mut lo, mut hi, mut best) = (0.0, c, gray);
+  for _ in 0..SEARCH_STEPS {
+    let mid = (lo + hi) / 2.0;
+    match find_in_gamut(h, mid, y, &vc) {
+      Some(lin) => {
+        lo = mid;
+        best = lin;
+      }
+      None => hi = mid,
+    }
+  }
+  best
+}
+
+/// Same as `hct_to_linear_rgb` but returns XYZ (D65) values
+pub(crate) fn hct_to_xyz(hct: &ColorTuple) -> ColorTuple {
+  linear_rgb_to_xyz(&hct_to_linear_rgb(hct))
+}
+
+/// Same as `hct_to_linear_rgb` but returns sRGB units
+pub(crate) fn hct_to_rgb(hct: &ColorTuple) -> Units {
+  let (r, g, b) = hct_to_linear_rgb(hct);
+  let mut units = new_rgb_units(
+    linear_to_srgb(r) * RGB_UNIT_MAX,
+    linear_to_srgb(g) * RGB_UNIT_MAX,
+    linear_to_srgb(b) * RGB_UNIT_MAX,
+  );
+  units.restrict();
+  units
+}
+
+#[cfg(test)]
+mod test {
+  use super::{hct_to_linear_rgb, xyz_to_hct};
+  use crate::common::approx::approx;
+  use crate::converters::{in_unit_cube, linear_rgb_to_xyz};
+
+  #[test]
+  fn hct_round_trip_test() {
+    let asserts = [(0.3, 0.5, 0.9), (1.0, 0.0, 0.0), (0.01, 0.02, 0.8), (0.9, 0.9, 0.2)];
+    for lin in asserts.iter() {
+      let hct = xyz_to_hct(&linear_rgb_to_xyz(lin));
+      let back = hct_to_linear_rgb(&hct);
+      assert!(approx(back.0, lin.0, 1e-6) && approx(back.1, lin.1, 1e-6) && approx(back.2, lin.2, 1e-6));
+    }
+  }
+
+  #[test]
+  fn hct_gamut_mapping_test() {
+    for hue in (0..360).step_by(30) {
+      for tone in [5.0, 30.0, 50.0, 70.0, 95.0] {
+        let lin = hct_to_linear_rgb(&(hue as f64, 200.0, tone));
+        assert!(in_unit_cube(&lin));
+        let (_, c, t) = xyz_to_hct(&linear_rgb_to_xyz(&lin));
+        assert!(approx(t, tone, 1e-4));
+        assert!(c > 5.0 && c < 200.0);
+      }
+    }
+  }
+}
@@ -0,0 +1,97 @@
+use crate::ColorTuple;
+use crate::common::f64_powf;
+use crate::common::matrix::{mat3_mul_tuple, Mat3};
+use crate::transfer::PQ_MAX_LUMINANCE;
+
+// constants of Safdar et al. (2017), "Perceptually uniform color space
+// for image signals including high dynamic range and wide gamut"
+const B: f64 = 1.15;
+const G: f64 = 0.66;
+const D: f64 = -0.56;
+const D0: f64 = 1.629_549_953_282_156_6e-11;
+const N: f64 = 2610.0 / 16_384.0;
+const P: f64 = 1.7 * 2523.0 / 32.0;
+const C1: f64 = 3424.0 / 4096.0;
+const C2: f64 = 2413.0 / 128.0;
+const C3: f64 = 2392.0 / 128.0;
+
+static XYZ_TO_LMS: Mat3 = [
+  [0.414_789_72, 0.579_999, 0.014_648],
+  [-0.201_51, 1.120_649, 0.053_100_8],
+  [-0.016_600_8, 0.2648, 0.668_479_9],
+];
+
+static IZAZBZ: Mat3 = [
+  [0.5, 0.5, 0.0],
+  [3.524, -4.066_708, 0.542_708],
+  [0.199_076, 1.096_799, -1.295_875],
+];
+
+// inverses of the matrices above
+static LMS_TO_XYZ: Mat3 = [
+  [1.924_226_435_787_606_7, -1.004_792_312_595_365_5, 0.037_651_404_030_618_01],
+  [0.350_316_762_094_999_07, 0.726_481_193_931_655_2, -0.065_384_422_948_085_02],
+  [-0.090_982_810_982_847_58, -0.312_728_290_523_074, 1.522_766_561_305_260_6],
+];
+
+static IZAZBZ_TO_LMS: Mat3 = [
+  [1.0, 0.138_605_043_271_539_3, 0.058_047_316_156_118_876],
+  [1.0, -0.138_605_043_271_539_3, -0.058_047_316_156_118_876],
+  [1.0, -0.096_019_242_026_318_95, -0.811_891_896_056_039],
+];
+
+// PQ-like curve of Jzazbz, takes absolute luminance
+fn encode(v: f64) -> f64 {
+  let vn = f64_powf(v.max(0.0) / PQ_MAX_LUMINANCE, N);
+  f64_powf((C1 + C2 * vn) / (1.0 + C3 * vn), P)
+}
+
+fn decode(v: f64) -> f64 {
+  let vp = f64_powf(v.max(0.0), 1.0 / P);
+  PQ_MAX_LUMINANCE * f64_powf(((C1 - vp) / (C3 * vp - C2)).max(0.0), 1.0 / N)
+}
+
+/// Takes absolute XYZ (D65) values in cd/m², returns `Jz`, `az`, `bz`
+pub(crate) fn xyz_to_jzazbz(xyz: &ColorTuple) -> ColorTuple {
+  let (x, y, z) = *xyz;
+  let xm = B * x - (B - 1.0) * z;
+  let ym = G * y - (G - 1.0) * x;
+  let (l, m, s) = mat3_mul_tuple(&XYZ_TO_LMS, &(xm, ym, z));
+  let (iz, az, bz) = mat3_mul_tuple(&IZAZBZ, &(encode(l), encode(m), encode(s)));
+  let jz = (1.0 + D) * iz / (1.0 + D * iz) - D0;
+  (jz, az, bz)
+}
+
+/// Inverse of `xyz_to_jzazbz`
+pub(crate) fn jzazbz_to_xyz(jab: &ColorTuple) -> ColorTuple {
+  let (jz, az, bz) = *jab;
+  let iz = (jz + D0) / (1.0 + D - D * (jz + D0));
+  let (l, m, s) = mat3_mul_tuple(&IZAZBZ_TO_LMS, &(iz, az, bz));
+  let (xm, ym, z) = mat3_mul_tuple(&LMS_TO_XYZ, &(decode(l), decode(m), decode(s)));
+  let x = (xm + (B - 1.0) * z) / B;
+  let y = (ym + (G - 1.0) * x) / G;
+  (x, y, z)
+}
+
+#[cfg(test)]
+mod test {
+  use super::{jzazbz_to_xyz, xyz_to_jzazbz};
+  use crate::common::approx::approx;
+
+  #[test]
+  fn xyz_jzazbz_test() {
+    let asserts = [
+      (0.0, 0.0, 0.0),
+      (95.047, 100.0, 108.883),
+      (41.24, 21.26, 1.93),
+      (1000.0, 1200.0, 800.0),
+      (0.5, 0.2, 3.0),
+    ];
+    for xyz in asserts.iter() {
+      let back = jzazbz_to_xyz(&xyz_to_jzazbz(xyz));
+      assert!(approx(back.0, xyz.0, 1e-7) && approx(back.1, xyz.1, 1e-7) && approx(back.2, xyz.2, 1e-7));
+    }
+    let (jz, az, bz) = xyz_to_jzazbz(&(0.0, 0.0, 0.0));
+    assert!(approx(jz, 0.0, 1e-9) && approx(az, 0.0, 1e-9) && approx(bz, 0.0, 1e-9));
+  }
+}
@@ -0,0 +1,79 @@
+use crate::ColorTuple;
+use crate::common::f64_cbrt;
+use crate::WhitePoint;
+
+// CIE standard constants in their exact rational form
+pub(super) const EPSILON: f64 = 216.0 / 24_389.0;
+pub(super) const KAPPA: f64 = 24_389.0 / 27.0;
+
+fn f(t: f64) -> f64 {
+  if t > EPSILON { f64_cbrt(t) } else { (KAPPA * t + 16.0) / 116.0 }
+}
+
+fn f_inv(t: f64) -> f64 {
+  let t3 = t * t * t;
+  if t3 > EPSILON { t3 } else { (116.0 * t - 16.0) / KAPPA }
+}
+
+/// Takes XYZ values relative to `white` and returns CIE L*a*b*
+pub(crate) fn xyz_to_lab(xyz: &ColorTuple, white: &WhitePoint) -> ColorTuple {
+  let (wx, wy, wz) = white.xyz();
+  let fx = f(xyz.0 / wx);
+  let fy = f(xyz.1 / wy);
+  let fz = f(xyz.2 / wz);
+  (116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz))
+}
+
+/// Inverse of `xyz_to_lab`
+pub(crate) fn lab_to_xyz(lab: &ColorTuple, white: &WhitePoint) -> ColorTuple {
+  let (l, a, b) = *lab;
+  let (wx, wy, wz) = white.xyz();
+  let fy = (l + 16.0) / 116.0;
+  let fx = fy + a / 500.0;
+  let fz = fy - b / 200.0;
+  let y = if l > KAPPA * EPSILON { fy * fy * fy } else { l / KAPPA };
+  (f_inv(fx) * wx, y * wy, f_inv(fz) * wz)
+}
+
+/// Takes relative luminance `Y` (`1.0` is reference white) and returns CIE L*
+pub(crate) fn y_to_lstar(y: f64) -> f64 {
+  116.0 * f(y) - 16.0
+}
+
+/// Inverse of `y_to_lstar`
+pub(crate) fn lstar_to_y(l: f64) -> f64 {
+  if l > KAPPA * EPSILON { f_inv((l + 16.0) / 116.0) } else { l / KAPPA }
+}
+
+#[cfg(test)]
+mod test {
+  use super::{lab_to_xyz, lstar_to_y, xyz_to_lab, y_to_lstar};
+  use crate::common::approx::approx;
+  use crate::WhitePoint;
+
+  #[test]
+  fn xyz_lab_test() {
+    let d50 = WhitePoint::D50;
+    let asserts = [
+      (d50.xyz(), (100.0, 0.0, 0.0)),
+      ((0.0, 0.0, 0.0), (0.0, 0.0, 0.0)),
+      ((0.2, 0.3, 0.4), (61.654_222_210, -38.749_493_679, -23.226_965_248)),
+      ((0.001, 0.002, 0.003), (1.806_592_593, -3.749_355_843, -2.547_766_885)),
+    ];
+    for (xyz, lab) in asserts.iter() {
+      let l = xyz_to_lab(xyz, &d50);
+      assert!(approx(l.0, lab.0, 1e-6) && approx(l.1, lab.1, 1e-6) && approx(l.2, lab.2, 1e-6));
+      let x = lab_to_xyz(lab, &d50);
+      assert!(approx(x.0, xyz.0, 1e-9) && approx(x.1, xyz.1, 1e-9) && approx(x.2, xyz.2, 1e-9));
+    }
+  }
+
+  #[test]
+  fn lstar_test() {
+    let asserts = [(0.0, 0.0), (0.002, 1.806_592_593), (0.184_186_518_512_444_16, 50.0), (1.0, 100.0)];
+    for (y, l) in asserts.iter() {
+      assert!(approx(y_to_lstar(*y), *l, 1e-6));
+      assert!(approx(lstar_to_y(*l), *y, 1e-9));
+    }
+  }
+}
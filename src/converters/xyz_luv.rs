@@ -0,0 +1,91 @@
+use crate::ColorTuple;
+use crate::common::f64_cbrt;
+use crate::WhitePoint;
+
+use super::xyz_lab::{EPSILON, KAPPA};
+
+/// Returns CIE 1976 u'v' chromaticity coordinates of XYZ values
+pub(crate) fn xyz_to_uv_prime(xyz: &ColorTuple) -> (f64, f64) {
+  let (x, y, z) = *xyz;
+  let d = x + 15.0 * y + 3.0 * z;
+  if d == 0.0 {
+    return (0.0, 0.0);
+  }
+  (4.0 * x / d, 9.0 * y / d)
+}
+
+/// Takes XYZ values relative to `white` and returns CIE L*u*v*
+pub(crate) fn xyz_to_luv(xyz: &ColorTuple, white: &WhitePoint) -> ColorTuple {
+  let w = white.xyz();
+  let yr = xyz.1 / w.1;
+  let l = if yr > EPSILON { 116.0 * f64_cbrt(yr) - 16.0 } else { KAPPA * yr };
+  if l == 0.0 {
+    return (0.0, 0.0, 0.0);
+  }
+  let (u, v) = xyz_to_uv_prime(xyz);
+  let (un, vn) = xyz_to_uv_prime(&w);
+  (l, 13.0 * l * (u - un), 13.0 * l * (v - vn))
+}
+
+/// Inverse of `xyz_to_luv`
+pub(crate) fn luv_to_xyz(luv: &ColorTuple, white: &WhitePoint) -> ColorTuple {
+  let (l, u, v) = *luv;
+  if l <= 0.0 {
+    return (0.0, 0.0, 0.0);
+  }
+  let w = white.xyz();
+  let (un, vn) = xyz_to_uv_prime(&w);
+  let u = u / (13.0 * l) + un;
+  let v = v / (13.0 * l) + vn;
+  // chromaticities with `v' <= 0` are out of the spectral locus and can't be converted
+  if v <= 0.0 {
+    return (0.0, 0.0, 0.0);
+  }
+  let y = if l > KAPPA * EPSILON {
+    let fy = (l + 16.0) / 116.0;
+    fy * fy * fy
+  } else {
+    l / KAPPA
+  } * w.1;
+  (y * 9.0 * u / (4.0 * v), y, y * (12.0 - 3.0 * u - 20.0 * v) / (4.0 * v))
+}
+
+#[cfg(test)]
+mod test {
+  use super::{luv_to_xyz, xyz_to_luv, xyz_to_uv_prime};
+  use crate::common::approx::approx;
+  use crate::WhitePoint;
+
+  #[test]
+  fn xyz_luv_test() {
+    let d65 = WhitePoint::D65;
+    let asserts = [
+      (d65.xyz(), (100.0, 0.0, 0.0)),
+      ((0.0, 0.0, 0.0), (0.0, 0.0, 0.0)),
+      ((0.412_390_8, 0.212_639, 0.019_330_8), (53.237_115, 175.009_833, 37.765_097)),
+      ((0.001, 0.002, 0.003), (1.806_593, -2.297_607, -0.430_258)),
+    ];
+    for (xyz, luv) in asserts.iter() {
+      let l = xyz_to_luv(xyz, &d65);
+      assert!(approx(l.0, luv.0, 1e-5) && approx(l.1, luv.1, 1e-5) && approx(l.2, luv.2, 1e-5));
+      let x = luv_to_xyz(&l, &d65);
+      assert!(approx(x.0, xyz.0, 1e-9) && approx(x.1, xyz.1, 1e-9) && approx(x.2, xyz.2, 1e-9));
+    }
+  }
+
+  #[test]
+  fn uv_prime_test() {
+    let (u, v) = xyz_to_uv_prime(&WhitePoint::D65.xyz());
+    assert!(approx(u, 0.197_830, 1e-6) && approx(v, 0.468_319, 1e-6));
+    assert_eq!(xyz_to_uv_prime(&(0.0, 0.0, 0.0)), (0.0, 0.0));
+  }
+
+  #[test]
+  fn luv_zero_v_prime_test() {
+    let d65 = WhitePoint::D65;
+    let (_, vn) = xyz_to_uv_prime(&d65.xyz());
+    let v = -13.0 * 50.0 * vn;
+    assert_eq!(luv_to_xyz(&(50.0, 0.0, v), &d65), (0.0, 0.0, 0.0));
+    assert_eq!(luv_to_xyz(&(50.0, 10.0, -400.0), &d65), (0.0, 0.0, 0.0));
+  }
+}
@@ -0,0 +1,62 @@
+use crate::ColorTuple;
+use crate::common::f64_cbrt;
+use crate::common::matrix::{mat3_mul_tuple, Mat3};
+
+// matrices from CSS Color Module Level 4, XYZ values are relative to D65
+static XYZ_TO_LMS: Mat3 = [
+  [0.819_022_437_996_703, 0.361_906_260_052_890_4, -0.128_873_781_520_987_9],
+  [0.032_983_653_932_388_5, 0.929_286_861_586_343_4, 0.036_144_666_350_642_4],
+  [0.048_177_189_359_624_2, 0.264_239_531_752_730_8, 0.633_547_828_469_430_9],
+];
+
+static LMS_TO_OKLAB: Mat3 = [
+  [0.210_454_268_309_314, 0.793_617_774_702_305_4, -0.004_072_043_011_619_3],
+  [1.977_998_532_431_168_4, -2.428_592_242_048_58, 0.450_593_709_617_411],
+  [0.025_904_042_465_547_8, 0.782_771_712_457_529_6, -0.808_675_754_923_077_4],
+];
+
+static LMS_TO_XYZ: Mat3 = [
+  [1.226_879_875_845_924_3, -0.557_814_994_460_217_1, 0.281_391_045_665_964_7],
+  [-0.040_575_745_214_800_8, 1.112_286_803_280_317, -0.071_711_058_065_516_4],
+  [-0.076_372_936_674_660_1, -0.421_493_332_402_243_2, 1.586_924_019_836_781_6],
+];
+
+static OKLAB_TO_LMS: Mat3 = [
+  [1.0, 0.396_337_777_376_174_9, 0.215_803_757_309_913_6],
+  [1.0, -0.105_561_345_815_658_6, -0.063_854_172_825_813_3],
+  [1.0, -0.089_484_177_529_811_9, -1.291_485_548_019_409_2],
+];
+
+/// Takes XYZ (D65) values and returns Oklab
+pub(crate) fn xyz_to_oklab(xyz: &ColorTuple) -> ColorTuple {
+  let (l, m, s) = mat3_mul_tuple(&XYZ_TO_LMS, xyz);
+  mat3_mul_tuple(&LMS_TO_OKLAB, &(f64_cbrt(l), f64_cbrt(m), f64_cbrt(s)))
+}
+
+/// Inverse of `xyz_to_oklab`
+pub(crate) fn oklab_to_xyz(lab: &ColorTuple) -> ColorTuple {
+  let (l, m, s) = mat3_mul_tuple(&OKLAB_TO_LMS, lab);
+  mat3_mul_tuple(&LMS_TO_XYZ, &(l * l * l, m * m * m, s * s * s))
+}
+
+#[cfg(test)]
+mod test {
+  use super::{oklab_to_xyz, xyz_to_oklab};
+  use crate::common::approx::approx;
+  use crate::WhitePoint;
+
+  #[test]
+  fn xyz_oklab_test() {
+    let asserts = [
+      (WhitePoint::D65.xyz(), (1.0, 0.0, 0.0)),
+      ((0.0, 0.0, 0.0), (0.0, 0.0, 0.0)),
+      ((0.412_390_8, 0.212_639, 0.019_330_8), (0.627_955, 0.224_863, 0.125_846)),
+    ];
+    for (xyz, lab) in asserts.iter() {
+      let l = xyz_to_oklab(xyz);
+      assert!(approx(l.0, lab.0, 1e-6) && approx(l.1, lab.1, 1e-6) && approx(l.2, lab.2, 1e-6));
+      let x = oklab_to_xyz(&l);
+      assert!(approx(x.0, xyz.0, 1e-9) && approx(x.1, xyz.1, 1e-9) && approx(x.2, xyz.2, 1e-9));
+    }
+  }
+}
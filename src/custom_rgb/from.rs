@@ -0,0 +1,53 @@
+use crate::{ColorTuple, ColorTupleA, Rgb, WhitePoint, Xyz};
+use crate::common::matrix::mat3_mul_tuple;
+use crate::converters::{in_unit_cube, xyz_to_linear_rgb};
+use crate::xyz::adapt;
+
+use super::{CustomRgb, RgbSpace};
+
+impl CustomRgb {
+  /// Creates color of `space` from XYZ values. They are adapted to the white of the space
+  /// before, values out of gamut are clipped
+  pub fn from_xyz(xyz: &Xyz, space: &RgbSpace) -> CustomRgb {
+    let t: ColorTuple = xyz.adapt(space.white()).into();
+    let (r, g, b) = mat3_mul_tuple(&space.from_xyz, &t);
+    // clip before encoding, custom transfer functions may be undefined out of 0..1
+    let (r, g, b) = (r.clamp(0.0, 1.0), g.clamp(0.0, 1.0), b.clamp(0.0, 1.0));
+    CustomRgb::from_linear(r, g, b, xyz.units.alpha.get(), space)
+  }
+
+  /// Creates color of `space` from sRGB color
+  pub fn from_rgb(rgb: &Rgb, space: &RgbSpace) -> CustomRgb {
+    CustomRgb::from_xyz(&Xyz::from(rgb), space)
+  }
+
+  /// Converts color to another color space
+  pub fn to_space(&self, space: &RgbSpace) -> CustomRgb {
+    CustomRgb::from_xyz(&self.into(), space)
+  }
+
+  /// Checks whether the color can be represented in sRGB without clipping
+  pub fn in_srgb_gamut(&self) -> bool {
+    let d65 = adapt(&self._to_xyz_tuple(), &self.space.white(), &WhitePoint::D65);
+    in_unit_cube(&xyz_to_linear_rgb(&d65))
+  }
+
+  fn _to_xyz_tuple(&self) -> ColorTuple {
+    mat3_mul_tuple(&self.space.to_xyz, &self.to_linear())
+  }
+}
+
+into_for_some!(ColorTuple, CustomRgb, self, {
+  let u = &self.units;
+  (u[0], u[1], u[2])
+});
+into_for_some!(ColorTupleA, CustomRgb, self, {
+  let u = &self.units;
+  (u[0], u[1], u[2], u.alpha.get_f64())
+});
+
+from_for_some!(CustomRgb, Xyz, color, {
+  let (x, y, z) = color._to_xyz_tuple();
+  Xyz::new_with_white(x, y, z, color.units.alpha.get(), color.space.white())
+});
+from_for_some!(CustomRgb, Rgb, color, { Rgb::from(Xyz::from(color)) });
@@ -0,0 +1,119 @@
+pub use space::{RgbSpace, TransferFunction};
+
+use crate::{ColorTuple, ColorUnitsIter};
+use crate::units::{Alpha, GetColorUnits, Unit, Units};
+
+#[cfg(test)]
+mod tests;
+
+mod from;
+mod space;
+
+/// Color of a user-defined RGB color space (see `RgbSpace`).
+///
+/// For camera, display or working spaces which are not built in. Values are
+/// gamma-encoded ratios, linear-light values can be got with `to_linear` and
+/// `from_linear` methods. Conversion to and from other color models is performed
+/// through `Xyz`: `CustomRgb::from_xyz`, `CustomRgb::from_rgb` and `From` traits.
+///
+/// Conversion to `Rgb` clips colors which are out of sRGB gamut,
+/// use `in_srgb_gamut` to check it before.
+///
+/// Ranges:
+/// * red: 0.0 - 1.0
+/// * green: 0.0 - 1.0
+/// * blue: 0.0 - 1.0
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{CustomRgb, Rgb, RgbSpace, TransferFunction, WhitePoint};
+///
+/// // display with DCI-P3 primaries, D65 white and gamma 2.2
+/// let primaries = [(0.680, 0.320), (0.265, 0.690), (0.150, 0.060)];
+/// let space = RgbSpace::new(primaries, WhitePoint::D65, TransferFunction::Gamma(2.2)).unwrap();
+///
+/// let orange = CustomRgb::from_rgb(&Rgb::from([255, 128, 0]), &space);
+/// let (r, g, b) = orange.into();
+/// assert_eq!(((r * 100.0).round(), (g * 100.0).round(), (b * 100.0).round()), (93.0, 52.0, 21.0));
+///
+/// let green = CustomRgb::new(0.0, 1.0, 0.0, None, &space);
+/// assert!(!green.in_srgb_gamut());
+/// assert_eq!(Rgb::from(&green).to_css_string(), "rgb(0,255,0)");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CustomRgb {
+  pub(crate) units: Units,
+  pub(crate) space: RgbSpace,
+}
+
+iter_def!(CustomRgb);
+
+pub(crate) fn new_custom_rgb_units(r: f64, g: f64, b: f64) -> Units {
+  let ul = [Unit::new_ratio(r), Unit::new_ratio(g), Unit::new_ratio(b), Unit::default()];
+  Units { len: 3, list: ul, alpha: Alpha::default() }
+}
+
+impl CustomRgb {
+  pub fn new(r: f64, g: f64, b: f64, a: Option<f64>, space: &RgbSpace) -> CustomRgb {
+    let mut units = new_custom_rgb_units(r, g, b);
+    units.alpha.set_opt(a);
+    units.restrict();
+    CustomRgb { units, space: space.clone() }
+  }
+
+  /// Creates color from linear-light values
+  pub fn from_linear(r: f64, g: f64, b: f64, a: Option<f64>, space: &RgbSpace) -> CustomRgb {
+    let tf = space.transfer();
+    CustomRgb::new(tf.from_linear(r), tf.from_linear(g), tf.from_linear(b), a, space)
+  }
+
+  /// Returns linear-light values
+  pub fn to_linear(&self) -> ColorTuple {
+    let (u, tf) = (&self.units, self.space.transfer());
+    (tf.to_linear(u[0]), tf.to_linear(u[1]), tf.to_linear(u[2]))
+  }
+
+  pub fn red(&self) -> f64 { self.units[0] }
+  pub fn green(&self) -> f64 { self.units[1] }
+  pub fn blue(&self) -> f64 { self.units[2] }
+
+  pub fn set_red(&mut self, val: f64) { self.units.list[0].set(val); }
+  pub fn set_green(&mut self, val: f64) { self.units.list[1].set(val); }
+  pub fn set_blue(&mut self, val: f64) { self.units.list[2].set(val); }
+
+  /// Returns color space of the color
+  pub fn space(&self) -> &RgbSpace {
+    &self.space
+  }
+
+  /// Returns an iterator over three color units and the possibly alpha value.
+  pub fn iter(&self) -> ColorUnitsIter {
+    ColorUnitsIter::from_units(&self.units)
+  }
+}
+
+//
+//
+//
+// AsRef<CustomRgb>
+//
+impl AsRef<CustomRgb> for CustomRgb {
+  fn as_ref(&self) -> &CustomRgb {
+    self
+  }
+}
+
+impl GetColorUnits for CustomRgb {
+  fn get_units(&self) -> &Units {
+    &self.units
+  }
+  fn get_units_mut(&mut self) -> &mut Units {
+    &mut self.units
+  }
+
+  fn same_space(&self, other: &CustomRgb) -> bool {
+    self.space == other.space
+  }
+}
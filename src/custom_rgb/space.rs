@@ -0,0 +1,210 @@
+#[cfg(not(feature = "std"))]
+use alloc::sync::Arc;
+#[cfg(feature = "std")]
+use std::sync::Arc;
+
+use crate::WhitePoint;
+use crate::common::{f64_abs, f64_powf};
+use crate::common::matrix::{mat3_inverse, Mat3};
+use crate::converters::rgb_to_xyz_matrix;
+
+/// Transfer function of an RGB color space: conversion of gamma-encoded values to
+/// linear light and back. Negative values are extended symmetrically.
+///
+/// # Example
+/// ```
+/// use colorsys::TransferFunction;
+///
+/// let gamma = TransferFunction::Gamma(2.2);
+/// assert_eq!(gamma.to_linear(1.0), 1.0);
+/// assert!((gamma.from_linear(gamma.to_linear(0.5)) - 0.5).abs() < 1e-12);
+///
+/// // camera log curve with parameters known only at runtime
+/// let (a, b) = (5.0, 0.1);
+/// let log = TransferFunction::custom(
+///   move |v| ((v - b) * a).exp2() / 64.0,
+///   move |l: f64| (l * 64.0).log2() / a + b,
+/// );
+/// assert!((log.to_linear(log.from_linear(0.18)) - 0.18).abs() < 1e-12);
+/// ```
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TransferFunction {
+  /// Values are linear light
+  Linear,
+  /// Pure power function: `linear = encoded ^ gamma`
+  Gamma(f64),
+  /// Power function with a linear segment near black, as in sRGB and Rec.709.
+  /// Encoding of linear value `l` is `slope * l` below `beta`,
+  /// and `alpha * l ^ (1 / gamma) - (alpha - 1)` above it
+  Piecewise { gamma: f64, alpha: f64, beta: f64, slope: f64 },
+  /// Functions which convert encoded value to linear and back.
+  /// Can't be serialized
+  #[cfg_attr(feature = "serde", serde(skip))]
+  Custom {
+    to_linear: Arc<dyn Fn(f64) -> f64 + Send + Sync>,
+    from_linear: Arc<dyn Fn(f64) -> f64 + Send + Sync>,
+  },
+}
+
+impl TransferFunction {
+  /// Transfer function of sRGB
+  pub const SRGB: TransferFunction =
+    TransferFunction::Piecewise { gamma: 2.4, alpha: 1.055, beta: 0.003_130_8, slope: 12.92 };
+
+  /// Creates custom transfer function, `from_linear` must be inverse of `to_linear`
+  pub fn custom(
+    to_linear: impl Fn(f64) -> f64 + Send + Sync + 'static,
+    from_linear: impl Fn(f64) -> f64 + Send + Sync + 'static,
+  ) -> TransferFunction {
+    TransferFunction::Custom { to_linear: Arc::new(to_linear), from_linear: Arc::new(from_linear) }
+  }
+
+  /// Converts gamma-encoded value to linear light
+  pub fn to_linear(&self, c: f64) -> f64 {
+    let abs = f64_abs(c);
+    let lin = match self {
+      TransferFunction::Linear => abs,
+      TransferFunction::Gamma(gamma) => f64_powf(abs, *gamma),
+      TransferFunction::Piecewise { gamma, alpha, beta, slope } => {
+        if abs < beta * slope {
+          abs / slope
+        } else {
+          f64_powf((abs + alpha - 1.0) / alpha, *gamma)
+        }
+      }
+      TransferFunction::Custom { to_linear, .. } => return to_linear(c),
+    };
+    if c < 0.0 { -lin } else { lin }
+  }
+
+  /// Converts linear light to gamma-encoded value
+  pub fn from_linear(&self, c: f64) -> f64 {
+    let abs = f64_abs(c);
+    let enc = match self {
+      TransferFunction::Linear => abs,
+      TransferFunction::Gamma(gamma) => f64_powf(abs, 1.0 / gamma),
+      TransferFunction::Piecewise { gamma, alpha, beta, slope } => {
+        if abs < *beta {
+          abs * slope
+        } else {
+          alpha * f64_powf(abs, 1.0 / gamma) - (alpha - 1.0)
+        }
+      }
+      TransferFunction::Custom { from_linear, .. } => return from_linear(c),
+    };
+    if c < 0.0 { -enc } else { enc }
+  }
+}
+
+impl core::fmt::Debug for TransferFunction {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    match self {
+      TransferFunction::Linear => f.write_str("Linear"),
+      TransferFunction::Gamma(gamma) => f.debug_tuple("Gamma").field(gamma).finish(),
+      TransferFunction::Piecewise { gamma, alpha, beta, slope } => f
+        .debug_struct("Piecewise")
+        .field("gamma", gamma)
+        .field("alpha", alpha)
+        .field("beta", beta)
+        .field("slope", slope)
+        .finish(),
+      TransferFunction::Custom { .. } => f.write_str("Custom"),
+    }
+  }
+}
+
+/// Custom functions are equal only if they are clones of the same function
+impl PartialEq for TransferFunction {
+  fn eq(&self, other: &TransferFunction) -> bool {
+    use TransferFunction::*;
+    match (self, other) {
+      (Linear, Linear) => true,
+      (Gamma(a), Gamma(b)) => a == b,
+      (
+        Piecewise { gamma, alpha, beta, slope },
+        Piecewise { gamma: gamma2, alpha: alpha2, beta: beta2, slope: slope2 },
+      ) => gamma == gamma2 && alpha == alpha2 && beta == beta2 && slope == slope2,
+      (Custom { to_linear, from_linear }, Custom { to_linear: to2, from_linear: from2 }) => {
+        Arc::ptr_eq(to_linear, to2) && Arc::ptr_eq(from_linear, from2)
+      }
+      _ => false,
+    }
+  }
+}
+
+/// RGB color space defined by chromaticities of its primaries, white point
+/// and transfer function. Matrices of conversion to and from XYZ are derived from them.
+///
+/// # Example
+/// ```
+/// use colorsys::{RgbSpace, TransferFunction, WhitePoint};
+///
+/// // sRGB primaries with gamma 2.2
+/// let primaries = [(0.64, 0.33), (0.30, 0.60), (0.15, 0.06)];
+/// let space = RgbSpace::new(primaries, WhitePoint::D65, TransferFunction::Gamma(2.2)).unwrap();
+/// let m = space.to_xyz_matrix();
+/// assert!((m[0][0] - 0.4124).abs() < 0.0001);
+///
+/// // primaries on one line don't make a color space
+/// let collinear = [(0.1, 0.1), (0.2, 0.2), (0.3, 0.3)];
+/// assert!(RgbSpace::new(collinear, WhitePoint::D65, TransferFunction::Linear).is_none());
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(into = "RgbSpaceDef", try_from = "RgbSpaceDef"))]
+pub struct RgbSpace {
+  primaries: [(f64, f64); 3],
+  white: WhitePoint,
+  transfer: TransferFunction,
+
+  pub(crate) to_xyz: Mat3,
+  pub(crate) from_xyz: Mat3,
+}
+
+impl RgbSpace {
+  /// Creates color space from CIE 1931 chromaticity coordinates `(x, y)` of red, green
+  /// and blue primaries. Returns `None` if the primaries are collinear, or the primaries
+  /// or the white have zero `y`
+  pub fn new(primaries: [(f64, f64); 3], white: WhitePoint, transfer: TransferFunction) -> Option<RgbSpace> {
+    let to_xyz = rgb_to_xyz_matrix(&primaries, &white)?;
+    let from_xyz = mat3_inverse(&to_xyz)?;
+    if !to_xyz.iter().chain(from_xyz.iter()).flatten().all(|v| v.is_finite()) {
+      return None;
+    }
+    Some(RgbSpace { primaries, white, transfer, to_xyz, from_xyz })
+  }
+
+  /// Returns chromaticity coordinates `(x, y)` of red, green and blue primaries
+  pub fn primaries(&self) -> [(f64, f64); 3] { self.primaries }
+  pub fn white(&self) -> WhitePoint { self.white }
+  pub fn transfer(&self) -> &TransferFunction { &self.transfer }
+
+  /// Returns matrix (row-major) which converts linear RGB values to XYZ values
+  /// relative to the white of the space
+  pub fn to_xyz_matrix(&self) -> [[f64; 3]; 3] { self.to_xyz }
+}
+
+// serialized form of `RgbSpace`, matrices are derived again on deserialization
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct RgbSpaceDef {
+  primaries: [(f64, f64); 3],
+  white: WhitePoint,
+  transfer: TransferFunction,
+}
+
+#[cfg(feature = "serde")]
+impl From<RgbSpace> for RgbSpaceDef {
+  fn from(space: RgbSpace) -> RgbSpaceDef {
+    RgbSpaceDef { primaries: space.primaries, white: space.white, transfer: space.transfer }
+  }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RgbSpaceDef> for RgbSpace {
+  type Error = &'static str;
+  fn try_from(def: RgbSpaceDef) -> Result<RgbSpace, Self::Error> {
+    RgbSpace::new(def.primaries, def.white, def.transfer).ok_or("invalid primaries or white point of RGB space")
+  }
+}
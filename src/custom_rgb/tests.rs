@@ -0,0 +1,152 @@
+use crate::{
+  AdobeRgb, ApproxEq, ColorTupleA, CustomRgb, DisplayP3, ProPhotoRgb, Rgb, RgbSpace,
+  TransferFunction, WhitePoint, Xyz,
+};
+use crate::common::approx::approx;
+use crate::common::{f64_exp, f64_ln, f64_sqrt};
+use crate::transfer::{linear_to_prophoto, prophoto_to_linear, srgb_to_linear};
+
+fn p3_space() -> RgbSpace {
+  let primaries = [(0.680, 0.320), (0.265, 0.690), (0.150, 0.060)];
+  RgbSpace::new(primaries, WhitePoint::D65, TransferFunction::SRGB).unwrap()
+}
+
+#[test]
+fn custom_rgb_as_built_in() {
+  let a98_primaries = [(0.640, 0.330), (0.210, 0.710), (0.150, 0.060)];
+  let a98 = RgbSpace::new(a98_primaries, WhitePoint::D65, TransferFunction::Gamma(563.0 / 256.0)).unwrap();
+  let prophoto_primaries = [(0.734_699, 0.265_301), (0.159_597, 0.840_403), (0.036_598, 0.000_105)];
+  let tf = TransferFunction::custom(prophoto_to_linear, linear_to_prophoto);
+  let prophoto = RgbSpace::new(prophoto_primaries, WhitePoint::D50, tf).unwrap();
+  let p3 = p3_space();
+
+  let colors = [[255, 0, 0], [255, 255, 255], [0, 0, 0], [34, 111, 235], [0, 255, 0], [250, 200, 10]];
+  for rgb_arr in colors.iter() {
+    let rgb = Rgb::from(rgb_arr);
+
+    let color = CustomRgb::from_rgb(&rgb, &p3);
+    let (r, g, b) = DisplayP3::from(&rgb).into();
+    assert!(color.approx_eq_clarify(&CustomRgb::new(r, g, b, None, &p3), 0.000_001));
+    assert!(Rgb::from(&color).approx_eq_clarify(&rgb, 0.000_001));
+
+    let color = CustomRgb::from_rgb(&rgb, &a98);
+    let (r, g, b) = AdobeRgb::from(&rgb).into();
+    assert!(color.approx_eq_clarify(&CustomRgb::new(r, g, b, None, &a98), 0.000_001));
+
+    let color = CustomRgb::from_rgb(&rgb, &prophoto);
+    let (r, g, b) = ProPhotoRgb::from(&rgb).into();
+    assert!(color.approx_eq_clarify(&CustomRgb::new(r, g, b, None, &prophoto), 0.000_001));
+    assert!(color.in_srgb_gamut());
+    assert!(Rgb::from(&color).approx_eq_clarify(&rgb, 0.000_001));
+  }
+}
+
+#[test]
+fn custom_rgb_linear_xyz() {
+  let space = p3_space();
+  let color = CustomRgb::from_linear(0.214_041_140_5, 1.0, 0.0, Some(0.5), &space);
+  assert!(color.approx_eq(&CustomRgb::new(0.5, 1.0, 0.0, Some(0.5), &space)));
+  assert_eq!(color.space(), &space);
+  let (r, g, b) = color.to_linear();
+  assert!(CustomRgb::from_linear(r, g, b, Some(0.5), &space).approx_eq(&color));
+
+  let white = Xyz::from(CustomRgb::new(1.0, 1.0, 1.0, None, &space));
+  assert!(white.approx_eq(&Xyz::from(WhitePoint::D65.xyz())));
+
+  let xyz = Xyz::from(&color).adapt(WhitePoint::D50);
+  let back = CustomRgb::from_xyz(&xyz, &space);
+  assert!(back.approx_eq(&color));
+  assert_eq!(Into::<ColorTupleA>::into(back).3, 0.5);
+
+  let d50_space = RgbSpace::new(space.primaries(), WhitePoint::D50, TransferFunction::Linear).unwrap();
+  let gray = CustomRgb::new(0.5, 0.5, 0.5, None, &space);
+  let converted = gray.to_space(&d50_space);
+  assert_eq!(converted.space().white(), WhitePoint::D50);
+  assert!(approx(converted.red(), converted.blue(), 1e-12));
+  assert!(converted.to_space(&space).approx_eq(&gray));
+  assert!(!converted.approx_eq(&CustomRgb::new(converted.red(), converted.green(), converted.blue(), None, &space)));
+  let color = CustomRgb::new(0.5, 0.6, 0.4, None, &space);
+  assert!(color.to_space(&d50_space).to_space(&space).approx_eq(&color));
+}
+
+#[test]
+fn custom_rgb_gamut() {
+  let space = p3_space();
+  assert!(!CustomRgb::new(0.0, 1.0, 0.0, None, &space).in_srgb_gamut());
+  assert!(!CustomRgb::new(0.95, 0.1, 0.1, None, &space).in_srgb_gamut());
+  assert!(CustomRgb::new(0.5, 0.5, 0.5, None, &space).in_srgb_gamut());
+
+  let rgb = Rgb::from(CustomRgb::new(0.0, 1.0, 0.0, None, &space));
+  assert_eq!(Into::<[u8; 3]>::into(rgb), [0, 255, 0]);
+
+  let mut color = CustomRgb::new(1.5, -0.5, 0.5, None, &space);
+  assert_eq!((color.red(), color.green(), color.blue()), (1.0, 0.0, 0.5));
+  color.set_green(0.25);
+  assert_eq!(color.green(), 0.25);
+}
+
+#[test]
+fn custom_rgb_clip_before_encoding() {
+  // camera log curve which is undefined for negative values
+  let (a, b) = (5.0, 0.1);
+  let log = TransferFunction::custom(
+    move |v| f64_exp((v - b) * a * core::f64::consts::LN_2) / 64.0,
+    move |l| f64_ln(l * 64.0) / core::f64::consts::LN_2 / a + b,
+  );
+  let primaries = [(0.64, 0.33), (0.30, 0.60), (0.15, 0.06)];
+  let space = RgbSpace::new(primaries, WhitePoint::D65, log).unwrap();
+
+  let p3_green = Xyz::from(&DisplayP3::new(0.0, 1.0, 0.0, None));
+  let color = CustomRgb::from_xyz(&p3_green, &space);
+  for v in [color.red(), color.green(), color.blue()] {
+    assert!(v.is_finite() && (0.0..=1.0).contains(&v));
+  }
+  assert_eq!((color.red(), color.green(), color.blue()), (0.0, 1.0, 0.0));
+}
+
+#[test]
+fn rgb_space_new() {
+  let primaries = [(0.64, 0.33), (0.30, 0.60), (0.15, 0.06)];
+  let space = RgbSpace::new(primaries, WhitePoint::D65, TransferFunction::Linear).unwrap();
+  let m = space.to_xyz_matrix();
+  assert!(approx(m[1][0], 0.2126, 0.0001) && approx(m[1][1], 0.7152, 0.0001));
+  assert_eq!(space.primaries(), primaries);
+
+  let collinear = [(0.1, 0.1), (0.2, 0.2), (0.3, 0.3)];
+  assert!(RgbSpace::new(collinear, WhitePoint::D65, TransferFunction::Linear).is_none());
+  let zero_y = [(0.64, 0.33), (0.30, 0.0), (0.15, 0.06)];
+  assert!(RgbSpace::new(zero_y, WhitePoint::D65, TransferFunction::Linear).is_none());
+  let zero_white = WhitePoint::Custom(0.3, 0.0);
+  assert!(RgbSpace::new(primaries, zero_white, TransferFunction::Linear).is_none());
+}
+
+#[test]
+fn transfer_functions() {
+  let rec709 = TransferFunction::Piecewise { gamma: 1.0 / 0.45, alpha: 1.099, beta: 0.018, slope: 4.5 };
+  let functions = [
+    TransferFunction::Linear,
+    TransferFunction::Gamma(2.2),
+    TransferFunction::SRGB,
+    rec709.clone(),
+    TransferFunction::custom(|v| v * v, f64_sqrt),
+  ];
+  for tf in functions.iter() {
+    for v in [0.0, 0.01, 0.04, 0.2, 0.5, 0.8, 1.0] {
+      assert!(approx(tf.from_linear(tf.to_linear(v)), v, 1e-12));
+    }
+    assert!(approx(tf.to_linear(1.0), 1.0, 1e-12));
+    assert_eq!(tf, &tf.clone());
+  }
+
+  for v in [-0.5, -0.01, 0.0, 0.01, 0.04, 0.5, 1.0] {
+    assert!(approx(TransferFunction::SRGB.to_linear(v), srgb_to_linear(v), 1e-12));
+  }
+  assert_eq!(TransferFunction::Gamma(2.0).to_linear(-0.5), -0.25);
+  assert_eq!(rec709.from_linear(0.01), 0.045);
+
+  let square = TransferFunction::custom(|v| v * v, f64_sqrt);
+  assert_eq!(square, square.clone());
+  assert_ne!(square, TransferFunction::custom(|v| v * v, f64_sqrt));
+  assert_ne!(TransferFunction::Gamma(2.2), TransferFunction::Gamma(2.4));
+  assert_eq!(format!("{:?}", square), "Custom");
+}
@@ -0,0 +1,48 @@
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+use crate::{ColorUnitsIter, WhitePoint};
+use crate::converters::DISPLAY_P3_PRIMARIES;
+use crate::transfer::{linear_to_srgb, srgb_to_linear};
+use crate::units::Units;
+
+#[cfg(test)]
+mod tests;
+
+/// The Display P3 color space.
+///
+/// Wide-gamut RGB space of Apple displays: DCI-P3 primaries with `D65` white and
+/// the sRGB transfer function. Values are gamma-encoded ratios, linear-light values
+/// can be got with `to_linear` and `from_linear` methods.
+///
+/// Conversion to `Rgb` clips colors which are out of sRGB gamut,
+/// use `in_srgb_gamut` to check it before.
+///
+/// Ranges:
+/// * red: 0.0 - 1.0
+/// * green: 0.0 - 1.0
+/// * blue: 0.0 - 1.0
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{DisplayP3, Rgb};
+///
+/// let p3: DisplayP3 = "color(display-p3 0.5 0.8 0.3)".parse().unwrap();
+/// assert!(p3.in_srgb_gamut());
+/// assert_eq!(Rgb::from(&p3).to_css_string(), "rgb(100,206,47)");
+///
+/// let red = DisplayP3::new(1.0, 0.0, 0.0, Some(0.5));
+/// assert!(!red.in_srgb_gamut());
+/// assert_eq!(Rgb::from(&red).to_css_string(), "rgba(255,0,0,0.5)");
+///
+/// let p3 = DisplayP3::from(Rgb::from([255, 0, 0]));
+/// assert_eq!(p3.to_css_string(), "color(display-p3 0.9175 0.2003 0.1386)");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DisplayP3 {
+  pub(crate) units: Units,
+}
+
+rgb_space_def!(DisplayP3, "display-p3", DISPLAY_P3_PRIMARIES, WhitePoint::D65, srgb_to_linear, linear_to_srgb);
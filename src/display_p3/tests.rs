@@ -0,0 +1,65 @@
+use crate::{ApproxEq, ColorTuple, ColorTupleA, DisplayP3, ParseError, Rgb, WhitePoint, Xyz};
+
+#[test]
+fn display_p3_from_rgb() {
+  let asserts = [
+    ([255, 0, 0], (0.917_488, 0.200_287, 0.138_561)),
+    ([255, 255, 255], (1.0, 1.0, 1.0)),
+    ([0, 0, 0], (0.0, 0.0, 0.0)),
+    ([34, 111, 235], (0.224_838, 0.429_141, 0.890_228)),
+    ([0, 255, 0], (0.458_402, 0.985_265, 0.298_295)),
+  ];
+  for (rgb_arr, p3_t) in asserts.iter() {
+    let rgb = Rgb::from(rgb_arr);
+    let p3 = DisplayP3::from(&rgb);
+    assert!(p3.approx_eq_clarify(&DisplayP3::from(p3_t), 0.000_001));
+    assert!(p3.in_srgb_gamut());
+    assert!(Rgb::from(&p3).approx_eq_clarify(&rgb, 0.000_001));
+  }
+}
+
+#[test]
+fn display_p3_gamut() {
+  assert!(!DisplayP3::new(0.0, 1.0, 0.0, None).in_srgb_gamut());
+  assert!(!DisplayP3::new(0.95, 0.1, 0.1, None).in_srgb_gamut());
+  assert!(DisplayP3::new(0.5, 0.5, 0.5, None).in_srgb_gamut());
+
+  let rgb = Rgb::from(DisplayP3::new(0.0, 1.0, 0.0, None));
+  assert_eq!(Into::<[u8; 3]>::into(rgb), [0, 255, 0]);
+}
+
+#[test]
+fn display_p3_linear_xyz() {
+  let p3 = DisplayP3::from_linear(0.214_041_140_5, 1.0, 0.0, Some(0.5));
+  assert!(p3.approx_eq(&DisplayP3::new(0.5, 1.0, 0.0, Some(0.5))));
+  let (r, g, b) = p3.to_linear();
+  assert!(DisplayP3::from_linear(r, g, b, Some(0.5)).approx_eq(&p3));
+
+  let white = Xyz::from(DisplayP3::new(1.0, 1.0, 1.0, None));
+  assert!(white.approx_eq(&Xyz::from(WhitePoint::D65.xyz())));
+
+  let xyz = Xyz::from(&p3).adapt(WhitePoint::D50);
+  assert!(DisplayP3::from(&xyz).approx_eq(&p3));
+}
+
+#[test]
+fn from_str_tst() {
+  fn parse_p3(s: &str) -> Result<DisplayP3, ParseError> {
+    s.parse::<DisplayP3>()
+  }
+
+  assert_eq!(
+    Into::<ColorTuple>::into(parse_p3("color(display-p3 0.2 0.4 0.6)").unwrap()),
+    (0.2, 0.4, 0.6)
+  );
+  assert_eq!(
+    Into::<ColorTupleA>::into(parse_p3("COLOR(display-p3 50% none 1.5 / 40%)").unwrap()),
+    (0.5, 0.0, 1.0, 0.4)
+  );
+  assert!(parse_p3("color(srgb 0.2 0.4 0.6)").is_err());
+  assert!(parse_p3("color(display-p3 0.2 0.4)").is_err());
+  assert!(parse_p3("color(display-p3 0.2 0.4 red)").is_err());
+
+  let p3 = DisplayP3::new(0.5, 0.25, 0.0, Some(0.5));
+  assert_eq!(parse_p3(&p3.to_css_string()).unwrap(), p3);
+}
@@ -0,0 +1,33 @@
+use crate::{ColorAlpha, Rgb, Xyz, WhitePoint};
+use crate::converters::{hct_to_rgb, hct_to_xyz, xyz_to_hct};
+use crate::xyz::new_xyz_units;
+
+use super::{new_hct_units, Hct};
+
+tuples_def!(Hct, f32);
+tuples_def!(Hct, f64);
+
+fn from_xyz(xyz: &Xyz) -> Hct {
+  let (h, c, t) = xyz_to_hct(&xyz.adapt(WhitePoint::D65).into());
+  let mut units = new_hct_units(h, c, t);
+  units.alpha.set(xyz.alpha());
+  units.restrict();
+  Hct::from_units(units)
+}
+
+fn to_xyz(hct: &Hct) -> Xyz {
+  let (x, y, z) = hct_to_xyz(&hct.into());
+  let mut units = new_xyz_units(x, y, z);
+  units.alpha.set(hct.alpha());
+  Xyz::from_units(units, WhitePoint::D65)
+}
+
+from_for_some!(Xyz, Hct, xyz, { from_xyz(xyz) });
+from_for_some!(Hct, Xyz, hct, { to_xyz(hct) });
+
+from_for_some!(Rgb, Hct, rgb, { from_xyz(&rgb.into()) });
+from_for_some!(Hct, Rgb, hct, {
+  let mut units = hct_to_rgb(&hct.into());
+  units.alpha.set(hct.alpha());
+  Rgb::from_units(units)
+});
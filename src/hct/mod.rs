@@ -0,0 +1,131 @@
+pub use tonal_palette::TonalPalette;
+
+use crate::ColorUnitsIter;
+use crate::consts::ALL_MIN;
+use crate::converters::{hct_to_xyz, xyz_to_hct};
+use crate::units::{Alpha, GetColorUnits, Unit, Units};
+
+#[cfg(test)]
+mod tests;
+
+mod from;
+mod tonal_palette;
+
+/// The HCT (hue, chroma, tone) color system of Material Design 3.
+///
+/// Hue and chroma are those of CAM16 under the default viewing conditions,
+/// tone is CIE L\*. Equal tones give a predictable contrast whatever hue or chroma is.
+/// Only colors of sRGB gamut are represented: `Hct::new` and setters look for
+/// the color with requested hue and tone, and chroma as close to requested as the
+/// gamut allows, then keep hue, chroma and tone of the found color.
+///
+/// Ranges:
+/// * hue: 0.0 - 360.0
+/// * chroma: 0.0 - not restricted, the maximum depends on hue and tone
+/// * tone: 0.0 - 100.0
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{ApproxEq, Hct, Rgb};
+///
+/// let blue = Hct::from(Rgb::from([0, 0, 255]));
+/// assert_eq!(blue.hue().round(), 283.0);
+/// assert_eq!(blue.chroma().round(), 87.0);
+/// assert_eq!(blue.tone().round(), 32.0);
+///
+/// // too colorful to be in sRGB, chroma is reduced
+/// let mut light_blue = blue.clone();
+/// light_blue.set_tone(90.0);
+/// assert_eq!(light_blue.tone().round(), 90.0);
+/// assert!(light_blue.chroma() < 20.0);
+/// assert!(Rgb::from(&light_blue).approx_eq_clarify(&Rgb::from([224, 224, 255]), 0.5));
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hct {
+  pub(crate) units: Units,
+}
+
+iter_def!(Hct);
+
+pub(crate) fn new_hct_units(h: f64, c: f64, t: f64) -> Units {
+  let ul = [
+    Unit::new_hue(h),
+    Unit::new_unbounded(c.max(ALL_MIN)),
+    Unit::new_percent(t),
+    Unit::default(),
+  ];
+  Units { len: 3, list: ul, alpha: Alpha::default() }
+}
+
+// units of the sRGB color closest to requested hue, chroma and tone
+fn solve_units(h: f64, c: f64, t: f64, alpha: &Alpha) -> Units {
+  let (h, c, t) = xyz_to_hct(&hct_to_xyz(&(h, c, t)));
+  let mut units = new_hct_units(h, c, t);
+  units.alpha = alpha.clone();
+  units.restrict();
+  units
+}
+
+impl Hct {
+  pub fn new(h: f64, c: f64, t: f64, a: Option<f64>) -> Hct {
+    let mut alpha = Alpha::default();
+    alpha.set_opt(a);
+    Hct::from_units(solve_units(h, c, t, &alpha))
+  }
+
+  pub(crate) fn from_units(units: Units) -> Self {
+    Hct { units }
+  }
+
+  pub fn hue(&self) -> f64 { self.units[0] }
+  pub fn chroma(&self) -> f64 { self.units[1] }
+  pub fn tone(&self) -> f64 { self.units[2] }
+
+  pub fn set_hue(&mut self, val: f64) {
+    self.units = solve_units(val, self.chroma(), self.tone(), &self.units.alpha);
+  }
+  pub fn set_chroma(&mut self, val: f64) {
+    self.units = solve_units(self.hue(), val, self.tone(), &self.units.alpha);
+  }
+  pub fn set_tone(&mut self, val: f64) {
+    self.units = solve_units(self.hue(), self.chroma(), val, &self.units.alpha);
+  }
+
+  /// Returns an iterator over three color units and the possibly alpha value.
+  pub fn iter(&self) -> ColorUnitsIter {
+    ColorUnitsIter::from_units(&self.units)
+  }
+}
+
+//
+//
+//
+// Default
+//
+impl Default for Hct {
+  fn default() -> Hct {
+    Hct::from_units(new_hct_units(0.0, 0.0, 0.0))
+  }
+}
+
+//
+//
+//
+// AsRef<Hct>
+//
+impl AsRef<Hct> for Hct {
+  fn as_ref(&self) -> &Hct {
+    self
+  }
+}
+
+impl GetColorUnits for Hct {
+  fn get_units(&self) -> &Units {
+    &self.units
+  }
+  fn get_units_mut(&mut self) -> &mut Units {
+    &mut self.units
+  }
+}
@@ -0,0 +1,86 @@
+use crate::common::approx::approx;
+use crate::{ApproxEq, ColorAlpha, Hct, Rgb, TonalPalette, Xyz};
+
+#[test]
+fn hct_from_rgb() {
+  // hue, chroma, tone of Material Design color utilities
+  let asserts = [
+    ([255, 0, 0], [27.408, 113.357, 53.237]),
+    ([0, 255, 0], [142.139, 108.410, 87.737]),
+    ([0, 0, 255], [282.788, 87.230, 32.302]),
+    ([255, 255, 255], [209.492, 2.869, 100.0]),
+    ([0, 0, 0], [0.0, 0.0, 0.0]),
+  ];
+  for (rgb_arr, [h, c, t]) in asserts.iter() {
+    let rgb = Rgb::from(rgb_arr);
+    let hct = Hct::from(&rgb);
+    assert!(approx(hct.hue(), *h, 0.1));
+    assert!(approx(hct.chroma(), *c, 0.05));
+    assert!(approx(hct.tone(), *t, 0.002));
+    assert!(Rgb::from(&hct).approx_eq_clarify(&rgb, 0.000_001));
+  }
+}
+
+#[test]
+fn hct_new() {
+  let hct = Hct::new(120.0, 40.0, 60.0, Some(0.3));
+  assert!(approx(hct.hue(), 120.0, 1e-6));
+  assert!(approx(hct.chroma(), 40.0, 1e-6));
+  assert!(approx(hct.tone(), 60.0, 1e-6));
+  assert_eq!(hct.alpha(), 0.3);
+  assert!(Hct::from(Xyz::from(&hct)).approx_eq(&hct));
+
+  // chroma is reduced to fit in sRGB gamut
+  let hct = Hct::new(120.0, 150.0, 60.0, None);
+  assert!(approx(hct.hue(), 120.0, 1e-4));
+  assert!(approx(hct.tone(), 60.0, 1e-4));
+  assert!(hct.chroma() > 40.0 && hct.chroma() < 150.0);
+
+  let black = Hct::new(30.0, 50.0, 0.0, None);
+  assert!(Rgb::from(&black).approx_eq(&Rgb::from([0, 0, 0])));
+  let white = Hct::new(30.0, 50.0, 100.0, None);
+  assert!(Rgb::from(&white).approx_eq(&Rgb::from([255, 255, 255])));
+}
+
+#[test]
+fn hct_setters() {
+  let mut hct = Hct::from(Rgb::from([34, 111, 235]));
+  hct.set_tone(70.0);
+  assert!(approx(hct.tone(), 70.0, 1e-4));
+  hct.set_chroma(20.0);
+  assert!(approx(hct.chroma(), 20.0, 1e-4));
+  hct.set_hue(200.0);
+  assert!(approx(hct.hue(), 200.0, 1e-4));
+  assert!(approx(hct.chroma(), 20.0, 1e-4));
+  assert!(approx(hct.tone(), 70.0, 1e-4));
+}
+
+#[test]
+fn tonal_palette_of_blue() {
+  // tones of Material Design color utilities
+  let asserts = [
+    (100.0, [255, 255, 255]),
+    (95.0, [241, 239, 255]),
+    (90.0, [224, 224, 255]),
+    (80.0, [190, 194, 255]),
+    (70.0, [157, 163, 255]),
+    (60.0, [124, 132, 255]),
+    (50.0, [90, 100, 255]),
+    (40.0, [52, 61, 255]),
+    (30.0, [0, 0, 239]),
+    (20.0, [0, 1, 172]),
+    (10.0, [0, 0, 110]),
+    (0.0, [0, 0, 0]),
+  ];
+  let palette = TonalPalette::from(Rgb::from([0, 0, 255]));
+  assert!(approx(palette.hue(), 282.788, 0.1));
+  assert!(approx(palette.chroma(), 87.230, 0.05));
+  for (tone, rgb_arr) in asserts.iter() {
+    assert!(palette.tone(*tone).approx_eq_clarify(&Rgb::from(rgb_arr), 1.0));
+  }
+
+  let tones = palette.tones();
+  for (i, tone) in TonalPalette::TONES.iter().enumerate() {
+    assert_eq!(tones[i], palette.tone(*tone));
+  }
+}
@@ -0,0 +1,60 @@
+use crate::{Hct, Rgb};
+use crate::converters::hct_to_rgb;
+
+/// A set of colors of one hue and chroma which differ only in tone,
+/// as the tonal palettes of Material Design 3.
+///
+/// # Example
+/// ```
+/// use colorsys::{ApproxEq, Rgb, TonalPalette};
+///
+/// let palette = TonalPalette::from(Rgb::from([0, 0, 255]));
+/// let container = palette.tone(90.0);
+/// assert!(container.approx_eq_clarify(&Rgb::from([224, 224, 255]), 0.5));
+///
+/// let tones = palette.tones();
+/// assert_eq!(tones.len(), TonalPalette::TONES.len());
+/// assert!(tones[4].approx_eq_clarify(&Rgb::from([52, 61, 255]), 1.0));
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TonalPalette {
+  hue: f64,
+  chroma: f64,
+}
+
+impl TonalPalette {
+  /// Tones used by Material Design 3 color schemes
+  pub const TONES: [f64; 13] = [0.0, 10.0, 20.0, 30.0, 40.0, 50.0, 60.0, 70.0, 80.0, 90.0, 95.0, 99.0, 100.0];
+
+  pub fn new(hue: f64, chroma: f64) -> TonalPalette {
+    TonalPalette { hue, chroma: chroma.max(0.0) }
+  }
+
+  pub fn hue(&self) -> f64 { self.hue }
+  pub fn chroma(&self) -> f64 { self.chroma }
+
+  /// Returns color of palette with given tone (0.0 - 100.0)
+  pub fn tone(&self, tone: f64) -> Rgb {
+    Rgb::from_units(hct_to_rgb(&(self.hue, self.chroma, tone)))
+  }
+
+  /// Returns colors of palette for each of `TONES`
+  pub fn tones(&self) -> [Rgb; 13] {
+    core::array::from_fn(|i| self.tone(TonalPalette::TONES[i]))
+  }
+}
+
+//
+//
+//
+// Default
+//
+impl Default for TonalPalette {
+  fn default() -> TonalPalette {
+    TonalPalette::new(0.0, 0.0)
+  }
+}
+
+from_for_some!(Hct, TonalPalette, hct, { TonalPalette::new(hct.hue(), hct.chroma()) });
+from_for_some!(Rgb, TonalPalette, rgb, { TonalPalette::from(Hct::from(rgb)) });
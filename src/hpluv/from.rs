@@ -0,0 +1,45 @@
+use crate::{ColorAlpha, Hsl, LchUv, Rgb, Xyz};
+use crate::converters::{hpluv_to_lchuv, lchuv_to_hpluv};
+use crate::lchuv::new_lchuv_units;
+use crate::ratio_converters::ratio_to_hpluva;
+
+use super::{new_hpluv_units, Hpluv, HpluvRatio};
+
+tuples_def!(Hpluv, f32);
+tuples_def!(Hpluv, f64);
+
+fn from_lchuv(lchuv: &LchUv) -> Hpluv {
+  let (h, s, l) = lchuv_to_hpluv(&lchuv.into());
+  let mut units = new_hpluv_units(h, s, l);
+  units.alpha.set(lchuv.alpha());
+  units.restrict();
+  Hpluv::from_units(units)
+}
+
+fn to_lchuv(hpluv: &Hpluv) -> LchUv {
+  let (l, c, h) = hpluv_to_lchuv(&hpluv.into());
+  let mut units = new_lchuv_units(l, c, h);
+  units.alpha.set(hpluv.alpha());
+  LchUv::from_units(units)
+}
+
+from_for_some!(LchUv, Hpluv, lchuv, { from_lchuv(lchuv) });
+from_for_some!(Hpluv, LchUv, hpluv, { to_lchuv(hpluv) });
+
+from_for_some!(Xyz, Hpluv, xyz, { from_lchuv(&xyz.into()) });
+from_for_some!(Hpluv, Xyz, hpluv, { to_lchuv(hpluv).into() });
+
+from_for_some!(Rgb, Hpluv, rgb, { from_lchuv(&rgb.into()) });
+from_for_some!(Hpluv, Rgb, hpluv, { to_lchuv(hpluv).into() });
+
+from_for_some!(Hsl, Hpluv, hsl, { from_lchuv(&hsl.into()) });
+from_for_some!(Hpluv, Hsl, hpluv, { to_lchuv(hpluv).into() });
+
+fn from_hpluv_ratio(ratio: &HpluvRatio) -> Hpluv {
+  let ru = &ratio.units;
+  let t = ratio_to_hpluva(&(ru[0], ru[1], ru[2], ru.alpha.get_f64()));
+  Hpluv::new(t.0, t.1, t.2, Some(t.3))
+}
+
+from_for_some!(HpluvRatio, Hpluv, ratio, { from_hpluv_ratio(ratio) });
+into_for_some!(HpluvRatio, Hpluv, self, { self.as_ratio() });
@@ -0,0 +1,169 @@
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+pub use ratio::HpluvRatio;
+
+use crate::{ColorAlpha, ColorUnitsIter, ParseError};
+use crate::common::css_fn::{css_alpha, css_fn_from_str, css_fn_to_string, css_hue, css_number};
+use crate::common::f64_round;
+use crate::consts::{ALL_MIN, HUE_MAX, PERCENT_MAX};
+use crate::err::make_parse_err;
+use crate::units::{Alpha, GetColorUnits, Unit, Units};
+
+#[cfg(test)]
+mod tests;
+
+mod from;
+mod ops;
+mod ratio;
+mod transform;
+
+/// The HPLuv color space: pastel variant of `Hsluv`.
+///
+/// Saturation is the percent of the maximum chroma which is available in sRGB gamut
+/// for given lightness and *any* hue. So colors with the same lightness and saturation
+/// have the same chroma, but only pastel colors can be represented with saturation
+/// up to `100.0`. Values above `100.0` are kept, but they are out of the "safe" range
+/// and some of them are out of sRGB gamut.
+///
+/// Ranges:
+/// * hue: 0.0 - 360.0
+/// * saturation: 0.0 - not restricted, colors with `0.0 - 100.0` are always in sRGB gamut
+/// * lightness: 0.0 - 100.0
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{Hpluv, Rgb, prelude::*};
+///
+/// let hpluv = Hpluv::from(Rgb::from([255, 0, 0]));
+/// assert!(hpluv.approx_eq_clarify(&Hpluv::new(12.177, 426.7468, 53.2371, None), 0.0001));
+///
+/// let mut hpluv = Hpluv::new(250.0, 100.0, 60.0, None);
+/// hpluv.adjust_hue(60.0);
+/// assert_eq!(Rgb::from(&hpluv).to_css_string(), "rgb(184,124,181)");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hpluv {
+  pub(crate) units: Units,
+}
+
+iter_def!(Hpluv);
+
+pub(crate) fn new_hpluv_units(h: f64, s: f64, l: f64) -> Units {
+  let ul = [Unit::new_hue(h), Unit::new_unbounded(s.max(ALL_MIN)), Unit::new_percent(l), Unit::default()];
+  Units { len: 3, list: ul, alpha: Alpha::default() }
+}
+
+impl Hpluv {
+  pub fn new(h: f64, s: f64, l: f64, a: Option<f64>) -> Hpluv {
+    let mut units = new_hpluv_units(h, s, l);
+    units.alpha.set_opt(a);
+    units.restrict();
+    Hpluv { units }
+  }
+
+  pub(crate) fn from_units(u: Units) -> Self { Hpluv { units: u } }
+
+  /// Returns a String in the format of CSS functions, like `hpluv(12 100% 53%)`.
+  /// Hpluv is not a part of CSS, the string is for parsing with `FromStr` and for other
+  /// tools which support this notation.
+  /// # Example
+  /// ```
+  /// use colorsys::Hpluv;
+  ///
+  /// let hpluv = Hpluv::new(194.2, 63.0, 12.7, Some(0.75));
+  /// assert_eq!(hpluv.to_css_string(), "hpluv(194 63% 13% / 0.75)");
+  /// ```
+  pub fn to_css_string(&self) -> String {
+    let u = &self.units;
+    let components = [
+      format!("{}", f64_round(u[0])),
+      format!("{}%", f64_round(u[1])),
+      format!("{}%", f64_round(u[2])),
+    ];
+    css_fn_to_string("hpluv", &components, u.alpha.get_f64())
+  }
+
+  pub fn hue(&self) -> f64 { self.units[0] }
+  pub fn saturation(&self) -> f64 { self.units[1] }
+  pub fn lightness(&self) -> f64 { self.units[2] }
+
+  pub fn set_hue(&mut self, val: f64) { self.units.list[0].set(val); }
+  pub fn set_saturation(&mut self, val: f64) { self.units.list[1].set(val.max(ALL_MIN)); }
+  pub fn set_lightness(&mut self, val: f64) { self.units.list[2].set(val); }
+
+  /// Returns an iterator over three color units and the possibly alpha value.
+  pub fn iter(&self) -> ColorUnitsIter {
+    ColorUnitsIter::from_units(&self.units)
+  }
+
+  /// Returns an HPLuv representation with values converted to float from 0.0 to 1.0,
+  /// saturation is not restricted
+  pub fn as_ratio(&self) -> HpluvRatio {
+    let u = &self.units;
+    HpluvRatio::new(u[0] / HUE_MAX, u[1] / PERCENT_MAX, u[2] / PERCENT_MAX, u.alpha.get_f64())
+  }
+}
+
+//
+//
+//
+// Default
+//
+impl Default for Hpluv {
+  fn default() -> Hpluv {
+    Hpluv::from_units(new_hpluv_units(0.0, 0.0, 0.0))
+  }
+}
+
+//
+//
+//
+// AsRef<Hpluv>
+//
+impl AsRef<Hpluv> for Hpluv {
+  fn as_ref(&self) -> &Hpluv {
+    self
+  }
+}
+
+
+//
+//
+//
+// FromStr
+//
+impl core::str::FromStr for Hpluv {
+  type Err = ParseError;
+  /// Parses `hpluv(..)` string in the format of CSS functions. Hue can be specified with
+  /// angle units (`deg`, `rad`, etc.), saturation and lightness as percentages or numbers
+  fn from_str(s: &str) -> Result<Hpluv, ParseError> {
+    let make_err = || make_parse_err(s, "hpluv");
+    let (components, alpha) = css_fn_from_str(s, &["hpluv"], 3).ok_or_else(make_err)?;
+
+    let h = css_hue(&components[0]).ok_or_else(make_err)?;
+    let sat = css_number(&components[1], PERCENT_MAX).ok_or_else(make_err)?;
+    let l = css_number(&components[2], PERCENT_MAX).ok_or_else(make_err)?;
+    if sat < ALL_MIN || !(ALL_MIN..=PERCENT_MAX).contains(&l) {
+      return Err(make_err());
+    }
+
+    let mut hpluv = Hpluv::new(h, sat, l, None);
+    if let Some(a) = alpha {
+      hpluv.set_alpha(css_alpha(&a).ok_or_else(make_err)?);
+    }
+    Ok(hpluv)
+  }
+}
+
+
+impl GetColorUnits for Hpluv {
+  fn get_units(&self) -> &Units {
+    &self.units
+  }
+  fn get_units_mut(&mut self) -> &mut Units {
+    &mut self.units
+  }
+}
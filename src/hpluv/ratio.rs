@@ -0,0 +1,126 @@
+use crate::consts::ALL_MIN;
+use crate::units::{Alpha, GetColorUnits, Unit, Units};
+
+///
+/// Hpluv representation as ratio (from `0.0` to `1.0`).
+/// Saturation is not restricted, as in `Hpluv`: `1.0` is `100.0` of `Hpluv`.
+/// Cannot be modified, added, subtracted, etc. Can be converted to `Hpluv` and vice versa.
+/// Used for compatibility in various libraries.
+///
+/// # Example
+/// ```rust
+/// use colorsys::{ApproxEq, Hpluv, HpluvRatio};
+///
+/// let origin = Hpluv::from((126.0, 250.0, 52.0));
+///
+/// let ratio_f32: [f32; 3] = origin.as_ratio().into();
+/// let ratio_f64: [f64; 4] = origin.as_ratio().into();
+/// // ~[0.35, 2.5, 0.52, 1.0]
+///
+/// let converted_f32: Hpluv = HpluvRatio::from(&ratio_f32).into();
+/// let converted_f64: Hpluv = HpluvRatio::from(&ratio_f64).into();
+///
+/// assert!(origin.approx_eq_clarify(&converted_f32, 0.0001));
+/// assert!(origin.approx_eq(&converted_f64));
+/// ```
+///
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HpluvRatio {
+  pub(super) units: Units,
+}
+
+impl HpluvRatio {
+  pub fn new(h: f64, s: f64, l: f64, a: f64) -> Self {
+    let ul = [Unit::new_ratio(h), Unit::new_unbounded(s.max(ALL_MIN)), Unit::new_ratio(l), Unit::default()];
+    let mut units = Units { len: 3, list: ul, alpha: Alpha::default() };
+    units.alpha.set(a);
+    units.restrict();
+    HpluvRatio::from_units(units)
+  }
+
+  pub fn h(&self) -> f64 { self.units[0] }
+  pub fn s(&self) -> f64 { self.units[1] }
+  pub fn l(&self) -> f64 { self.units[2] }
+  pub fn a(&self) -> f64 { self.units.alpha.get_f64() }
+
+  pub(crate) fn from_units(u: Units) -> Self { HpluvRatio { units: u } }
+}
+
+impl AsRef<HpluvRatio> for HpluvRatio {
+  fn as_ref(&self) -> &HpluvRatio {
+    self
+  }
+}
+
+impl GetColorUnits for HpluvRatio {
+  fn get_units(&self) -> &Units {
+    &self.units
+  }
+  fn get_units_mut(&mut self) -> &mut Units {
+    &mut self.units
+  }
+}
+
+macro_rules! from_for_hpluv_ratio {
+  ($from_type: ty, $val: ident, $conv: block) => {
+    impl From<&$from_type> for HpluvRatio {
+      fn from($val: &$from_type) -> HpluvRatio {
+        ($conv)
+      }
+    }
+    impl From<$from_type> for HpluvRatio {
+      fn from($val: $from_type) -> HpluvRatio {
+        HpluvRatio::from(&$val)
+      }
+    }
+  };
+}
+
+macro_rules! from_for_hpluv_ratio_all {
+  ($t: ty) => {
+    from_for_hpluv_ratio!(($t, $t, $t), v, {
+      let (h, s, l) = *v;
+      HpluvRatio::new(h as f64, s as f64, l as f64, 1.0)
+    });
+    from_for_hpluv_ratio!(($t, $t, $t, $t), v, {
+      let (h, s, l, a) = *v;
+      HpluvRatio::new(h as f64, s as f64, l as f64, a as f64)
+    });
+    from_for_hpluv_ratio!([$t; 3], v, {
+      let [h, s, l] = *v;
+      HpluvRatio::new(h as f64, s as f64, l as f64, 1.0)
+    });
+    from_for_hpluv_ratio!([$t; 4], v, {
+      let [h, s, l, a] = *v;
+      HpluvRatio::new(h as f64, s as f64, l as f64, a as f64)
+    });
+  };
+}
+
+from_for_hpluv_ratio_all!(f32);
+from_for_hpluv_ratio_all!(f64);
+
+macro_rules! into_for_hpluv_ratio_all {
+  ($t: ty) => {
+    into_for_some!(($t, $t, $t), HpluvRatio, self, {
+      let u = &self.get_units();
+      (u[0] as $t, u[1] as $t, u[2] as $t)
+    });
+    into_for_some!(($t, $t, $t, $t), HpluvRatio, self, {
+      let u = &self.get_units();
+      (u[0] as $t, u[1] as $t, u[2] as $t, u.alpha.get_f64() as $t)
+    });
+    into_for_some!([$t; 3], HpluvRatio, self, {
+      let u = &self.get_units();
+      [u[0] as $t, u[1] as $t, u[2] as $t]
+    });
+    into_for_some!([$t; 4], HpluvRatio, self, {
+      let u = &self.get_units();
+      [u[0] as $t, u[1] as $t, u[2] as $t, u.alpha.get_f64() as $t]
+    });
+  };
+}
+
+into_for_hpluv_ratio_all!(f32);
+into_for_hpluv_ratio_all!(f64);
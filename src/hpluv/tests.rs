@@ -0,0 +1,82 @@
+use crate::{
+  ApproxEq, ColorTransform, ColorTuple, ColorTupleA, Hpluv, HpluvRatio, Hsluv, LchUv, ParseError,
+  Rgb, SaturationInSpace,
+};
+
+#[test]
+fn hpluv_from_rgb() {
+  let asserts = [
+    ([255, 0, 0], (12.177_051, 426.746_786, 53.237_116)),
+    ([255, 255, 255], (0.0, 0.0, 100.0)),
+    ([0, 0, 0], (0.0, 0.0, 0.0)),
+    ([128, 128, 128], (0.0, 0.0, 53.585_013)),
+  ];
+  for (rgb_arr, hpluv_t) in asserts.iter() {
+    let rgb = Rgb::from(rgb_arr);
+    let hpluv = Hpluv::from(&rgb);
+    assert!(hpluv.approx_eq_clarify(&Hpluv::from(hpluv_t), 0.0001));
+    assert!(Rgb::from(&hpluv).approx_eq_clarify(&rgb, 0.000_001));
+  }
+}
+
+#[test]
+fn hpluv_hsluv() {
+  // with the same lightness and saturation all hues have the same chroma
+  // which is always in sRGB gamut
+  for h in (5..360).step_by(30) {
+    let hpluv = Hpluv::new(h as f64, 100.0, 40.0, None);
+    let hsluv = Hsluv::from(LchUv::from(&hpluv));
+    assert!(hsluv.saturation() <= 100.000_001);
+    assert!(Hpluv::from(Rgb::from(&hpluv)).approx_eq_clarify(&hpluv, 0.000_001));
+  }
+}
+
+#[test]
+fn hpluv_transform() {
+  let mut hpluv = Hpluv::new(40.0, 50.0, 50.0, None);
+  hpluv.saturate(SaturationInSpace::Hsl(100.0));
+  assert_eq!(hpluv.saturation(), 150.0);
+  hpluv.saturate(SaturationInSpace::Hsv(-200.0));
+  assert_eq!(hpluv.saturation(), 0.0);
+
+  hpluv.set_saturation(20.0);
+  hpluv.lighten(10.0);
+  hpluv.adjust_hue(330.0);
+  assert_eq!(Into::<ColorTuple>::into(&hpluv), (10.0, 20.0, 60.0));
+
+  hpluv.invert();
+  hpluv.grayscale_simple();
+  assert_eq!(Into::<ColorTuple>::into(&hpluv), (0.0, 0.0, 60.0));
+}
+
+#[test]
+fn hpluv_ratio() {
+  let hpluv = Hpluv::new(90.0, 250.0, 30.0, Some(0.5));
+  let ratio = hpluv.as_ratio();
+  assert_eq!(Into::<ColorTupleA>::into(&ratio), (0.25, 2.5, 0.3, 0.5));
+  assert!(Hpluv::from(&ratio).approx_eq(&hpluv));
+  assert!(Hpluv::from(HpluvRatio::from([0.25, 2.5, 0.3, 0.5])).approx_eq(&hpluv));
+}
+
+#[test]
+fn from_str_tst() {
+  fn parse_hpluv(s: &str) -> Result<Hpluv, ParseError> {
+    s.parse::<Hpluv>()
+  }
+
+  assert_eq!(
+    Into::<ColorTuple>::into(parse_hpluv("hpluv(134 250% 51%)").unwrap()),
+    (134.0, 250.0, 51.0)
+  );
+  assert_eq!(
+    Into::<ColorTupleA>::into(parse_hpluv("hpluv(90deg, 11, 51, 0.3)").unwrap()),
+    (90.0, 11.0, 51.0, 0.3)
+  );
+  assert!(parse_hpluv("hpluv(134 -11% 51%)").is_err());
+  assert!(parse_hpluv("hpluv(134 11% 151%)").is_err());
+  assert!(parse_hpluv("hsluv(134 11% 51%)").is_err());
+
+  let hpluv = Hpluv::new(14.4, 199.5, 40.0, None);
+  assert_eq!(hpluv.to_css_string(), "hpluv(14 200% 40%)");
+  assert!(parse_hpluv(&hpluv.to_css_string()).is_ok());
+}
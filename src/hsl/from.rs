@@ -3,6 +3,7 @@ use crate::{
 };
 
 use super::{Hsl, HslRatio, Rgb};
+use crate::{Hsv, Hwb};
 
 macro_rules! from_for_hsl {
   ($from_type: ty, $val: ident, $conv: block) => {
@@ -102,6 +103,56 @@ impl From<Rgb> for Hsl {
   }
 }
 
+fn from_hsv(hsv: &Hsv) -> Hsl {
+  let a = hsv.alpha();
+  let mut hsl = Hsl::from_units(hsv_to_hsl(hsv));
+  hsl.set_alpha(a);
+  hsl
+}
+
+impl From<&Hsv> for Hsl {
+  /// # Example
+  /// ```
+  /// use colorsys::{Hsl,Hsv};
+  /// let hsv = Hsv::from(&(194.0, 9.36, 92.32));
+  /// let hsl = Hsl::from(&hsv);
+  /// assert_eq!(hsl.to_css_string(), "hsl(194,36%,88%)");
+  /// ```
+  fn from(hsv: &Hsv) -> Self {
+    from_hsv(hsv)
+  }
+}
+
+impl From<&mut Hsv> for Hsl {
+  fn from(hsv: &mut Hsv) -> Self {
+    from_hsv(hsv)
+  }
+}
+
+impl From<Hsv> for Hsl {
+  fn from(hsv: Hsv) -> Self {
+    from_hsv(&hsv)
+  }
+}
+
+impl From<&Hwb> for Hsl {
+  fn from(hwb: &Hwb) -> Self {
+    from_hsv(&Hsv::from(hwb))
+  }
+}
+
+impl From<&mut Hwb> for Hsl {
+  fn from(hwb: &mut Hwb) -> Self {
+    from_hsv(&Hsv::from(hwb))
+  }
+}
+
+impl From<Hwb> for Hsl {
+  fn from(hwb: Hwb) -> Self {
+    from_hsv(&Hsv::from(hwb))
+  }
+}
+
 fn from_hsl_ratio(ratio: &HslRatio) -> Hsl {
   let ru = &ratio.units;
   let t = ratio_to_hsla(&(ru[0], ru[1], ru[2], ru.alpha.get_f64()));
@@ -1,4 +1,4 @@
-use crate::{ColorTransform, SaturationInSpace};
+use crate::{ColorTransform, Hsv, SaturationInSpace};
 use crate::consts::{ALL_MIN, HUE_MAX};
 use crate::normalize::bound_hue;
 
@@ -13,7 +13,11 @@ impl ColorTransform for Hsl {
     match sat {
       SaturationInSpace::Hsl(s) => self.units.list[1].increase(s),
       SaturationInSpace::Hsv(s) => {
-        unimplemented!("{}", s);
+        let mut hsv: Hsv = self.as_ref().into();
+        hsv.saturate(SaturationInSpace::Hsv(s));
+        let hsl = Hsl::from(hsv);
+        self.units.list[1].value = hsl.units[1];
+        self.units.list[2].value = hsl.units[2];
       }
     }
   }
@@ -0,0 +1,45 @@
+use crate::{ColorAlpha, Hsl, LchUv, Rgb, Xyz};
+use crate::converters::{hsluv_to_lchuv, lchuv_to_hsluv};
+use crate::lchuv::new_lchuv_units;
+use crate::ratio_converters::ratio_to_hsluva;
+
+use super::{new_hsluv_units, Hsluv, HsluvRatio};
+
+tuples_def!(Hsluv, f32);
+tuples_def!(Hsluv, f64);
+
+fn from_lchuv(lchuv: &LchUv) -> Hsluv {
+  let (h, s, l) = lchuv_to_hsluv(&lchuv.into());
+  let mut units = new_hsluv_units(h, s, l);
+  units.alpha.set(lchuv.alpha());
+  units.restrict();
+  Hsluv::from_units(units)
+}
+
+fn to_lchuv(hsluv: &Hsluv) -> LchUv {
+  let (l, c, h) = hsluv_to_lchuv(&hsluv.into());
+  let mut units = new_lchuv_units(l, c, h);
+  units.alpha.set(hsluv.alpha());
+  LchUv::from_units(units)
+}
+
+from_for_some!(LchUv, Hsluv, lchuv, { from_lchuv(lchuv) });
+from_for_some!(Hsluv, LchUv, hsluv, { to_lchuv(hsluv) });
+
+from_for_some!(Xyz, Hsluv, xyz, { from_lchuv(&xyz.into()) });
+from_for_some!(Hsluv, Xyz, hsluv, { to_lchuv(hsluv).into() });
+
+from_for_some!(Rgb, Hsluv, rgb, { from_lchuv(&rgb.into()) });
+from_for_some!(Hsluv, Rgb, hsluv, { to_lchuv(hsluv).into() });
+
+from_for_some!(Hsl, Hsluv, hsl, { from_lchuv(&hsl.into()) });
+from_for_some!(Hsluv, Hsl, hsluv, { to_lchuv(hsluv).into() });
+
+fn from_hsluv_ratio(ratio: &HsluvRatio) -> Hsluv {
+  let ru = &ratio.units;
+  let t = ratio_to_hsluva(&(ru[0], ru[1], ru[2], ru.alpha.get_f64()));
+  Hsluv::new(t.0, t.1, t.2, Some(t.3))
+}
+
+from_for_some!(HsluvRatio, Hsluv, ratio, { from_hsluv_ratio(ratio) });
+into_for_some!(HsluvRatio, Hsluv, self, { self.as_ratio() });
@@ -0,0 +1,166 @@
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+pub use ratio::HsluvRatio;
+
+use crate::{ColorAlpha, ColorUnitsIter, ParseError};
+use crate::common::css_fn::{css_alpha, css_fn_from_str, css_fn_to_string, css_hue, css_number};
+use crate::common::f64_round;
+use crate::consts::{ALL_MIN, PERCENT_MAX};
+use crate::err::make_parse_err;
+use crate::units::{Alpha, GetColorUnits, Unit, Units};
+
+#[cfg(test)]
+mod tests;
+
+mod from;
+mod ops;
+mod ratio;
+mod transform;
+
+/// The HSLuv color space: human-friendly alternative to HSL.
+///
+/// It is a cylindrical transformation of `LchUv`, where saturation is the percent of
+/// the maximum chroma available in sRGB gamut for given lightness and hue.
+/// So, unlike `Hsl`, lightness is perceptual (it is L\* of CIE L\*u\*v\*) and every
+/// combination of components is a valid sRGB color.
+///
+/// Ranges:
+/// * hue: 0.0 - 360.0
+/// * saturation: 0.0 - 100.0
+/// * lightness: 0.0 - 100.0
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{Hsluv, Rgb, prelude::*};
+///
+/// let mut hsluv = Hsluv::from(Rgb::from([255, 0, 0]));
+/// assert!(hsluv.approx_eq_clarify(&Hsluv::new(12.177, 100.0, 53.2371, None), 0.0001));
+///
+/// hsluv.set_lightness(80.0);
+/// hsluv.set_saturation(50.0);
+/// assert_eq!(Rgb::from(&hsluv).to_css_string(), "rgb(229,189,189)");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hsluv {
+  pub(crate) units: Units,
+}
+
+iter_def!(Hsluv);
+
+pub(crate) fn new_hsluv_units(h: f64, s: f64, l: f64) -> Units {
+  let ul = [Unit::new_hue(h), Unit::new_percent(s), Unit::new_percent(l), Unit::default()];
+  Units { len: 3, list: ul, alpha: Alpha::default() }
+}
+
+impl Hsluv {
+  pub fn new(h: f64, s: f64, l: f64, a: Option<f64>) -> Hsluv {
+    let mut units = new_hsluv_units(h, s, l);
+    units.alpha.set_opt(a);
+    units.restrict();
+    Hsluv { units }
+  }
+
+  pub(crate) fn from_units(u: Units) -> Self { Hsluv { units: u } }
+
+  /// Returns a String in the format of CSS functions, like `hsluv(12 100% 53%)`.
+  /// Hsluv is not a part of CSS, the string is for parsing with `FromStr` and for other
+  /// tools which support this notation.
+  /// # Example
+  /// ```
+  /// use colorsys::Hsluv;
+  ///
+  /// let hsluv = Hsluv::new(194.2, 63.0, 12.7, Some(0.75));
+  /// assert_eq!(hsluv.to_css_string(), "hsluv(194 63% 13% / 0.75)");
+  /// ```
+  pub fn to_css_string(&self) -> String {
+    let u = &self.units;
+    let components = [
+      format!("{}", f64_round(u[0])),
+      format!("{}%", f64_round(u[1])),
+      format!("{}%", f64_round(u[2])),
+    ];
+    css_fn_to_string("hsluv", &components, u.alpha.get_f64())
+  }
+
+  pub fn hue(&self) -> f64 { self.units[0] }
+  pub fn saturation(&self) -> f64 { self.units[1] }
+  pub fn lightness(&self) -> f64 { self.units[2] }
+
+  pub fn set_hue(&mut self, val: f64) { self.units.list[0].set(val); }
+  pub fn set_saturation(&mut self, val: f64) { self.units.list[1].set(val); }
+  pub fn set_lightness(&mut self, val: f64) { self.units.list[2].set(val); }
+
+  /// Returns an iterator over three color units and the possibly alpha value.
+  pub fn iter(&self) -> ColorUnitsIter {
+    ColorUnitsIter::from_units(&self.units)
+  }
+
+  /// Returns an HSLuv representation with values converted to float from 0.0 to 1.0
+  pub fn as_ratio(&self) -> HsluvRatio {
+    HsluvRatio::from_units(self.units.as_ratio())
+  }
+}
+
+//
+//
+//
+// Default
+//
+impl Default for Hsluv {
+  fn default() -> Hsluv {
+    Hsluv::from_units(new_hsluv_units(0.0, 0.0, 0.0))
+  }
+}
+
+//
+//
+//
+// AsRef<Hsluv>
+//
+impl AsRef<Hsluv> for Hsluv {
+  fn as_ref(&self) -> &Hsluv {
+    self
+  }
+}
+
+
+//
+//
+//
+// FromStr
+//
+impl core::str::FromStr for Hsluv {
+  type Err = ParseError;
+  /// Parses `hsluv(..)` string in the format of CSS functions. Hue can be specified with
+  /// angle units (`deg`, `rad`, etc.), saturation and lightness as percentages or numbers
+  fn from_str(s: &str) -> Result<Hsluv, ParseError> {
+    let make_err = || make_parse_err(s, "hsluv");
+    let (components, alpha) = css_fn_from_str(s, &["hsluv"], 3).ok_or_else(make_err)?;
+
+    let h = css_hue(&components[0]).ok_or_else(make_err)?;
+    let sat = css_number(&components[1], PERCENT_MAX).ok_or_else(make_err)?;
+    let l = css_number(&components[2], PERCENT_MAX).ok_or_else(make_err)?;
+    if !(ALL_MIN..=PERCENT_MAX).contains(&sat) || !(ALL_MIN..=PERCENT_MAX).contains(&l) {
+      return Err(make_err());
+    }
+
+    let mut hsluv = Hsluv::new(h, sat, l, None);
+    if let Some(a) = alpha {
+      hsluv.set_alpha(css_alpha(&a).ok_or_else(make_err)?);
+    }
+    Ok(hsluv)
+  }
+}
+
+
+impl GetColorUnits for Hsluv {
+  fn get_units(&self) -> &Units {
+    &self.units
+  }
+  fn get_units_mut(&mut self) -> &mut Units {
+    &mut self.units
+  }
+}
@@ -0,0 +1,122 @@
+use crate::units::{Units, GetColorUnits};
+
+///
+/// Hsluv representation as ratio (from `0.0` to `1.0`).
+/// Cannot be modified, added, subtracted, etc. Can be converted to `Hsluv` and vice versa.
+/// Used for compatibility in various libraries.
+///
+/// # Example
+/// ```rust
+/// use colorsys::{ApproxEq, Hsluv, HsluvRatio};
+///
+/// let origin = Hsluv::from((126.0, 43.0, 52.0));
+///
+/// let ratio_f32: [f32; 3] = origin.as_ratio().into();
+/// let ratio_f64: [f64; 4] = origin.as_ratio().into();
+/// // ~[0.35, 0.43, 0.52, 1.0]
+///
+/// let converted_f32: Hsluv = HsluvRatio::from(&ratio_f32).into();
+/// let converted_f64: Hsluv = HsluvRatio::from(&ratio_f64).into();
+///
+/// assert!(origin.approx_eq_clarify(&converted_f32, 0.0001));
+/// assert!(origin.approx_eq(&converted_f64));
+/// ```
+///
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HsluvRatio {
+  pub(super) units: Units,
+}
+
+impl HsluvRatio {
+  pub fn new(h: f64, s: f64, l: f64, a: f64) -> Self {
+    let mut units = Units::new_ratios(&[h, s, l]);
+    units.alpha.set(a);
+    HsluvRatio::from_units(units)
+  }
+
+  pub fn h(&self) -> f64 { self.units[0] }
+  pub fn s(&self) -> f64 { self.units[1] }
+  pub fn l(&self) -> f64 { self.units[2] }
+  pub fn a(&self) -> f64 { self.units.alpha.get_f64() }
+
+  pub(crate) fn from_units(u: Units) -> Self { HsluvRatio { units: u } }
+}
+
+impl AsRef<HsluvRatio> for HsluvRatio {
+  fn as_ref(&self) -> &HsluvRatio {
+    self
+  }
+}
+
+impl GetColorUnits for HsluvRatio {
+  fn get_units(&self) -> &Units {
+    &self.units
+  }
+  fn get_units_mut(&mut self) -> &mut Units {
+    &mut self.units
+  }
+}
+
+macro_rules! from_for_hsluv_ratio {
+  ($from_type: ty, $val: ident, $conv: block) => {
+    impl From<&$from_type> for HsluvRatio {
+      fn from($val: &$from_type) -> HsluvRatio {
+        ($conv)
+      }
+    }
+    impl From<$from_type> for HsluvRatio {
+      fn from($val: $from_type) -> HsluvRatio {
+        HsluvRatio::from(&$val)
+      }
+    }
+  };
+}
+
+macro_rules! from_for_hsluv_ratio_all {
+  ($t: ty) => {
+    from_for_hsluv_ratio!(($t, $t, $t), v, {
+      let (h, s, l) = *v;
+      HsluvRatio::new(h as f64, s as f64, l as f64, 1.0)
+    });
+    from_for_hsluv_ratio!(($t, $t, $t, $t), v, {
+      let (h, s, l, a) = *v;
+      HsluvRatio::new(h as f64, s as f64, l as f64, a as f64)
+    });
+    from_for_hsluv_ratio!([$t; 3], v, {
+      let [h, s, l] = *v;
+      HsluvRatio::new(h as f64, s as f64, l as f64, 1.0)
+    });
+    from_for_hsluv_ratio!([$t; 4], v, {
+      let [h, s, l, a] = *v;
+      HsluvRatio::new(h as f64, s as f64, l as f64, a as f64)
+    });
+  };
+}
+
+from_for_hsluv_ratio_all!(f32);
+from_for_hsluv_ratio_all!(f64);
+
+macro_rules! into_for_hsluv_ratio_all {
+  ($t: ty) => {
+    into_for_some!(($t, $t, $t), HsluvRatio, self, {
+      let u = &self.get_units();
+      (u[0] as $t, u[1] as $t, u[2] as $t)
+    });
+    into_for_some!(($t, $t, $t, $t), HsluvRatio, self, {
+      let u = &self.get_units();
+      (u[0] as $t, u[1] as $t, u[2] as $t, u.alpha.get_f64() as $t)
+    });
+    into_for_some!([$t; 3], HsluvRatio, self, {
+      let u = &self.get_units();
+      [u[0] as $t, u[1] as $t, u[2] as $t]
+    });
+    into_for_some!([$t; 4], HsluvRatio, self, {
+      let u = &self.get_units();
+      [u[0] as $t, u[1] as $t, u[2] as $t, u.alpha.get_f64() as $t]
+    });
+  };
+}
+
+into_for_hsluv_ratio_all!(f32);
+into_for_hsluv_ratio_all!(f64);
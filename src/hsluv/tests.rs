@@ -0,0 +1,95 @@
+use crate::{
+  ApproxEq, ColorTransform, ColorTuple, ColorTupleA, Hsl, Hsluv, HsluvRatio, LchUv, ParseError,
+  Rgb, SaturationInSpace,
+};
+
+#[test]
+fn hsluv_from_rgb() {
+  let asserts = [
+    ([255, 0, 0], (12.177_051, 100.0, 53.237_116)),
+    ([255, 255, 255], (0.0, 0.0, 100.0)),
+    ([0, 0, 0], (0.0, 0.0, 0.0)),
+    ([128, 128, 128], (0.0, 0.0, 53.585_013)),
+    ([0, 0, 255], (265.874_36, 100.0, 32.300_79)),
+  ];
+  for (rgb_arr, hsluv_t) in asserts.iter() {
+    let rgb = Rgb::from(rgb_arr);
+    let hsluv = Hsluv::from(&rgb);
+    assert!(hsluv.approx_eq_clarify(&Hsluv::from(hsluv_t), 0.0001));
+    assert!(Rgb::from(&hsluv).approx_eq_clarify(&rgb, 0.000_001));
+    assert!(hsluv.approx_eq_clarify(&rgb, 0.000_001));
+  }
+}
+
+#[test]
+fn hsluv_is_in_gamut() {
+  for h in (5..360).step_by(15) {
+    for l in (5..100).step_by(10) {
+      let hsluv = Hsluv::new(h as f64, 100.0, l as f64, None);
+      let lchuv = LchUv::from(&hsluv);
+      let back = Hsluv::from(&lchuv);
+      assert!(back.approx_eq_clarify(&hsluv, 0.000_001));
+      assert!(Hsluv::from(Rgb::from(&hsluv)).approx_eq_clarify(&hsluv, 0.000_001));
+    }
+  }
+}
+
+#[test]
+fn hsluv_conversions() {
+  let hsl = Hsl::new(200.0, 40.0, 30.0, Some(0.5));
+  let hsluv = Hsluv::from(&hsl);
+  assert!(Hsl::from(&hsluv).approx_eq_clarify(&hsl, 0.000_001));
+
+  let sum = Hsluv::new(10.0, 20.0, 30.0, None) + Hsluv::new(20.0, 30.0, 40.0, None);
+  assert_eq!(Into::<ColorTuple>::into(sum), (30.0, 50.0, 70.0));
+}
+
+#[test]
+fn hsluv_transform() {
+  let mut hsluv = Hsluv::new(40.0, 50.0, 50.0, None);
+  hsluv.lighten(-20.0);
+  hsluv.saturate(SaturationInSpace::Hsv(60.0));
+  assert_eq!(Into::<ColorTuple>::into(&hsluv), (40.0, 100.0, 30.0));
+
+  hsluv.adjust_hue(-60.0);
+  assert_eq!(hsluv.hue(), 340.0);
+
+  hsluv.invert();
+  assert_eq!(hsluv.hue(), 160.0);
+
+  hsluv.grayscale_simple();
+  assert_eq!(Into::<ColorTuple>::into(&hsluv), (0.0, 0.0, 30.0));
+}
+
+#[test]
+fn hsluv_ratio() {
+  let hsluv = Hsluv::new(90.0, 40.0, 30.0, Some(0.5));
+  let ratio = hsluv.as_ratio();
+  assert_eq!(Into::<ColorTupleA>::into(&ratio), (0.25, 0.4, 0.3, 0.5));
+  assert!(Hsluv::from(&ratio).approx_eq(&hsluv));
+  assert!(Hsluv::from(HsluvRatio::from([0.25, 0.4, 0.3, 0.5])).approx_eq(&hsluv));
+}
+
+#[test]
+fn from_str_tst() {
+  fn parse_hsluv(s: &str) -> Result<Hsluv, ParseError> {
+    s.parse::<Hsluv>()
+  }
+
+  assert_eq!(
+    Into::<ColorTuple>::into(parse_hsluv("hsluv(134 11% 51%)").unwrap()),
+    (134.0, 11.0, 51.0)
+  );
+  assert_eq!(
+    Into::<ColorTupleA>::into(parse_hsluv("HSLuv(0.25turn 11 51 / 30%)").unwrap()),
+    (90.0, 11.0, 51.0, 0.3)
+  );
+  assert!(parse_hsluv("hsluv(134 111% 51%)").is_err());
+  assert!(parse_hsluv("hsluv(134 11% 51% / 2)").is_err());
+  assert!(parse_hsluv("hsluv(134 11%)").is_err());
+  assert!(parse_hsluv("hsl(134 11% 51%)").is_err());
+
+  let hsluv = Hsluv::new(14.4, 99.5, 0.0, Some(0.5));
+  assert_eq!(hsluv.to_css_string(), "hsluv(14 100% 0% / 0.5)");
+  assert!(parse_hsluv(&hsluv.to_css_string()).is_ok());
+}
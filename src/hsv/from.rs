@@ -0,0 +1,216 @@
+use crate::{
+  ColorAlpha, converters::*, ratio_converters::ratio_to_hsva,
+};
+
+use super::{Hsv, HsvRatio};
+use crate::{Hsl, Hwb, Rgb};
+
+macro_rules! from_for_hsv {
+  ($from_type: ty, $val: ident, $conv: block) => {
+    impl From<&$from_type> for Hsv {
+      fn from($val: &$from_type) -> Hsv {
+        ($conv)
+      }
+    }
+    impl From<$from_type> for Hsv {
+      fn from($val: $from_type) -> Hsv {
+        Hsv::from(&$val)
+      }
+    }
+  };
+}
+
+macro_rules! from_for_hsv_all {
+  ($t: ty) => {
+    from_for_hsv!(($t, $t, $t), v, {
+      let (h, s, v) = *v;
+      Hsv::new(h as f64, s as f64, v as f64, None)
+    });
+    from_for_hsv!([$t; 3], v, {
+      let [h, s, v] = *v;
+      Hsv::new(h as f64, s as f64, v as f64, None)
+    });
+  };
+}
+
+macro_rules! from_for_hsv_all_with_alpha {
+  ($t: ty) => {
+    from_for_hsv!(($t, $t, $t, $t), v, {
+      let (h, s, v, a) = *v;
+      Hsv::new(h as f64, s as f64, v as f64, Some(a as f64))
+    });
+    from_for_hsv!([$t; 4], v, {
+      let [h, s, v, a] = *v;
+      Hsv::new(h as f64, s as f64, v as f64, Some(a as f64))
+    });
+  };
+}
+
+from_for_hsv_all!(f32);
+from_for_hsv_all!(f64);
+from_for_hsv_all_with_alpha!(f32);
+from_for_hsv_all_with_alpha!(f64);
+from_for_hsv_all!(i16);
+from_for_hsv_all!(i32);
+from_for_hsv_all!(i64);
+from_for_hsv_all!(u16);
+from_for_hsv_all!(u32);
+from_for_hsv_all!(u64);
+
+fn from_rgb(rgb: &Rgb) -> Hsv {
+  let a = rgb.alpha();
+  let mut hsv = Hsv::from_units(rgb_to_hsv(rgb));
+  hsv.set_alpha(a);
+  hsv
+}
+
+impl From<&Rgb> for Hsv {
+  /// # Example
+  /// ```
+  /// use colorsys::{Rgb,Hsv};
+  /// let rgb = Rgb::from(&(215.0, 231.0, 236.0));
+  /// let hsv = Hsv::from(&rgb);
+  /// assert_eq!(hsv.to_css_string(), "hsv(194,9%,93%)");
+  /// ```
+  fn from(rgb: &Rgb) -> Self {
+    from_rgb(rgb)
+  }
+}
+
+impl From<&mut Rgb> for Hsv {
+  fn from(rgb: &mut Rgb) -> Self {
+    from_rgb(rgb)
+  }
+}
+
+impl From<Rgb> for Hsv {
+  fn from(rgb: Rgb) -> Self {
+    from_rgb(&rgb)
+  }
+}
+
+fn from_hsl(hsl: &Hsl) -> Hsv {
+  let a = hsl.alpha();
+  let mut hsv = Hsv::from_units(hsl_to_hsv(hsl));
+  hsv.set_alpha(a);
+  hsv
+}
+
+impl From<&Hsl> for Hsv {
+  /// # Example
+  /// ```
+  /// use colorsys::{Hsl,Hsv};
+  /// let hsl = Hsl::from(&(332.0, 100.0, 42.0));
+  /// let hsv = Hsv::from(&hsl);
+  /// assert_eq!(hsv.to_css_string(), "hsv(332,100%,84%)");
+  /// ```
+  fn from(hsl: &Hsl) -> Self {
+    from_hsl(hsl)
+  }
+}
+
+impl From<&mut Hsl> for Hsv {
+  fn from(hsl: &mut Hsl) -> Self {
+    from_hsl(hsl)
+  }
+}
+
+impl From<Hsl> for Hsv {
+  fn from(hsl: Hsl) -> Self {
+    from_hsl(&hsl)
+  }
+}
+
+fn from_hwb(hwb: &Hwb) -> Hsv {
+  let a = hwb.alpha();
+  let mut hsv = Hsv::from_units(hwb_to_hsv(hwb));
+  hsv.set_alpha(a);
+  hsv
+}
+
+impl From<&Hwb> for Hsv {
+  fn from(hwb: &Hwb) -> Self {
+    from_hwb(hwb)
+  }
+}
+
+impl From<&mut Hwb> for Hsv {
+  fn from(hwb: &mut Hwb) -> Self {
+    from_hwb(hwb)
+  }
+}
+
+impl From<Hwb> for Hsv {
+  fn from(hwb: Hwb) -> Self {
+    from_hwb(&hwb)
+  }
+}
+
+fn from_hsv_ratio(ratio: &HsvRatio) -> Hsv {
+  let ru = &ratio.units;
+  let t = ratio_to_hsva(&(ru[0], ru[1], ru[2], ru.alpha.get_f64()));
+  Hsv::new(t.0, t.1, t.2, Some(t.3))
+}
+
+impl From<&HsvRatio> for Hsv {
+  fn from(r: &HsvRatio) -> Self {
+    from_hsv_ratio(r)
+  }
+}
+
+impl From<&mut HsvRatio> for Hsv {
+  fn from(r: &mut HsvRatio) -> Self {
+    from_hsv_ratio(r)
+  }
+}
+
+impl From<HsvRatio> for Hsv {
+  fn from(r: HsvRatio) -> Self {
+    from_hsv_ratio(&r)
+  }
+}
+
+//
+//
+//
+// INTO
+//
+
+into_for_some!(HsvRatio, Hsv, self, { self.as_ratio() });
+
+macro_rules! into_for_hsv_all {
+  ($t: ty) => {
+    into_for_some!(($t, $t, $t), Hsv, self, {
+      let u = &self.units;
+      (u[0] as $t, u[1] as $t, u[2] as $t)
+    });
+    into_for_some!([$t; 3], Hsv, self, {
+      let u = &self.units;
+      [u[0] as $t, u[1] as $t, u[2] as $t]
+    });
+  };
+}
+
+macro_rules! into_for_hsv_all_with_alpha {
+  ($t: ty) => {
+    into_for_some!(($t, $t, $t, $t), Hsv, self, {
+      let u = &self.units;
+      (u[0] as $t, u[1] as $t, u[2] as $t, self.units.alpha.get_f64() as $t)
+    });
+    into_for_some!([$t; 4], Hsv, self, {
+      let u = &self.units;
+      [u[0] as $t, u[1] as $t, u[2] as $t, self.units.alpha.get_f64() as $t]
+    });
+  };
+}
+
+into_for_hsv_all!(f32);
+into_for_hsv_all!(f64);
+into_for_hsv_all_with_alpha!(f32);
+into_for_hsv_all_with_alpha!(f64);
+into_for_hsv_all!(i16);
+into_for_hsv_all!(i32);
+into_for_hsv_all!(i64);
+into_for_hsv_all!(u16);
+into_for_hsv_all!(u32);
+into_for_hsv_all!(u64);
@@ -0,0 +1,138 @@
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+pub use ratio::HsvRatio;
+
+use crate::{ColorAlpha, ColorTupleA, ColorUnitsIter, ParseError};
+use crate::common::{Hs, hsl_hsv_from_str, tuple_to_string};
+use crate::units::{Alpha, GetColorUnits, Unit, Units};
+
+#[cfg(test)]
+mod tests;
+
+mod from;
+mod ops;
+mod ratio;
+mod transform;
+
+/// The HSV or HSB (hue, saturation, value (brightness)) color model
+///
+/// Ranges:
+/// * hue: 0.0 - 360.0
+/// * saturation: 0.0 - 100.0
+/// * value: 0.0 - 100.0
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{Hsv, Hsl, Rgb, prelude::*};
+///
+/// let mut hsv = Hsv::new(210.0, 50.0, 80.0, None);
+/// hsv.set_value(100.0);
+///
+/// let rgb = Rgb::from(&hsv);
+/// assert_eq!(rgb.to_css_string(), "rgb(128,191,255)");
+///
+/// let hsl: Hsl = hsv.as_ref().into();
+/// assert_eq!(hsl.to_css_string(), "hsl(210,100%,75%)");
+///
+/// hsv.saturate(SaturationInSpace::Hsv(-20.0));
+/// assert_eq!(hsv.to_css_string(), "hsv(210,30%,100%)");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hsv {
+  pub(crate) units: Units,
+}
+
+iter_def!(Hsv);
+pub(crate) fn new_hsv_units(h: f64, s: f64, v: f64) -> Units {
+  let ul = [Unit::new_hue(h), Unit::new_percent(s), Unit::new_percent(v), Unit::default()];
+  Units { len: 3, list: ul, alpha: Alpha::default() }
+}
+
+
+impl Hsv {
+  pub fn new(h: f64, s: f64, v: f64, a: Option<f64>) -> Hsv {
+    let mut units = new_hsv_units(h, s, v);
+    units.alpha.set_opt(a);
+    units.restrict();
+    Hsv { units }
+  }
+
+  pub(crate) fn from_units(u: Units) -> Self { Hsv { units: u } }
+
+  /// Returns a String in the `hsv(..)`/`hsva(..)` notation.
+  /// Note that it is not supported by CSS, but can be parsed back with `FromStr`.
+  pub fn to_css_string(&self) -> String {
+    let t: ColorTupleA = self.into();
+    tuple_to_string(&t, "hsv")
+  }
+
+  pub fn hue(&self) -> f64 { self.units[0] }
+  pub fn saturation(&self) -> f64 { self.units[1] }
+  pub fn value(&self) -> f64 { self.units[2] }
+
+  pub fn set_hue(&mut self, val: f64) { self.units.list[0].set(val); }
+  pub fn set_saturation(&mut self, val: f64) { self.units.list[1].set(val); }
+  pub fn set_value(&mut self, val: f64) { self.units.list[2].set(val); }
+
+  /// Returns an iterator over three color units and the possibly alpha value.
+  pub fn iter(&self) -> ColorUnitsIter {
+    ColorUnitsIter::from_units(&self.units)
+  }
+
+  /// Returns an HSV representation with values converted to floar from 0.0 to 1.0
+  pub fn as_ratio(&self) -> HsvRatio {
+    HsvRatio::from_units(self.units.as_ratio())
+  }
+}
+
+//
+//
+//
+// Default
+//
+impl Default for Hsv {
+  fn default() -> Hsv {
+    Hsv::from_units(new_hsv_units(0.0, 0.0, 0.0))
+  }
+}
+
+//
+//
+//
+// AsRef<Hsv>
+//
+impl AsRef<Hsv> for Hsv {
+  fn as_ref(&self) -> &Hsv {
+    self
+  }
+}
+
+//
+//
+//
+// FromStr
+//
+impl core::str::FromStr for Hsv {
+  type Err = ParseError;
+  fn from_str(s: &str) -> Result<Hsv, ParseError> {
+    let (tuple, alpha) = hsl_hsv_from_str(s, Hs::Hsv)?;
+    let mut hsv = Hsv::from(&tuple);
+    if let Some(a) = alpha {
+      hsv.set_alpha(a);
+    }
+    Ok(hsv)
+  }
+}
+
+
+impl GetColorUnits for Hsv {
+  fn get_units(&self) -> &Units {
+    &self.units
+  }
+  fn get_units_mut(&mut self) -> &mut Units {
+    &mut self.units
+  }
+}
@@ -0,0 +1,80 @@
+use crate::{
+  ApproxEq, ColorTransform, ColorTuple, ColorTupleA, Hsl, Hsv, ParseError,
+  Rgb, SaturationInSpace,
+};
+use crate::common::f64_round;
+
+fn round(n: f64) -> u32 {
+  f64_round(n) as u32
+}
+
+fn round_tuple(t: &ColorTuple) -> (u32, u32, u32) {
+  let (x, y, z) = *t;
+  (round(x), round(y), round(z))
+}
+
+#[test]
+fn hsv_to_rgb() {
+  let hsv = Hsv::from((126.0, 57.0, 73.0));
+  let rgb = Rgb::from(&hsv);
+  assert_eq!(round_tuple(&rgb.as_ref().into()), (80, 186, 91));
+
+  let hsv_new = Hsv::from(&rgb);
+  assert!(hsv_new.approx_eq(&hsv));
+}
+
+#[test]
+fn hsv_to_hsl() {
+  let hsv = Hsv::new(210.0, 30.0, 90.0, Some(0.4));
+  let hsl = Hsl::from(&hsv);
+  let hsv_new = Hsv::from(&hsl);
+  assert!(hsv_new.approx_eq(&hsv));
+  assert!(hsv.approx_eq_clarify(&Rgb::from(&hsl), 0.000_001));
+}
+
+#[test]
+fn from_str_tst() {
+  fn parse_hsv(s: &str) -> Result<Hsv, ParseError> {
+    s.parse::<Hsv>()
+  }
+
+  assert_eq!(
+    Into::<ColorTuple>::into(parse_hsv("hsv(134, 11%, 51%)").unwrap()),
+    (134.0, 11.0, 51.0)
+  );
+  assert_eq!(
+    Into::<ColorTupleA>::into(parse_hsv("hsva(134,11,51,0.3)").unwrap()),
+    (134.0, 11.0, 51.0, 0.3)
+  );
+  assert!(parse_hsv("hsl(134,11%,51%)").is_err());
+  assert!(parse_hsv("hsv(361,11%,51%)").is_err());
+
+  let hsv = Hsv::new(14.4, 99.5, 22.1, Some(0.5));
+  assert_eq!(hsv.to_css_string(), "hsva(14,100%,22%,0.5)");
+}
+
+#[test]
+fn saturate_in_hsv() {
+  let mut hsv = Hsv::from((40.0, 50.0, 50.0));
+  hsv.saturate(SaturationInSpace::Hsv(20.0));
+  assert_eq!(round_tuple(&hsv.as_ref().into()), (40, 70, 50));
+
+  let mut hsl = Hsl::from(&hsv);
+  hsl.saturate(SaturationInSpace::Hsv(-70.0));
+  assert_eq!(round_tuple(&hsl.into()), (40, 0, 50));
+
+  let mut rgb = Rgb::from(&hsv);
+  rgb.saturate(SaturationInSpace::Hsv(30.0));
+  assert!(Hsv::from(&rgb).approx_eq(&Hsv::from((40.0, 100.0, 50.0))));
+}
+
+#[test]
+fn hsv_iter() {
+  let hsv = Hsv::new(1.0, 2.0, 3.0, Some(0.5));
+  let mut it = hsv.iter();
+  assert_eq!(it.next(), Some(1.0));
+  assert_eq!(it.next(), Some(2.0));
+  assert_eq!(it.next(), Some(3.0));
+  assert_eq!(it.next(), Some(0.5));
+  assert_eq!(it.next(), None);
+}
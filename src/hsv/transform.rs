@@ -0,0 +1,48 @@
+use crate::{ColorTransform, Hsl, SaturationInSpace};
+use crate::consts::{ALL_MIN, HUE_MAX};
+use crate::normalize::bound_hue;
+
+use super::Hsv;
+
+impl Hsv {
+  fn _apply_hsl(&mut self, hsl: &Hsl) {
+    let hsv = Hsv::from(hsl);
+    self.units.list[1].value = hsv.units[1];
+    self.units.list[2].value = hsv.units[2];
+  }
+}
+
+impl ColorTransform for Hsv {
+  /// Lighten or darken color. Lightness is changed in HSL space, so
+  /// `hsv.lighten(x)` is the same as `hsl.lighten(x)` for the same color.
+  fn lighten(&mut self, amt: f64) {
+    let mut hsl: Hsl = self.as_ref().into();
+    hsl.lighten(amt);
+    self._apply_hsl(&hsl);
+  }
+
+  fn saturate(&mut self, sat: SaturationInSpace) {
+    match sat {
+      SaturationInSpace::Hsv(s) => self.units.list[1].increase(s),
+      SaturationInSpace::Hsl(s) => {
+        let mut hsl: Hsl = self.as_ref().into();
+        hsl.saturate(SaturationInSpace::Hsl(s));
+        self._apply_hsl(&hsl);
+      }
+    }
+  }
+
+  fn adjust_hue(&mut self, hue: f64) {
+    let h = bound_hue(self.units[0] + hue);
+    self.units.list[0].set(h);
+  }
+
+  fn grayscale_simple(&mut self) {
+    self.units.list[0].value = ALL_MIN;
+    self.units.list[1].value = ALL_MIN;
+  }
+  fn invert(&mut self) {
+    let h = (self.units[0] + HUE_MAX * 0.5) % HUE_MAX;
+    self.units.list[0].set(h);
+  }
+}
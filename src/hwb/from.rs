@@ -0,0 +1,202 @@
+use crate::{
+  ColorAlpha, converters::*, ratio_converters::ratio_to_hwba,
+};
+
+use super::{Hwb, HwbRatio};
+use crate::{Hsl, Hsv, Rgb};
+
+macro_rules! from_for_hwb {
+  ($from_type: ty, $val: ident, $conv: block) => {
+    impl From<&$from_type> for Hwb {
+      fn from($val: &$from_type) -> Hwb {
+        ($conv)
+      }
+    }
+    impl From<$from_type> for Hwb {
+      fn from($val: $from_type) -> Hwb {
+        Hwb::from(&$val)
+      }
+    }
+  };
+}
+
+macro_rules! from_for_hwb_all {
+  ($t: ty) => {
+    from_for_hwb!(($t, $t, $t), v, {
+      let (h, w, b) = *v;
+      Hwb::new(h as f64, w as f64, b as f64, None)
+    });
+    from_for_hwb!([$t; 3], v, {
+      let [h, w, b] = *v;
+      Hwb::new(h as f64, w as f64, b as f64, None)
+    });
+  };
+}
+
+macro_rules! from_for_hwb_all_with_alpha {
+  ($t: ty) => {
+    from_for_hwb!(($t, $t, $t, $t), v, {
+      let (h, w, b, a) = *v;
+      Hwb::new(h as f64, w as f64, b as f64, Some(a as f64))
+    });
+    from_for_hwb!([$t; 4], v, {
+      let [h, w, b, a] = *v;
+      Hwb::new(h as f64, w as f64, b as f64, Some(a as f64))
+    });
+  };
+}
+
+from_for_hwb_all!(f32);
+from_for_hwb_all!(f64);
+from_for_hwb_all_with_alpha!(f32);
+from_for_hwb_all_with_alpha!(f64);
+from_for_hwb_all!(i16);
+from_for_hwb_all!(i32);
+from_for_hwb_all!(i64);
+from_for_hwb_all!(u16);
+from_for_hwb_all!(u32);
+from_for_hwb_all!(u64);
+
+fn from_hsv(hsv: &Hsv) -> Hwb {
+  let a = hsv.alpha();
+  let mut hwb = Hwb::from_units(hsv_to_hwb(hsv));
+  hwb.set_alpha(a);
+  hwb
+}
+
+impl From<&Hsv> for Hwb {
+  /// # Example
+  /// ```
+  /// use colorsys::{Hsv,Hwb};
+  /// let hsv = Hsv::from(&(332.0, 50.0, 84.0));
+  /// let hwb = Hwb::from(&hsv);
+  /// assert_eq!(hwb.to_css_string(), "hwb(332 42% 16%)");
+  /// ```
+  fn from(hsv: &Hsv) -> Self {
+    from_hsv(hsv)
+  }
+}
+
+impl From<&mut Hsv> for Hwb {
+  fn from(hsv: &mut Hsv) -> Self {
+    from_hsv(hsv)
+  }
+}
+
+impl From<Hsv> for Hwb {
+  fn from(hsv: Hsv) -> Self {
+    from_hsv(&hsv)
+  }
+}
+
+impl From<&Rgb> for Hwb {
+  /// # Example
+  /// ```
+  /// use colorsys::{Rgb,Hwb};
+  /// let rgb = Rgb::from(&(51.0, 140.25, 229.5));
+  /// let hwb = Hwb::from(&rgb);
+  /// assert_eq!(hwb.to_css_string(), "hwb(210 20% 10%)");
+  /// ```
+  fn from(rgb: &Rgb) -> Self {
+    from_hsv(&Hsv::from(rgb))
+  }
+}
+
+impl From<&mut Rgb> for Hwb {
+  fn from(rgb: &mut Rgb) -> Self {
+    from_hsv(&Hsv::from(rgb))
+  }
+}
+
+impl From<Rgb> for Hwb {
+  fn from(rgb: Rgb) -> Self {
+    from_hsv(&Hsv::from(rgb))
+  }
+}
+
+impl From<&Hsl> for Hwb {
+  fn from(hsl: &Hsl) -> Self {
+    from_hsv(&Hsv::from(hsl))
+  }
+}
+
+impl From<&mut Hsl> for Hwb {
+  fn from(hsl: &mut Hsl) -> Self {
+    from_hsv(&Hsv::from(hsl))
+  }
+}
+
+impl From<Hsl> for Hwb {
+  fn from(hsl: Hsl) -> Self {
+    from_hsv(&Hsv::from(hsl))
+  }
+}
+
+fn from_hwb_ratio(ratio: &HwbRatio) -> Hwb {
+  let ru = &ratio.units;
+  let t = ratio_to_hwba(&(ru[0], ru[1], ru[2], ru.alpha.get_f64()));
+  Hwb::new(t.0, t.1, t.2, Some(t.3))
+}
+
+impl From<&HwbRatio> for Hwb {
+  fn from(r: &HwbRatio) -> Self {
+    from_hwb_ratio(r)
+  }
+}
+
+impl From<&mut HwbRatio> for Hwb {
+  fn from(r: &mut HwbRatio) -> Self {
+    from_hwb_ratio(r)
+  }
+}
+
+impl From<HwbRatio> for Hwb {
+  fn from(r: HwbRatio) -> Self {
+    from_hwb_ratio(&r)
+  }
+}
+
+//
+//
+//
+// INTO
+//
+
+into_for_some!(HwbRatio, Hwb, self, { self.as_ratio() });
+
+macro_rules! into_for_hwb_all {
+  ($t: ty) => {
+    into_for_some!(($t, $t, $t), Hwb, self, {
+      let u = &self.units;
+      (u[0] as $t, u[1] as $t, u[2] as $t)
+    });
+    into_for_some!([$t; 3], Hwb, self, {
+      let u = &self.units;
+      [u[0] as $t, u[1] as $t, u[2] as $t]
+    });
+  };
+}
+
+macro_rules! into_for_hwb_all_with_alpha {
+  ($t: ty) => {
+    into_for_some!(($t, $t, $t, $t), Hwb, self, {
+      let u = &self.units;
+      (u[0] as $t, u[1] as $t, u[2] as $t, self.units.alpha.get_f64() as $t)
+    });
+    into_for_some!([$t; 4], Hwb, self, {
+      let u = &self.units;
+      [u[0] as $t, u[1] as $t, u[2] as $t, self.units.alpha.get_f64() as $t]
+    });
+  };
+}
+
+into_for_hwb_all!(f32);
+into_for_hwb_all!(f64);
+into_for_hwb_all_with_alpha!(f32);
+into_for_hwb_all_with_alpha!(f64);
+into_for_hwb_all!(i16);
+into_for_hwb_all!(i32);
+into_for_hwb_all!(i64);
+into_for_hwb_all!(u16);
+into_for_hwb_all!(u32);
+into_for_hwb_all!(u64);
@@ -0,0 +1,169 @@
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+pub use ratio::HwbRatio;
+
+use crate::{ColorAlpha, ColorUnitsIter, ParseError};
+use crate::common::f64_round;
+use crate::common::css_fn::{css_alpha, css_fn_from_str, css_fn_to_string, css_hue, css_number};
+use crate::consts::{ALL_MIN, PERCENT_MAX};
+use crate::err::make_parse_err;
+use crate::units::{Alpha, GetColorUnits, Unit, Units};
+
+#[cfg(test)]
+mod tests;
+
+mod from;
+mod ops;
+mod ratio;
+mod transform;
+
+/// The HWB (hue, whiteness, blackness) color model
+///
+/// Ranges:
+/// * hue: 0.0 - 360.0
+/// * whiteness: 0.0 - 100.0
+/// * blackness: 0.0 - 100.0
+/// * alpha: 0.0 - 1.0
+///
+/// If the sum of whiteness and blackness is greater than 100.0, the color is
+/// a shade of gray (as CSS Color Module Level 4 specifies): whiteness and blackness
+/// are normalized on conversion to another color model, while stored values stay untouched.
+///
+/// # Example
+/// ```
+/// use colorsys::{Hwb, Rgb, prelude::*};
+///
+/// let hwb: Hwb = "hwb(210 20% 10%)".parse().unwrap();
+/// let rgb = Rgb::from(&hwb);
+/// assert_eq!(rgb.to_css_string(), "rgb(51,140,230)");
+///
+/// let mut gray = Hwb::new(210.0, 80.0, 80.0, Some(0.5));
+/// assert_eq!(Rgb::from(&gray).to_css_string(), "rgba(128,128,128,0.5)");
+///
+/// gray.set_blackness(0.0);
+/// assert_eq!(gray.to_css_string(), "hwb(210 80% 0% / 0.5)");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hwb {
+  pub(crate) units: Units,
+}
+
+iter_def!(Hwb);
+pub(crate) fn new_hwb_units(h: f64, w: f64, b: f64) -> Units {
+  let ul = [Unit::new_hue(h), Unit::new_percent(w), Unit::new_percent(b), Unit::default()];
+  Units { len: 3, list: ul, alpha: Alpha::default() }
+}
+
+
+impl Hwb {
+  pub fn new(h: f64, w: f64, b: f64, a: Option<f64>) -> Hwb {
+    let mut units = new_hwb_units(h, w, b);
+    units.alpha.set_opt(a);
+    units.restrict();
+    Hwb { units }
+  }
+
+  pub(crate) fn from_units(u: Units) -> Self { Hwb { units: u } }
+
+  /// Returns a String that can be used in CSS.
+  /// # Example
+  /// ```
+  /// use colorsys::Hwb;
+  ///
+  /// let hwb = Hwb::new(194.2, 0.0, 12.7, Some(0.75));
+  /// assert_eq!(hwb.to_css_string(), "hwb(194 0% 13% / 0.75)");
+  /// ```
+  pub fn to_css_string(&self) -> String {
+    let u = &self.units;
+    let components = [
+      format!("{}", f64_round(u[0])),
+      format!("{}%", f64_round(u[1])),
+      format!("{}%", f64_round(u[2])),
+    ];
+    css_fn_to_string("hwb", &components, u.alpha.get_f64())
+  }
+
+  pub fn hue(&self) -> f64 { self.units[0] }
+  pub fn whiteness(&self) -> f64 { self.units[1] }
+  pub fn blackness(&self) -> f64 { self.units[2] }
+
+  pub fn set_hue(&mut self, val: f64) { self.units.list[0].set(val); }
+  pub fn set_whiteness(&mut self, val: f64) { self.units.list[1].set(val); }
+  pub fn set_blackness(&mut self, val: f64) { self.units.list[2].set(val); }
+
+  /// Returns an iterator over three color units and the possibly alpha value.
+  pub fn iter(&self) -> ColorUnitsIter {
+    ColorUnitsIter::from_units(&self.units)
+  }
+
+  /// Returns an HWB representation with values converted to floar from 0.0 to 1.0
+  pub fn as_ratio(&self) -> HwbRatio {
+    HwbRatio::from_units(self.units.as_ratio())
+  }
+}
+
+//
+//
+//
+// Default
+//
+impl Default for Hwb {
+  fn default() -> Hwb {
+    Hwb::from_units(new_hwb_units(0.0, 0.0, PERCENT_MAX))
+  }
+}
+
+//
+//
+//
+// AsRef<Hwb>
+//
+impl AsRef<Hwb> for Hwb {
+  fn as_ref(&self) -> &Hwb {
+    self
+  }
+}
+
+//
+//
+//
+// FromStr
+//
+impl core::str::FromStr for Hwb {
+  type Err = ParseError;
+  /// Parses `hwb(..)` string. Hue can be specified with angle units (`deg`, `rad`, etc.),
+  /// whiteness and blackness as percentages or numbers from `0` to `100`.
+  fn from_str(s: &str) -> Result<Hwb, ParseError> {
+    let make_err = || make_parse_err(s, "hwb");
+    let (components, alpha) =
+      css_fn_from_str(s, &["hwb", "hwba"], 3).ok_or_else(make_err)?;
+
+    let h = css_hue(&components[0]).ok_or_else(make_err)?;
+    let mut wb = [ALL_MIN; 2];
+    for (ind, c) in components[1..].iter().enumerate() {
+      let n = css_number(c, PERCENT_MAX).ok_or_else(make_err)?;
+      if !(ALL_MIN..=PERCENT_MAX).contains(&n) {
+        return Err(make_err());
+      }
+      wb[ind] = n;
+    }
+
+    let mut hwb = Hwb::new(h, wb[0], wb[1], None);
+    if let Some(a) = alpha {
+      hwb.set_alpha(css_alpha(&a).ok_or_else(make_err)?);
+    }
+    Ok(hwb)
+  }
+}
+
+
+impl GetColorUnits for Hwb {
+  fn get_units(&self) -> &Units {
+    &self.units
+  }
+  fn get_units_mut(&mut self) -> &mut Units {
+    &mut self.units
+  }
+}
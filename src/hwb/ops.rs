@@ -0,0 +1,27 @@
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+
+use crate::{ApproxEq, DEFAULT_APPROX_EQ_PRECISION, Hsl, Rgb};
+
+use super::Hwb;
+
+ops_def!(Hwb);
+
+impl ApproxEq<Rgb> for Hwb {
+  fn approx_eq(&self, rgb: &Rgb) -> bool {
+    self.approx_eq_clarify(rgb, DEFAULT_APPROX_EQ_PRECISION)
+  }
+  fn approx_eq_clarify(&self, rgb: &Rgb, precision: f64) -> bool {
+    let hwb_from_rgb: Hwb = rgb.into();
+    self.units.approx_eq_clarify(&hwb_from_rgb.units, precision)
+  }
+}
+
+impl ApproxEq<Hsl> for Hwb {
+  fn approx_eq(&self, hsl: &Hsl) -> bool {
+    self.approx_eq_clarify(hsl, DEFAULT_APPROX_EQ_PRECISION)
+  }
+  fn approx_eq_clarify(&self, hsl: &Hsl, precision: f64) -> bool {
+    let hwb_from_hsl: Hwb = hsl.into();
+    self.units.approx_eq_clarify(&hwb_from_hsl.units, precision)
+  }
+}
@@ -0,0 +1,122 @@
+use crate::units::{Units, GetColorUnits};
+
+///
+/// Hwb representation as ratio (from `0.0` to `1.0`).
+/// Cannot be modified, added, subtracted, etc. Can be converted to `Hwb` and vice versa.
+/// Used for compatibility in various libraries.
+///
+/// # Example
+/// ```rust
+/// use colorsys::{ApproxEq, Hwb, HwbRatio};
+///
+/// let origin = Hwb::from((126.0, 31.0, 27.0));
+///
+/// let ratio_f32: [f32; 3] = origin.as_ratio().into();
+/// let ratio_f64: [f64; 4] = origin.as_ratio().into();
+/// // ~[0.35, 0.31, 0.27, 1.0]
+///
+/// let converted_f32: Hwb = HwbRatio::from(&ratio_f32).into();
+/// let converted_f64: Hwb = HwbRatio::from(&ratio_f64).into();
+///
+/// assert!(origin.approx_eq_clarify(&converted_f32, 0.0001));
+/// assert!(origin.approx_eq(&converted_f64));
+/// ```
+///
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HwbRatio {
+  pub(super) units: Units,
+}
+
+impl HwbRatio {
+  pub fn new(h: f64, w: f64, b: f64, a: f64) -> Self {
+    let mut units = Units::new_ratios(&[h, w, b]);
+    units.alpha.set(a);
+    HwbRatio::from_units(units)
+  }
+
+  pub fn h(&self) -> f64 { self.units[0] }
+  pub fn w(&self) -> f64 { self.units[1] }
+  pub fn b(&self) -> f64 { self.units[2] }
+  pub fn a(&self) -> f64 { self.units.alpha.get_f64() }
+
+  pub(crate) fn from_units(u: Units) -> Self { HwbRatio { units: u } }
+}
+
+impl AsRef<HwbRatio> for HwbRatio {
+  fn as_ref(&self) -> &HwbRatio {
+    self
+  }
+}
+
+impl GetColorUnits for HwbRatio {
+  fn get_units(&self) -> &Units {
+    &self.units
+  }
+  fn get_units_mut(&mut self) -> &mut Units {
+    &mut self.units
+  }
+}
+
+macro_rules! from_for_hwb_ratio {
+  ($from_type: ty, $val: ident, $conv: block) => {
+    impl From<&$from_type> for HwbRatio {
+      fn from($val: &$from_type) -> HwbRatio {
+        ($conv)
+      }
+    }
+    impl From<$from_type> for HwbRatio {
+      fn from($val: $from_type) -> HwbRatio {
+        HwbRatio::from(&$val)
+      }
+    }
+  };
+}
+
+macro_rules! from_for_hwb_ratio_all {
+  ($t: ty) => {
+    from_for_hwb_ratio!(($t, $t, $t), v, {
+      let (h, w, b) = *v;
+      HwbRatio::new(h as f64, w as f64, b as f64, 1.0)
+    });
+    from_for_hwb_ratio!(($t, $t, $t, $t), v, {
+      let (h, w, b, a) = *v;
+      HwbRatio::new(h as f64, w as f64, b as f64, a as f64)
+    });
+    from_for_hwb_ratio!([$t; 3], v, {
+      let [h, w, b] = *v;
+      HwbRatio::new(h as f64, w as f64, b as f64, 1.0)
+    });
+    from_for_hwb_ratio!([$t; 4], v, {
+      let [h, w, b, a] = *v;
+      HwbRatio::new(h as f64, w as f64, b as f64, a as f64)
+    });
+  };
+}
+
+from_for_hwb_ratio_all!(f32);
+from_for_hwb_ratio_all!(f64);
+
+macro_rules! into_for_hwb_ratio_all {
+  ($t: ty) => {
+    into_for_some!(($t, $t, $t), HwbRatio, self, {
+      let u = &self.get_units();
+      (u[0] as $t, u[1] as $t, u[2] as $t)
+    });
+    into_for_some!(($t, $t, $t, $t), HwbRatio, self, {
+      let u = &self.get_units();
+      (u[0] as $t, u[1] as $t, u[2] as $t, u.alpha.get_f64() as $t)
+    });
+    into_for_some!([$t; 3], HwbRatio, self, {
+      let u = &self.get_units();
+      [u[0] as $t, u[1] as $t, u[2] as $t]
+    });
+    into_for_some!([$t; 4], HwbRatio, self, {
+      let u = &self.get_units();
+      [u[0] as $t, u[1] as $t, u[2] as $t, u.alpha.get_f64() as $t]
+    });
+  };
+}
+
+into_for_hwb_ratio_all!(f32);
+into_for_hwb_ratio_all!(f64);
@@ -0,0 +1,81 @@
+use crate::{
+  ApproxEq, ColorTransform, ColorTuple, ColorTupleA, Hsl, Hwb, ParseError,
+  Rgb, SaturationInSpace,
+};
+use crate::common::f64_round;
+
+fn round(n: f64) -> u32 {
+  f64_round(n) as u32
+}
+
+fn round_tuple(t: &ColorTuple) -> (u32, u32, u32) {
+  let (x, y, z) = *t;
+  (round(x), round(y), round(z))
+}
+
+#[test]
+fn hwb_to_rgb() {
+  let hwb = Hwb::from((126.0, 31.0, 27.0));
+  let rgb = Rgb::from(&hwb);
+  assert_eq!(round_tuple(&rgb.as_ref().into()), (79, 186, 90));
+
+  let hwb_new = Hwb::from(&rgb);
+  assert!(hwb_new.approx_eq(&hwb));
+
+  let hsl = Hsl::from(&hwb);
+  assert!(Hwb::from(&hsl).approx_eq(&hwb));
+}
+
+#[test]
+fn hwb_normalized_to_gray() {
+  let hwb = Hwb::new(126.0, 70.0, 50.0, Some(0.2));
+  let rgb = Rgb::from(&hwb);
+  assert_eq!(Into::<ColorTupleA>::into(rgb), (148.75, 148.75, 148.75, 0.2));
+  assert_eq!(hwb.whiteness(), 70.0);
+  assert_eq!(hwb.blackness(), 50.0);
+}
+
+#[test]
+fn from_str_tst() {
+  fn parse_hwb(s: &str) -> Result<Hwb, ParseError> {
+    s.parse::<Hwb>()
+  }
+
+  assert_eq!(
+    Into::<ColorTuple>::into(parse_hwb("hwb(134 11% 51%)").unwrap()),
+    (134.0, 11.0, 51.0)
+  );
+  assert_eq!(
+    Into::<ColorTupleA>::into(parse_hwb("HWB(0.25turn 11 51 / 30%)").unwrap()),
+    (90.0, 11.0, 51.0, 0.3)
+  );
+  assert_eq!(
+    Into::<ColorTupleA>::into(parse_hwb("hwb(none none 100%)").unwrap()),
+    (0.0, 0.0, 100.0, 1.0)
+  );
+  assert!(parse_hwb("hwb(134 11% 151%)").is_err());
+  assert!(parse_hwb("hwb(134 11% 51% / 2)").is_err());
+  assert!(parse_hwb("hwb(134 11%)").is_err());
+  assert!(parse_hwb("hsl(134 11% 51%)").is_err());
+
+  let hwb = Hwb::new(14.4, 99.5, 0.0, Some(0.5));
+  assert_eq!(hwb.to_css_string(), "hwb(14 100% 0% / 0.5)");
+  assert!(parse_hwb(&hwb.to_css_string()).is_ok());
+}
+
+#[test]
+fn hwb_transform() {
+  let mut hwb = Hwb::from((40.0, 20.0, 30.0));
+  let mut hsl = Hsl::from(&hwb);
+
+  hwb.lighten(10.0);
+  hsl.lighten(10.0);
+  assert!(hwb.approx_eq(&Hwb::from(&hsl)));
+
+  hwb.saturate(SaturationInSpace::Hsl(-15.0));
+  hsl.saturate(SaturationInSpace::Hsl(-15.0));
+  assert!(hwb.approx_eq(&Hwb::from(&hsl)));
+
+  hwb.grayscale_simple();
+  assert_eq!(round_tuple(&hwb.into()), (0, 73, 27));
+}
@@ -0,0 +1,40 @@
+use crate::{ColorTransform, Hsv, SaturationInSpace};
+use crate::consts::HUE_MAX;
+use crate::normalize::bound_hue;
+
+use super::Hwb;
+
+impl Hwb {
+  fn _transform_as_hsv<F: FnOnce(&mut Hsv)>(&mut self, f: F) {
+    let mut hsv: Hsv = self.as_ref().into();
+    f(&mut hsv);
+    let hwb = Hwb::from(hsv);
+    self.units.list[1].value = hwb.units[1];
+    self.units.list[2].value = hwb.units[2];
+  }
+}
+
+impl ColorTransform for Hwb {
+  fn lighten(&mut self, amt: f64) {
+    self._transform_as_hsv(|hsv| hsv.lighten(amt));
+  }
+
+  fn saturate(&mut self, sat: SaturationInSpace) {
+    self._transform_as_hsv(|hsv| hsv.saturate(sat));
+  }
+
+  fn adjust_hue(&mut self, hue: f64) {
+    let h = bound_hue(self.units[0] + hue);
+    self.units.list[0].set(h);
+  }
+
+  fn grayscale_simple(&mut self) {
+    self.units.list[0].value = 0.0;
+    self._transform_as_hsv(|hsv| hsv.grayscale_simple());
+  }
+
+  fn invert(&mut self) {
+    let h = (self.units[0] + HUE_MAX * 0.5) % HUE_MAX;
+    self.units.list[0].set(h);
+  }
+}
@@ -0,0 +1,54 @@
+use crate::{ColorTuple, Rec2100Hlg, Rec2100Pq, Rgb, WhitePoint, Xyz};
+use crate::converters::{ictcp_to_linear, linear_to_ictcp, linear_to_xyz, xyz_to_linear, REC2020_PRIMARIES};
+use crate::units::Alpha;
+use crate::xyz::new_xyz_units;
+
+use super::{new_ictcp_units, ICtCp, ICtCpTransfer};
+
+tuples_def!(ICtCp, f32);
+tuples_def!(ICtCp, f64);
+
+// takes linear Rec.2020 values relative to HDR reference white
+pub(super) fn from_linear(lin: &ColorTuple, alpha: &Alpha, transfer: ICtCpTransfer) -> ICtCp {
+  let (i, ct, cp) = linear_to_ictcp(lin, &transfer);
+  let mut units = new_ictcp_units(i, ct, cp);
+  units.alpha = alpha.clone();
+  units.restrict();
+  ICtCp::from_units(units, transfer)
+}
+
+pub(super) fn to_linear(ictcp: &ICtCp) -> ColorTuple {
+  ictcp_to_linear(&ictcp.into(), &ictcp.transfer)
+}
+
+impl ICtCp {
+  /// Creates color from XYZ values encoded with given transfer function
+  pub fn from_xyz_with_transfer(xyz: &Xyz, transfer: ICtCpTransfer) -> ICtCp {
+    let d65 = xyz.adapt(WhitePoint::D65);
+    let lin = xyz_to_linear(&(&d65).into(), &REC2020_PRIMARIES, &WhitePoint::D65);
+    from_linear(&lin, &xyz.units.alpha, transfer)
+  }
+}
+
+from_for_some!(Xyz, ICtCp, xyz, { ICtCp::from_xyz_with_transfer(xyz, ICtCpTransfer::default()) });
+from_for_some!(ICtCp, Xyz, ictcp, {
+  let (x, y, z) = linear_to_xyz(&to_linear(ictcp), &REC2020_PRIMARIES, &WhitePoint::D65);
+  let mut units = new_xyz_units(x, y, z);
+  units.alpha = ictcp.units.alpha.clone();
+  Xyz::from_units(units, WhitePoint::D65)
+});
+
+from_for_some!(Rgb, ICtCp, rgb, { ICtCp::from(Xyz::from(rgb)) });
+from_for_some!(ICtCp, Rgb, ictcp, { Xyz::from(ictcp).into() });
+
+from_for_some!(Rec2100Pq, ICtCp, pq, { from_linear(&pq.to_linear(), &pq.units.alpha, ICtCpTransfer::Pq) });
+from_for_some!(ICtCp, Rec2100Pq, ictcp, {
+  let (r, g, b) = to_linear(ictcp);
+  Rec2100Pq::from_linear(r, g, b, ictcp.units.alpha.get())
+});
+
+from_for_some!(Rec2100Hlg, ICtCp, hlg, { from_linear(&hlg.to_linear(), &hlg.units.alpha, ICtCpTransfer::Hlg) });
+from_for_some!(ICtCp, Rec2100Hlg, ictcp, {
+  let (r, g, b) = to_linear(ictcp);
+  Rec2100Hlg::from_linear(r, g, b, ictcp.units.alpha.get())
+});
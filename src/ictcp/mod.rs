@@ -0,0 +1,168 @@
+use crate::ColorUnitsIter;
+use crate::common::f64_sqrt;
+use crate::units::{Alpha, GetColorUnits, Unit, Units};
+
+#[cfg(test)]
+mod tests;
+
+mod from;
+
+/// Transfer function which `ICtCp` values are encoded with.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ICtCpTransfer {
+  /// Perceptual quantizer (SMPTE ST 2084)
+  #[default]
+  Pq,
+  /// Hybrid Log-Gamma
+  Hlg,
+}
+
+// scale of ΔE ITP, `1.0` is about a just noticeable difference (ITU-R BT.2124)
+const DELTA_E_ITP_SCALE: f64 = 720.0;
+
+/// The ICtCp color representation of ITU-R BT.2100.
+///
+/// Intensity `i` and blue-yellow and red-green chroma `ct`, `cp` are derived from
+/// Rec.2100 RGB encoded with PQ or HLG transfer function (see `ICtCpTransfer`).
+/// As for `Rec2100Pq` and `Rec2100Hlg`, white of `Rgb` is HDR reference white.
+/// Conversions from `Xyz` and `Rgb` use PQ, from `Rec2100Hlg` - HLG, for others
+/// see `new_with_transfer`, `from_xyz_with_transfer` and `to_transfer`.
+///
+/// Ranges:
+/// * i: 0.0 - 1.0
+/// * ct: not restricted, usually -0.5 - 0.5
+/// * cp: not restricted, usually -0.5 - 0.5
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{ApproxEq, ICtCp, ICtCpTransfer, Rec2100Hlg, Rgb};
+///
+/// let white = ICtCp::from(Rgb::from([255, 255, 255]));
+/// assert_eq!((white.i() * 1000.0).round(), 581.0);
+/// assert!(white.ct().abs() < 1e-9 && white.cp().abs() < 1e-9);
+///
+/// let hlg = ICtCp::from(Rec2100Hlg::new(0.75, 0.75, 0.75, None));
+/// assert_eq!(hlg.transfer(), ICtCpTransfer::Hlg);
+/// assert!(hlg.to_transfer(ICtCpTransfer::Pq).approx_eq(&white));
+///
+/// // about one just noticeable difference
+/// let gray = ICtCp::from(Rgb::from([128, 128, 128]));
+/// let lighter = ICtCp::from(Rgb::from([129, 129, 129]));
+/// assert_eq!(gray.delta_e_itp(&lighter).round(), 1.0);
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ICtCp {
+  pub(crate) units: Units,
+  pub(crate) transfer: ICtCpTransfer,
+}
+
+iter_def!(ICtCp);
+
+pub(crate) fn new_ictcp_units(i: f64, ct: f64, cp: f64) -> Units {
+  let ul = [
+    Unit::new_ratio(i),
+    Unit::new_unbounded(ct),
+    Unit::new_unbounded(cp),
+    Unit::default(),
+  ];
+  Units { len: 3, list: ul, alpha: Alpha::default() }
+}
+
+impl ICtCp {
+  /// Creates color encoded with PQ transfer function
+  pub fn new(i: f64, ct: f64, cp: f64, a: Option<f64>) -> ICtCp {
+    ICtCp::new_with_transfer(i, ct, cp, a, ICtCpTransfer::default())
+  }
+
+  pub fn new_with_transfer(
+    i: f64,
+    ct: f64,
+    cp: f64,
+    a: Option<f64>,
+    transfer: ICtCpTransfer,
+  ) -> ICtCp {
+    let mut units = new_ictcp_units(i, ct, cp);
+    units.alpha.set_opt(a);
+    units.restrict();
+    ICtCp { units, transfer }
+  }
+
+  pub(crate) fn from_units(units: Units, transfer: ICtCpTransfer) -> Self {
+    ICtCp { units, transfer }
+  }
+
+  pub fn i(&self) -> f64 { self.units[0] }
+  pub fn ct(&self) -> f64 { self.units[1] }
+  pub fn cp(&self) -> f64 { self.units[2] }
+
+  pub fn set_i(&mut self, val: f64) { self.units.list[0].set(val); }
+  pub fn set_ct(&mut self, val: f64) { self.units.list[1].set(val); }
+  pub fn set_cp(&mut self, val: f64) { self.units.list[2].set(val); }
+
+  /// Returns the transfer function which values are encoded with
+  pub fn transfer(&self) -> ICtCpTransfer {
+    self.transfer
+  }
+
+  /// Returns same color encoded with another transfer function
+  pub fn to_transfer(&self, transfer: ICtCpTransfer) -> ICtCp {
+    if transfer == self.transfer {
+      return self.clone();
+    }
+    from::from_linear(&from::to_linear(self), &self.units.alpha, transfer)
+  }
+
+  /// Color difference ΔE ITP (ITU-R BT.2124), `1.0` is about a just noticeable
+  /// difference. Colors are compared in PQ encoding
+  pub fn delta_e_itp(&self, other: &ICtCp) -> f64 {
+    let a = self.to_transfer(ICtCpTransfer::Pq);
+    let b = other.to_transfer(ICtCpTransfer::Pq);
+    let di = a.i() - b.i();
+    let dt = 0.5 * (a.ct() - b.ct());
+    let dp = a.cp() - b.cp();
+    DELTA_E_ITP_SCALE * f64_sqrt(di * di + dt * dt + dp * dp)
+  }
+
+  /// Returns an iterator over three color units and the possibly alpha value.
+  pub fn iter(&self) -> ColorUnitsIter {
+    ColorUnitsIter::from_units(&self.units)
+  }
+}
+
+//
+//
+//
+// Default
+//
+impl Default for ICtCp {
+  fn default() -> ICtCp {
+    ICtCp::new(0.0, 0.0, 0.0, None)
+  }
+}
+
+//
+//
+//
+// AsRef<ICtCp>
+//
+impl AsRef<ICtCp> for ICtCp {
+  fn as_ref(&self) -> &ICtCp {
+    self
+  }
+}
+
+impl GetColorUnits for ICtCp {
+  fn get_units(&self) -> &Units {
+    &self.units
+  }
+  fn get_units_mut(&mut self) -> &mut Units {
+    &mut self.units
+  }
+
+  fn same_space(&self, other: &ICtCp) -> bool {
+    self.transfer == other.transfer
+  }
+}
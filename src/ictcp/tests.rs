@@ -0,0 +1,66 @@
+use crate::{ApproxEq, ColorAlpha, ICtCp, ICtCpTransfer, Rec2100Hlg, Rec2100Pq, Rgb, WhitePoint, Xyz};
+
+#[test]
+fn ictcp_from_rgb() {
+  let asserts = [
+    ([255, 0, 0], (0.427_880_284, -0.115_704_360, 0.278_728_947), (0.418_664_089, -0.056_467_984, 0.320_310_104)),
+    ([0, 255, 0], (0.539_760_280, -0.281_247_919, -0.049_484_507), (0.670_391_466, -0.340_949_573, -0.050_552_932)),
+    ([0, 0, 255], (0.355_983_628, 0.269_334_156, -0.161_371_098), (0.279_047_913, 0.333_745_334, -0.145_100_155)),
+    ([255, 255, 255], (0.580_688_881, 0.0, 0.0), (0.75, 0.0, 0.0)),
+    ([0, 0, 0], (0.0, 0.0, 0.0), (0.0, 0.0, 0.0)),
+  ];
+  for (rgb_arr, pq_t, hlg_t) in asserts.iter() {
+    let rgb = Rgb::from(rgb_arr);
+    let pq = ICtCp::from(&rgb);
+    assert_eq!(pq.transfer(), ICtCpTransfer::Pq);
+    assert!(pq.approx_eq_clarify(&ICtCp::from(pq_t), 1e-6));
+    assert!(Rgb::from(&pq).approx_eq_clarify(&rgb, 0.000_001));
+
+    let hlg = ICtCp::from_xyz_with_transfer(&Xyz::from(&rgb), ICtCpTransfer::Hlg);
+    assert!(hlg.approx_eq_clarify(&ICtCp::new_with_transfer(hlg_t.0, hlg_t.1, hlg_t.2, None, ICtCpTransfer::Hlg), 1e-8));
+    // same values of another transfer are another color
+    assert!(!hlg.approx_eq_clarify(&ICtCp::from(hlg_t), 1e-8));
+    assert!(hlg.to_transfer(ICtCpTransfer::Pq).approx_eq_clarify(&pq, 1e-9));
+    assert!(Rgb::from(&hlg).approx_eq_clarify(&rgb, 0.000_001));
+  }
+}
+
+#[test]
+fn ictcp_rec2100() {
+  let pq = Rec2100Pq::new(0.75, 0.7, 0.6, Some(0.5));
+  let ictcp = ICtCp::from(&pq);
+  assert_eq!(ictcp.transfer(), ICtCpTransfer::Pq);
+  assert_eq!(ictcp.alpha(), 0.5);
+  assert!(Rec2100Pq::from(&ictcp).approx_eq(&pq));
+  assert!(Xyz::from(&ictcp).approx_eq(&Xyz::from(&pq)));
+
+  let hlg = Rec2100Hlg::new(0.2, 0.9, 0.5, None);
+  let ictcp = ICtCp::from(&hlg);
+  assert_eq!(ictcp.transfer(), ICtCpTransfer::Hlg);
+  assert!(Rec2100Hlg::from(&ictcp).approx_eq(&hlg));
+  assert!(Rec2100Hlg::from(ictcp.to_transfer(ICtCpTransfer::Pq)).approx_eq(&hlg));
+
+  let xyz = Xyz::new_with_white(0.3, 0.2, 0.1, None, WhitePoint::D50);
+  assert!(Xyz::from(ICtCp::from(&xyz)).approx_eq(&xyz.adapt(WhitePoint::D65)));
+}
+
+#[test]
+fn ictcp_delta_e_itp() {
+  let gray = ICtCp::from(Rgb::from([128, 128, 128]));
+  assert_eq!(gray.delta_e_itp(&gray), 0.0);
+
+  let asserts = [
+    ([129, 129, 129], 1.139_661_583),
+    ([128, 129, 128], 1.098_742_095),
+  ];
+  for (rgb_arr, de) in asserts.iter() {
+    let other = ICtCp::from(Rgb::from(rgb_arr));
+    assert!((gray.delta_e_itp(&other) - de).abs() < 1e-6);
+    assert!((other.delta_e_itp(&gray) - de).abs() < 1e-6);
+  }
+
+  // encoding of colors does not matter
+  let red = ICtCp::from(Rgb::from([255, 0, 0]));
+  let blue = ICtCp::from(Rgb::from([0, 0, 255])).to_transfer(ICtCpTransfer::Hlg);
+  assert!((red.delta_e_itp(&blue) - 349.716_120_921).abs() < 1e-6);
+}
@@ -0,0 +1,34 @@
+use crate::{ColorAlpha, ColorTuple, Rgb, WhitePoint, Xyz, HDR_REFERENCE_WHITE};
+use crate::converters::{jzazbz_to_xyz, xyz_to_jzazbz};
+use crate::xyz::new_xyz_units;
+
+use super::{new_jzazbz_units, Jzazbz};
+
+tuples_def!(Jzazbz, f32);
+tuples_def!(Jzazbz, f64);
+
+impl Jzazbz {
+  /// Creates color from relative XYZ values, `Y` of `1.0` is displayed
+  /// at `sdr_white` luminance (cd/m²)
+  pub fn from_xyz_with_sdr_white(xyz: &Xyz, sdr_white: f64) -> Jzazbz {
+    let (x, y, z): ColorTuple = xyz.adapt(WhitePoint::D65).into();
+    let (jz, az, bz) = xyz_to_jzazbz(&(x * sdr_white, y * sdr_white, z * sdr_white));
+    let mut units = new_jzazbz_units(jz, az, bz);
+    units.alpha.set(xyz.alpha());
+    Jzazbz::from_units(units)
+  }
+
+  /// Returns relative XYZ (D65) values, `sdr_white` luminance (cd/m²) becomes `Y` of `1.0`
+  pub fn to_xyz_with_sdr_white(&self, sdr_white: f64) -> Xyz {
+    let (x, y, z) = jzazbz_to_xyz(&self.into());
+    let mut units = new_xyz_units(x / sdr_white, y / sdr_white, z / sdr_white);
+    units.alpha.set(self.alpha());
+    Xyz::from_units(units, WhitePoint::D65)
+  }
+}
+
+from_for_some!(Xyz, Jzazbz, xyz, { Jzazbz::from_xyz_with_sdr_white(xyz, HDR_REFERENCE_WHITE) });
+from_for_some!(Jzazbz, Xyz, jzazbz, { jzazbz.to_xyz_with_sdr_white(HDR_REFERENCE_WHITE) });
+
+from_for_some!(Rgb, Jzazbz, rgb, { Jzazbz::from(Xyz::from(rgb)) });
+from_for_some!(Jzazbz, Rgb, jzazbz, { Xyz::from(jzazbz).into() });
@@ -0,0 +1,109 @@
+use crate::ColorUnitsIter;
+use crate::consts::ALL_MIN;
+use crate::units::{Alpha, GetColorUnits, Unit, Units};
+
+#[cfg(test)]
+mod tests;
+
+mod from;
+
+/// The Jzazbz color space, perceptually uniform for high dynamic range.
+///
+/// Values are derived from absolute luminance, so the same `Xyz` or `Rgb` color gives
+/// different values depending on how bright white is displayed. Conversions with
+/// `From` put `Y` of `1.0` (white of `Rgb`) at `HDR_REFERENCE_WHITE` cd/m²;
+/// for another SDR white luminance see `from_xyz_with_sdr_white`
+/// and `to_xyz_with_sdr_white`.
+///
+/// Ranges:
+/// * jz: 0.0 - not restricted, 1.0 is about 10000 cd/m²
+/// * az: not restricted, usually -0.25 - 0.25
+/// * bz: not restricted, usually -0.25 - 0.25
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{ApproxEq, Jzazbz, Rgb, Xyz};
+///
+/// let white = Rgb::from([255, 255, 255]);
+/// let jzazbz = Jzazbz::from(&white);
+/// assert_eq!((jzazbz.jz() * 1000.0).round(), 222.0);
+///
+/// // white of a display which shows SDR content at 100 cd/m²
+/// let dim_white = Jzazbz::from_xyz_with_sdr_white(&Xyz::from(&white), 100.0);
+/// assert!(dim_white.jz() < jzazbz.jz());
+/// let xyz = dim_white.to_xyz_with_sdr_white(100.0);
+/// assert!(Rgb::from(&xyz).approx_eq_clarify(&white, 0.000_001));
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Jzazbz {
+  pub(crate) units: Units,
+}
+
+iter_def!(Jzazbz);
+
+pub(crate) fn new_jzazbz_units(jz: f64, az: f64, bz: f64) -> Units {
+  let ul = [
+    Unit::new_unbounded(jz.max(ALL_MIN)),
+    Unit::new_unbounded(az),
+    Unit::new_unbounded(bz),
+    Unit::default(),
+  ];
+  Units { len: 3, list: ul, alpha: Alpha::default() }
+}
+
+impl Jzazbz {
+  pub fn new(jz: f64, az: f64, bz: f64, a: Option<f64>) -> Jzazbz {
+    let mut units = new_jzazbz_units(jz, az, bz);
+    units.alpha.set_opt(a);
+    units.restrict();
+    Jzazbz { units }
+  }
+
+  pub(crate) fn from_units(u: Units) -> Self { Jzazbz { units: u } }
+
+  pub fn jz(&self) -> f64 { self.units[0] }
+  pub fn az(&self) -> f64 { self.units[1] }
+  pub fn bz(&self) -> f64 { self.units[2] }
+
+  pub fn set_jz(&mut self, val: f64) { self.units.list[0].set(val.max(ALL_MIN)); }
+  pub fn set_az(&mut self, val: f64) { self.units.list[1].set(val); }
+  pub fn set_bz(&mut self, val: f64) { self.units.list[2].set(val); }
+
+  /// Returns an iterator over three color units and the possibly alpha value.
+  pub fn iter(&self) -> ColorUnitsIter {
+    ColorUnitsIter::from_units(&self.units)
+  }
+}
+
+//
+//
+//
+// Default
+//
+impl Default for Jzazbz {
+  fn default() -> Jzazbz {
+    Jzazbz::from_units(new_jzazbz_units(0.0, 0.0, 0.0))
+  }
+}
+
+//
+//
+//
+// AsRef<Jzazbz>
+//
+impl AsRef<Jzazbz> for Jzazbz {
+  fn as_ref(&self) -> &Jzazbz {
+    self
+  }
+}
+
+impl GetColorUnits for Jzazbz {
+  fn get_units(&self) -> &Units {
+    &self.units
+  }
+  fn get_units_mut(&mut self) -> &mut Units {
+    &mut self.units
+  }
+}
@@ -0,0 +1,39 @@
+use crate::{ApproxEq, ColorAlpha, Jzazbz, Rgb, WhitePoint, Xyz, HDR_REFERENCE_WHITE};
+
+#[test]
+fn jzazbz_from_rgb() {
+  // SDR white at 203 cd/m², as in CSS Color 4
+  let asserts = [
+    ([255, 0, 0], (0.134_384_731, 0.117_885_263, 0.111_878_109)),
+    ([0, 255, 0], (0.176_807_128, -0.109_043_396, 0.118_989_436)),
+    ([0, 0, 255], (0.095_774_292, -0.040_845_496, -0.185_854_285)),
+    ([255, 255, 255], (0.222_065_250, -0.000_160_625, -0.000_117_034)),
+    ([0, 0, 0], (0.0, 0.0, 0.0)),
+  ];
+  for (rgb_arr, jzazbz_t) in asserts.iter() {
+    let rgb = Rgb::from(rgb_arr);
+    let jzazbz = Jzazbz::from(&rgb);
+    assert!(jzazbz.approx_eq_clarify(&Jzazbz::from(jzazbz_t), 1e-8));
+    assert!(Rgb::from(&jzazbz).approx_eq_clarify(&rgb, 0.000_001));
+  }
+}
+
+#[test]
+fn jzazbz_sdr_white() {
+  let xyz = Xyz::new_with_white(0.4, 0.3, 0.2, Some(0.5), WhitePoint::D50);
+  let jzazbz = Jzazbz::from(&xyz);
+  assert_eq!(jzazbz.alpha(), 0.5);
+  assert!(jzazbz.approx_eq(&Jzazbz::from_xyz_with_sdr_white(&xyz, HDR_REFERENCE_WHITE)));
+  assert!(Xyz::from(&jzazbz).approx_eq(&xyz.adapt(WhitePoint::D65)));
+
+  // the brighter white is, the lighter the color looks
+  let dim = Jzazbz::from_xyz_with_sdr_white(&xyz, 80.0);
+  let bright = Jzazbz::from_xyz_with_sdr_white(&xyz, 400.0);
+  assert!(dim.jz() < jzazbz.jz() && jzazbz.jz() < bright.jz());
+  assert!(dim.to_xyz_with_sdr_white(80.0).approx_eq(&xyz.adapt(WhitePoint::D65)));
+  assert!(bright.to_xyz_with_sdr_white(400.0).approx_eq(&xyz.adapt(WhitePoint::D65)));
+
+  // 10000 cd/m² is about 1.0
+  let peak = Jzazbz::from_xyz_with_sdr_white(&Xyz::from(Rgb::from([255, 255, 255])), 10_000.0);
+  assert!((peak.jz() - 1.0).abs() < 0.02);
+}
@@ -0,0 +1,44 @@
+use crate::{ColorAlpha, Jzazbz, Rgb, Xyz};
+use crate::converters::{polar_to_rect, rect_to_polar};
+use crate::jzazbz::new_jzazbz_units;
+
+use super::{new_jzczhz_units, JzCzhz};
+
+tuples_def!(JzCzhz, f32);
+tuples_def!(JzCzhz, f64);
+
+fn from_jzazbz(jzazbz: &Jzazbz) -> JzCzhz {
+  let (jz, cz, hz) = rect_to_polar(&jzazbz.into());
+  let mut units = new_jzczhz_units(jz, cz, hz);
+  units.alpha.set(jzazbz.alpha());
+  JzCzhz::from_units(units)
+}
+
+fn to_jzazbz(jzczhz: &JzCzhz) -> Jzazbz {
+  let (jz, az, bz) = polar_to_rect(&jzczhz.into());
+  let mut units = new_jzazbz_units(jz, az, bz);
+  units.alpha.set(jzczhz.alpha());
+  Jzazbz::from_units(units)
+}
+
+impl JzCzhz {
+  /// Creates color from relative XYZ values, `Y` of `1.0` is displayed
+  /// at `sdr_white` luminance (cd/m²)
+  pub fn from_xyz_with_sdr_white(xyz: &Xyz, sdr_white: f64) -> JzCzhz {
+    from_jzazbz(&Jzazbz::from_xyz_with_sdr_white(xyz, sdr_white))
+  }
+
+  /// Returns relative XYZ (D65) values, `sdr_white` luminance (cd/m²) becomes `Y` of `1.0`
+  pub fn to_xyz_with_sdr_white(&self, sdr_white: f64) -> Xyz {
+    to_jzazbz(self).to_xyz_with_sdr_white(sdr_white)
+  }
+}
+
+from_for_some!(Jzazbz, JzCzhz, jzazbz, { from_jzazbz(jzazbz) });
+from_for_some!(JzCzhz, Jzazbz, jzczhz, { to_jzazbz(jzczhz) });
+
+from_for_some!(Xyz, JzCzhz, xyz, { from_jzazbz(&xyz.into()) });
+from_for_some!(JzCzhz, Xyz, jzczhz, { to_jzazbz(jzczhz).into() });
+
+from_for_some!(Rgb, JzCzhz, rgb, { from_jzazbz(&rgb.into()) });
+from_for_some!(JzCzhz, Rgb, jzczhz, { to_jzazbz(jzczhz).into() });
@@ -0,0 +1,102 @@
+use crate::ColorUnitsIter;
+use crate::consts::ALL_MIN;
+use crate::units::{Alpha, GetColorUnits, Unit, Units};
+
+#[cfg(test)]
+mod tests;
+
+mod from;
+
+/// Cylindrical form of `Jzazbz`.
+///
+/// Hue is uniform for high dynamic range and wide gamut colors, which makes it
+/// a good space for gradients on HDR displays. As `Jzazbz`, values depend on
+/// absolute luminance (see `from_xyz_with_sdr_white`).
+///
+/// Ranges:
+/// * jz: 0.0 - not restricted, 1.0 is about 10000 cd/m²
+/// * cz: 0.0 - not restricted, usually up to 0.25
+/// * hz: 0.0 - 360.0
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{JzCzhz, Rgb};
+///
+/// let red = JzCzhz::from(Rgb::from([255, 0, 0]));
+/// assert_eq!((red.jz() * 1000.0).round(), 134.0);
+/// assert_eq!((red.cz() * 1000.0).round(), 163.0);
+/// assert_eq!(red.hz().round(), 44.0);
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JzCzhz {
+  pub(crate) units: Units,
+}
+
+iter_def!(JzCzhz);
+
+pub(crate) fn new_jzczhz_units(jz: f64, cz: f64, hz: f64) -> Units {
+  let ul = [
+    Unit::new_unbounded(jz.max(ALL_MIN)),
+    Unit::new_unbounded(cz.max(ALL_MIN)),
+    Unit::new_hue(hz),
+    Unit::default(),
+  ];
+  Units { len: 3, list: ul, alpha: Alpha::default() }
+}
+
+impl JzCzhz {
+  pub fn new(jz: f64, cz: f64, hz: f64, a: Option<f64>) -> JzCzhz {
+    let mut units = new_jzczhz_units(jz, cz, hz);
+    units.alpha.set_opt(a);
+    units.restrict();
+    JzCzhz { units }
+  }
+
+  pub(crate) fn from_units(u: Units) -> Self { JzCzhz { units: u } }
+
+  pub fn jz(&self) -> f64 { self.units[0] }
+  pub fn cz(&self) -> f64 { self.units[1] }
+  pub fn hz(&self) -> f64 { self.units[2] }
+
+  pub fn set_jz(&mut self, val: f64) { self.units.list[0].set(val.max(ALL_MIN)); }
+  pub fn set_cz(&mut self, val: f64) { self.units.list[1].set(val.max(ALL_MIN)); }
+  pub fn set_hz(&mut self, val: f64) { self.units.list[2].set(val); }
+
+  /// Returns an iterator over three color units and the possibly alpha value.
+  pub fn iter(&self) -> ColorUnitsIter {
+    ColorUnitsIter::from_units(&self.units)
+  }
+}
+
+//
+//
+//
+// Default
+//
+impl Default for JzCzhz {
+  fn default() -> JzCzhz {
+    JzCzhz::from_units(new_jzczhz_units(0.0, 0.0, 0.0))
+  }
+}
+
+//
+//
+//
+// AsRef<JzCzhz>
+//
+impl AsRef<JzCzhz> for JzCzhz {
+  fn as_ref(&self) -> &JzCzhz {
+    self
+  }
+}
+
+impl GetColorUnits for JzCzhz {
+  fn get_units(&self) -> &Units {
+    &self.units
+  }
+  fn get_units_mut(&mut self) -> &mut Units {
+    &mut self.units
+  }
+}
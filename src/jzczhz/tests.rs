@@ -0,0 +1,31 @@
+use crate::{ApproxEq, ColorAlpha, Jzazbz, JzCzhz, Rgb, Xyz};
+
+#[test]
+fn jzczhz_from_rgb() {
+  let asserts = [
+    ([255, 0, 0], (0.134_384_731, 0.162_522_757, 43.502_345)),
+    ([0, 255, 0], (0.176_807_128, 0.161_396_865, 132.502_530)),
+    ([0, 0, 255], (0.095_774_292, 0.190_289_700, 257.605_064)),
+    ([34, 111, 235], (0.115_889_806, 0.131_381_398, 254.152_173)),
+  ];
+  for (rgb_arr, jzczhz_t) in asserts.iter() {
+    let rgb = Rgb::from(rgb_arr);
+    let jzczhz = JzCzhz::from(&rgb);
+    assert!(jzczhz.approx_eq_clarify(&JzCzhz::from(jzczhz_t), 1e-6));
+    assert!(Rgb::from(&jzczhz).approx_eq_clarify(&rgb, 0.000_001));
+  }
+}
+
+#[test]
+fn jzczhz_jzazbz() {
+  let jzazbz = Jzazbz::new(0.1, -0.03, -0.04, Some(0.2));
+  let jzczhz = JzCzhz::from(&jzazbz);
+  assert!(jzczhz.approx_eq(&JzCzhz::new(0.1, 0.05, 233.130_102_354_155_98, Some(0.2))));
+  assert!(Jzazbz::from(&jzczhz).approx_eq(&jzazbz));
+
+  let xyz = Xyz::from(Rgb::from([200, 100, 50]));
+  let jzczhz = JzCzhz::from_xyz_with_sdr_white(&xyz, 100.0);
+  assert_eq!(jzczhz.alpha(), 1.0);
+  assert!(jzczhz.approx_eq(&JzCzhz::from(Jzazbz::from_xyz_with_sdr_white(&xyz, 100.0))));
+  assert!(jzczhz.to_xyz_with_sdr_white(100.0).approx_eq(&xyz));
+}
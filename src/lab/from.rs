@@ -0,0 +1,36 @@
+use crate::{Cmyk, ColorAlpha, Hsl, Rgb, WhitePoint, Xyz};
+use crate::converters::{lab_to_xyz, xyz_to_lab};
+use crate::xyz::new_xyz_units;
+
+use super::{new_lab_units, Lab};
+
+tuples_def!(Lab, f32);
+tuples_def!(Lab, f64);
+
+fn from_xyz(xyz: &Xyz) -> Lab {
+  let d50 = xyz.adapt(WhitePoint::D50);
+  let (l, a, b) = xyz_to_lab(&d50.into(), &WhitePoint::D50);
+  let mut units = new_lab_units(l, a, b);
+  units.alpha.set(xyz.alpha());
+  units.restrict();
+  Lab::from_units(units)
+}
+
+fn to_xyz(lab: &Lab) -> Xyz {
+  let (x, y, z) = lab_to_xyz(&lab.into(), &WhitePoint::D50);
+  let mut units = new_xyz_units(x, y, z);
+  units.alpha.set(lab.alpha());
+  Xyz::from_units(units, WhitePoint::D50)
+}
+
+from_for_some!(Xyz, Lab, xyz, { from_xyz(xyz) });
+from_for_some!(Lab, Xyz, lab, { to_xyz(lab) });
+
+from_for_some!(Rgb, Lab, rgb, { from_xyz(&rgb.into()) });
+from_for_some!(Lab, Rgb, lab, { to_xyz(lab).into() });
+
+from_for_some!(Hsl, Lab, hsl, { Lab::from(Rgb::from(hsl)) });
+from_for_some!(Lab, Hsl, lab, { Rgb::from(lab).into() });
+
+from_for_some!(Cmyk, Lab, cmyk, { Lab::from(Rgb::from(cmyk)) });
+from_for_some!(Lab, Cmyk, lab, { Rgb::from(lab).into() });
@@ -0,0 +1,154 @@
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+use crate::{ColorAlpha, ColorUnitsIter, ParseError};
+use crate::common::css_fn::{css_alpha, css_fn_from_str, css_fn_to_string, css_num_to_string, css_number};
+use crate::consts::PERCENT_MAX;
+use crate::err::make_parse_err;
+use crate::units::{Alpha, GetColorUnits, Unit, Units};
+
+#[cfg(test)]
+mod tests;
+
+mod from;
+mod transform;
+
+/// Value of `a` and `b` axes which corresponds to `100%` in CSS `lab()`
+pub(crate) const LAB_AB_PERCENT_REF: f64 = 125.0;
+
+/// The CIE L\*a\*b\* (CIELAB) color space.
+///
+/// Perceptually uniform color space with the `D50` reference white (as in CSS).
+/// Conversion from/to other color models is performed through `Xyz` with
+/// Bradford chromatic adaptation when needed.
+///
+/// Ranges:
+/// * lightness: 0.0 - 100.0
+/// * a: not restricted, usually -125.0 - 125.0 (green - red)
+/// * b: not restricted, usually -125.0 - 125.0 (blue - yellow)
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{Lab, Lch, Rgb, prelude::*};
+///
+/// let lab = Lab::from(Rgb::from([255, 0, 0]));
+/// assert_eq!(lab.to_css_string(), "lab(54.29 80.8 69.89)");
+///
+/// let lch: Lch = lab.as_ref().into();
+/// assert_eq!(lch.to_css_string(), "lch(54.29 106.84 40.86)");
+///
+/// let mut lab: Lab = "lab(50% 40 -20 / 0.5)".parse().unwrap();
+/// lab.lighten(10.0);
+/// assert_eq!(lab.to_css_string(), "lab(60 40 -20 / 0.5)");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lab {
+  pub(crate) units: Units,
+}
+
+iter_def!(Lab);
+
+pub(crate) fn new_lab_units(l: f64, a: f64, b: f64) -> Units {
+  let ul = [Unit::new_percent(l), Unit::new_unbounded(a), Unit::new_unbounded(b), Unit::default()];
+  Units { len: 3, list: ul, alpha: Alpha::default() }
+}
+
+impl Lab {
+  pub fn new(l: f64, a: f64, b: f64, alpha: Option<f64>) -> Lab {
+    let mut units = new_lab_units(l, a, b);
+    units.alpha.set_opt(alpha);
+    units.restrict();
+    Lab { units }
+  }
+
+  pub(crate) fn from_units(u: Units) -> Self { Lab { units: u } }
+
+  /// Returns a String that can be used in CSS.
+  /// # Example
+  /// ```
+  /// use colorsys::Lab;
+  ///
+  /// let lab = Lab::new(29.2345, 39.3825, -20.0664, Some(0.7));
+  /// assert_eq!(lab.to_css_string(), "lab(29.23 39.38 -20.07 / 0.7)");
+  /// ```
+  pub fn to_css_string(&self) -> String {
+    let u = &self.units;
+    let components = [
+      css_num_to_string(u[0], 2),
+      css_num_to_string(u[1], 2),
+      css_num_to_string(u[2], 2),
+    ];
+    css_fn_to_string("lab", &components, u.alpha.get_f64())
+  }
+
+  pub fn lightness(&self) -> f64 { self.units[0] }
+  pub fn a(&self) -> f64 { self.units[1] }
+  pub fn b(&self) -> f64 { self.units[2] }
+
+  pub fn set_lightness(&mut self, val: f64) { self.units.list[0].set(val); }
+  pub fn set_a(&mut self, val: f64) { self.units.list[1].set(val); }
+  pub fn set_b(&mut self, val: f64) { self.units.list[2].set(val); }
+
+  /// Returns an iterator over three color units and the possibly alpha value.
+  pub fn iter(&self) -> ColorUnitsIter {
+    ColorUnitsIter::from_units(&self.units)
+  }
+}
+
+//
+//
+//
+// Default
+//
+impl Default for Lab {
+  fn default() -> Lab {
+    Lab::from_units(new_lab_units(0.0, 0.0, 0.0))
+  }
+}
+
+//
+//
+//
+// AsRef<Lab>
+//
+impl AsRef<Lab> for Lab {
+  fn as_ref(&self) -> &Lab {
+    self
+  }
+}
+
+//
+//
+//
+// FromStr
+//
+impl core::str::FromStr for Lab {
+  type Err = ParseError;
+  /// Parses CSS `lab(..)` string. Percentages are allowed for all components.
+  fn from_str(s: &str) -> Result<Lab, ParseError> {
+    let make_err = || make_parse_err(s, "lab");
+    let (c, alpha) = css_fn_from_str(s, &["lab"], 3).ok_or_else(make_err)?;
+
+    let l = css_number(&c[0], PERCENT_MAX).ok_or_else(make_err)?;
+    let a = css_number(&c[1], LAB_AB_PERCENT_REF).ok_or_else(make_err)?;
+    let b = css_number(&c[2], LAB_AB_PERCENT_REF).ok_or_else(make_err)?;
+
+    let mut lab = Lab::new(l, a, b, None);
+    if let Some(a) = alpha {
+      lab.set_alpha(css_alpha(&a).ok_or_else(make_err)?);
+    }
+    Ok(lab)
+  }
+}
+
+
+impl GetColorUnits for Lab {
+  fn get_units(&self) -> &Units {
+    &self.units
+  }
+  fn get_units_mut(&mut self) -> &mut Units {
+    &mut self.units
+  }
+}
@@ -0,0 +1,77 @@
+use crate::{
+  ApproxEq, Cmyk, ColorTransform, ColorTuple, Hsl, Lab, Lch, ParseError, Rgb,
+  SaturationInSpace, Xyz,
+};
+
+#[test]
+fn lab_from_rgb() {
+  let asserts = [
+    ([255, 0, 0], (54.2905, 80.8049, 69.8910)),
+    ([255, 255, 255], (100.0, 0.0, 0.0)),
+    ([0, 0, 0], (0.0, 0.0, 0.0)),
+    ([34, 111, 235], (47.9914, 13.6184, -70.4057)),
+    ([0, 255, 0], (87.8185, -79.2711, 80.9946)),
+  ];
+  for (rgb_arr, lab_t) in asserts.iter() {
+    let rgb = Rgb::from(rgb_arr);
+    let lab = Lab::from(&rgb);
+    assert!(lab.approx_eq_clarify(&Lab::from(lab_t), 0.0001));
+    assert!(Rgb::from(&lab).approx_eq_clarify(&rgb, 0.000_001));
+  }
+}
+
+#[test]
+fn lab_conversions() {
+  let hsl = Hsl::new(200.0, 40.0, 30.0, Some(0.5));
+  let lab = Lab::from(&hsl);
+  assert!(Hsl::from(&lab).approx_eq_clarify(&hsl, 0.000_001));
+
+  let cmyk = Cmyk::new(0.0, 20.0, 70.0, 5.0, None);
+  let lab = Lab::from(&cmyk);
+  assert!(Cmyk::from(&lab).approx_eq_clarify(&cmyk, 0.000_001));
+
+  let xyz = Xyz::from(&lab);
+  assert!(Lab::from(&xyz).approx_eq(&lab));
+  assert!(Lab::from(Xyz::from(Rgb::from(&cmyk))).approx_eq(&lab));
+}
+
+#[test]
+fn from_str_tst() {
+  fn parse_lab(s: &str) -> Result<Lab, ParseError> {
+    s.parse::<Lab>()
+  }
+
+  assert_eq!(
+    Into::<ColorTuple>::into(parse_lab("lab(29.2345% 39.3825 20.0664)").unwrap()),
+    (29.2345, 39.3825, 20.0664)
+  );
+  let lab = parse_lab("LAB(52.2345 40% -100% / 25%)").unwrap();
+  assert!(lab.approx_eq(&Lab::new(52.2345, 50.0, -125.0, Some(0.25))));
+  assert_eq!(parse_lab("lab(120 none 0)").unwrap(), Lab::new(100.0, 0.0, 0.0, None));
+
+  assert!(parse_lab("lab(50 10)").is_err());
+  assert!(parse_lab("lab(50 10 10 / 1.5)").is_err());
+  assert!(parse_lab("lch(50 10 10)").is_err());
+
+  let lab = Lab::new(29.2345, -0.001, 20.0664, None);
+  assert_eq!(lab.to_css_string(), "lab(29.23 0 20.07)");
+}
+
+#[test]
+fn lab_transform() {
+  let mut lab = Lab::new(50.0, 30.0, 40.0, None);
+  lab.lighten(-20.0);
+  assert_eq!(lab.lightness(), 30.0);
+
+  lab.saturate(SaturationInSpace::Hsl(10.0));
+  assert!(Lch::from(&lab).approx_eq(&Lch::new(30.0, 65.0, 53.130_102_354_155_98, None)));
+
+  lab.adjust_hue(180.0);
+  assert!(lab.approx_eq(&Lab::new(30.0, -39.0, -52.0, None)));
+
+  lab.invert();
+  assert!(lab.approx_eq(&Lab::new(70.0, 39.0, 52.0, None)));
+
+  lab.grayscale_simple();
+  assert_eq!(lab, Lab::new(70.0, 0.0, 0.0, None));
+}
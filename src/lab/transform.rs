@@ -0,0 +1,41 @@
+use crate::{ColorTransform, Lch, SaturationInSpace};
+use crate::consts::{ALL_MIN, PERCENT_MAX};
+
+use super::Lab;
+
+impl Lab {
+  fn _transform_as_lch<F: FnOnce(&mut Lch)>(&mut self, f: F) {
+    let mut lch = Lch::from(&*self);
+    f(&mut lch);
+    *self = Lab::from(lch);
+  }
+}
+
+/// Modifications are performed in perceptual terms:
+/// `lighten` changes L\*, `saturate` changes chroma and `adjust_hue` rotates hue
+/// (see `Lch` for details).
+impl ColorTransform for Lab {
+  fn lighten(&mut self, amt: f64) {
+    self.units.list[0].increase(amt);
+  }
+
+  fn saturate(&mut self, sat: SaturationInSpace) {
+    self._transform_as_lch(|lch| lch.saturate(sat));
+  }
+
+  fn adjust_hue(&mut self, hue: f64) {
+    self._transform_as_lch(|lch| lch.adjust_hue(hue));
+  }
+
+  fn grayscale_simple(&mut self) {
+    self.units.list[1].value = ALL_MIN;
+    self.units.list[2].value = ALL_MIN;
+  }
+
+  /// Inverts lightness and opposes both `a` and `b` axes
+  fn invert(&mut self) {
+    self.units.list[0].set(PERCENT_MAX - self.units[0]);
+    self.units.list[1].value = -self.units[1];
+    self.units.list[2].value = -self.units[2];
+  }
+}
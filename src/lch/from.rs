@@ -0,0 +1,37 @@
+use crate::{Cmyk, ColorAlpha, Hsl, Lab, Rgb, Xyz};
+use crate::converters::{polar_to_rect, rect_to_polar};
+use crate::lab::new_lab_units;
+
+use super::{new_lch_units, Lch};
+
+tuples_def!(Lch, f32);
+tuples_def!(Lch, f64);
+
+fn from_lab(lab: &Lab) -> Lch {
+  let (l, c, h) = rect_to_polar(&lab.into());
+  let mut units = new_lch_units(l, c, h);
+  units.alpha.set(lab.alpha());
+  Lch::from_units(units)
+}
+
+fn to_lab(lch: &Lch) -> Lab {
+  let (l, a, b) = polar_to_rect(&lch.into());
+  let mut units = new_lab_units(l, a, b);
+  units.alpha.set(lch.alpha());
+  Lab::from_units(units)
+}
+
+from_for_some!(Lab, Lch, lab, { from_lab(lab) });
+from_for_some!(Lch, Lab, lch, { to_lab(lch) });
+
+from_for_some!(Xyz, Lch, xyz, { from_lab(&xyz.into()) });
+from_for_some!(Lch, Xyz, lch, { to_lab(lch).into() });
+
+from_for_some!(Rgb, Lch, rgb, { from_lab(&rgb.into()) });
+from_for_some!(Lch, Rgb, lch, { to_lab(lch).into() });
+
+from_for_some!(Hsl, Lch, hsl, { from_lab(&hsl.into()) });
+from_for_some!(Lch, Hsl, lch, { to_lab(lch).into() });
+
+from_for_some!(Cmyk, Lch, cmyk, { from_lab(&cmyk.into()) });
+from_for_some!(Lch, Cmyk, lch, { to_lab(lch).into() });
@@ -0,0 +1,153 @@
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+use crate::{ColorAlpha, ColorUnitsIter, ParseError};
+use crate::common::css_fn::{css_alpha, css_fn_from_str, css_fn_to_string, css_hue, css_num_to_string, css_number};
+use crate::consts::{ALL_MIN, PERCENT_MAX};
+use crate::err::make_parse_err;
+use crate::units::{Alpha, GetColorUnits, Unit, Units};
+
+#[cfg(test)]
+mod tests;
+
+mod from;
+mod transform;
+
+/// Value of chroma which corresponds to `100%` in CSS `lch()`
+pub(crate) const LCH_CHROMA_PERCENT_REF: f64 = 150.0;
+
+/// The CIE LCh(ab) color space: cylindrical form of `Lab`.
+///
+/// Ranges:
+/// * lightness: 0.0 - 100.0
+/// * chroma: 0.0 - not restricted, usually up to 150.0
+/// * hue: 0.0 - 360.0
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{Lch, Rgb, prelude::*};
+///
+/// let mut lch: Lch = "lch(52.2 72.2 50)".parse().unwrap();
+/// lch.adjust_hue(180.0);
+/// lch.saturate(SaturationInSpace::Hsl(-10.0));
+/// assert_eq!(lch.to_css_string(), "lch(52.2 57.2 230)");
+///
+/// let rgb = Rgb::from(&lch);
+/// assert_eq!(rgb.to_css_string(), "rgb(0,143,199)");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lch {
+  pub(crate) units: Units,
+}
+
+iter_def!(Lch);
+
+pub(crate) fn new_lch_units(l: f64, c: f64, h: f64) -> Units {
+  let ul = [
+    Unit::new_percent(l),
+    Unit::new_unbounded(c.max(ALL_MIN)),
+    Unit::new_hue(h),
+    Unit::default(),
+  ];
+  Units { len: 3, list: ul, alpha: Alpha::default() }
+}
+
+impl Lch {
+  pub fn new(l: f64, c: f64, h: f64, a: Option<f64>) -> Lch {
+    let mut units = new_lch_units(l, c, h);
+    units.alpha.set_opt(a);
+    units.restrict();
+    Lch { units }
+  }
+
+  pub(crate) fn from_units(u: Units) -> Self { Lch { units: u } }
+
+  /// Returns a String that can be used in CSS.
+  /// # Example
+  /// ```
+  /// use colorsys::Lch;
+  ///
+  /// let lch = Lch::new(29.2345, 44.2, 27.0, None);
+  /// assert_eq!(lch.to_css_string(), "lch(29.23 44.2 27)");
+  /// ```
+  pub fn to_css_string(&self) -> String {
+    let u = &self.units;
+    let components = [
+      css_num_to_string(u[0], 2),
+      css_num_to_string(u[1], 2),
+      css_num_to_string(u[2], 2),
+    ];
+    css_fn_to_string("lch", &components, u.alpha.get_f64())
+  }
+
+  pub fn lightness(&self) -> f64 { self.units[0] }
+  pub fn chroma(&self) -> f64 { self.units[1] }
+  pub fn hue(&self) -> f64 { self.units[2] }
+
+  pub fn set_lightness(&mut self, val: f64) { self.units.list[0].set(val); }
+  pub fn set_chroma(&mut self, val: f64) { self.units.list[1].set(val.max(ALL_MIN)); }
+  pub fn set_hue(&mut self, val: f64) { self.units.list[2].set(val); }
+
+  /// Returns an iterator over three color units and the possibly alpha value.
+  pub fn iter(&self) -> ColorUnitsIter {
+    ColorUnitsIter::from_units(&self.units)
+  }
+}
+
+//
+//
+//
+// Default
+//
+impl Default for Lch {
+  fn default() -> Lch {
+    Lch::from_units(new_lch_units(0.0, 0.0, 0.0))
+  }
+}
+
+//
+//
+//
+// AsRef<Lch>
+//
+impl AsRef<Lch> for Lch {
+  fn as_ref(&self) -> &Lch {
+    self
+  }
+}
+
+//
+//
+//
+// FromStr
+//
+impl core::str::FromStr for Lch {
+  type Err = ParseError;
+  /// Parses CSS `lch(..)` string. Hue can be specified with angle units (`deg`, `rad`, etc.)
+  fn from_str(s: &str) -> Result<Lch, ParseError> {
+    let make_err = || make_parse_err(s, "lch");
+    let (c, alpha) = css_fn_from_str(s, &["lch"], 3).ok_or_else(make_err)?;
+
+    let l = css_number(&c[0], PERCENT_MAX).ok_or_else(make_err)?;
+    let ch = css_number(&c[1], LCH_CHROMA_PERCENT_REF).ok_or_else(make_err)?;
+    let h = css_hue(&c[2]).ok_or_else(make_err)?;
+
+    let mut lch = Lch::new(l, ch, h, None);
+    if let Some(a) = alpha {
+      lch.set_alpha(css_alpha(&a).ok_or_else(make_err)?);
+    }
+    Ok(lch)
+  }
+}
+
+
+impl GetColorUnits for Lch {
+  fn get_units(&self) -> &Units {
+    &self.units
+  }
+  fn get_units_mut(&mut self) -> &mut Units {
+    &mut self.units
+  }
+}
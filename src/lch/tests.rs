@@ -0,0 +1,75 @@
+use crate::{
+  ApproxEq, ColorTransform, ColorTuple, ColorTupleA, Hsl, Lab, Lch, ParseError,
+  Rgb, SaturationInSpace,
+};
+
+#[test]
+fn lch_from_rgb() {
+  let asserts = [
+    ([255, 0, 0], (54.2905, 106.8372, 40.8577)),
+    ([255, 255, 255], (100.0, 0.0, 0.0)),
+    ([128, 128, 128], (53.5850, 0.0, 0.0)),
+    ([34, 111, 235], (47.9914, 71.7107, 280.9474)),
+    ([255, 255, 0], (97.6070, 94.7123, 99.5723)),
+  ];
+  for (rgb_arr, lch_t) in asserts.iter() {
+    let rgb = Rgb::from(rgb_arr);
+    let lch = Lch::from(&rgb);
+    assert!(lch.approx_eq_clarify(&Lch::from(lch_t), 0.0001));
+    assert!(Rgb::from(&lch).approx_eq_clarify(&rgb, 0.000_001));
+  }
+}
+
+#[test]
+fn lch_lab() {
+  let lab = Lab::new(50.0, -3.0, -4.0, Some(0.1));
+  let lch = Lch::from(&lab);
+  assert!(lch.approx_eq(&Lch::new(50.0, 5.0, 233.130_102_354_155_98, Some(0.1))));
+  assert!(Lab::from(&lch).approx_eq(&lab));
+
+  let hsl = Hsl::from((300.0, 70.0, 40.0));
+  assert!(Hsl::from(Lch::from(&hsl)).approx_eq_clarify(&hsl, 0.000_001));
+}
+
+#[test]
+fn from_str_tst() {
+  fn parse_lch(s: &str) -> Result<Lch, ParseError> {
+    s.parse::<Lch>()
+  }
+
+  assert_eq!(
+    Into::<ColorTuple>::into(parse_lch("lch(29.2345% 44.2 27)").unwrap()),
+    (29.2345, 44.2, 27.0)
+  );
+  assert_eq!(
+    Into::<ColorTupleA>::into(parse_lch("lch(50% 50% 0.5turn / 0.3)").unwrap()),
+    (50.0, 75.0, 180.0, 0.3)
+  );
+  assert_eq!(parse_lch("lch(50 -10 -90deg)").unwrap(), Lch::new(50.0, 0.0, 270.0, None));
+  assert!(parse_lch("lch(50 10 10deg 5)").is_err());
+  assert!(parse_lch("lch(50 10 10px)").is_err());
+
+  let lch = Lch::new(50.0, 30.0, 359.996, Some(0.25));
+  assert_eq!(lch.to_css_string(), "lch(50 30 360 / 0.25)");
+}
+
+#[test]
+fn lch_transform() {
+  let mut lch = Lch::new(50.0, 30.0, 40.0, None);
+  lch.lighten(60.0);
+  assert_eq!(lch.lightness(), 100.0);
+
+  lch.saturate(SaturationInSpace::Hsv(-30.0));
+  assert_eq!(lch.chroma(), 0.0);
+  lch.saturate(SaturationInSpace::Hsl(20.0));
+  assert_eq!(lch.chroma(), 30.0);
+
+  lch.adjust_hue(-60.0);
+  assert_eq!(lch.hue(), 340.0);
+
+  lch.invert();
+  assert_eq!(Into::<ColorTuple>::into(&lch), (0.0, 30.0, 160.0));
+
+  lch.grayscale_simple();
+  assert_eq!(Into::<ColorTuple>::into(&lch), (0.0, 0.0, 0.0));
+}
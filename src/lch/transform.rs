@@ -0,0 +1,38 @@
+use crate::{ColorTransform, SaturationInSpace};
+use crate::consts::{ALL_MIN, HUE_MAX, PERCENT_MAX};
+use crate::normalize::bound_hue;
+
+use super::{Lch, LCH_CHROMA_PERCENT_REF};
+
+impl ColorTransform for Lch {
+  /// Changes lightness (L\*). Amt is percent - `-100..100`
+  fn lighten(&mut self, amt: f64) {
+    self.units.list[0].increase(amt);
+  }
+
+  /// Changes chroma. Value is percent of chroma `150.0` (as `100%` in CSS `lch()`),
+  /// so `10.0` increases chroma by `15.0`. Both variants of `SaturationInSpace` are treated the same.
+  fn saturate(&mut self, sat: SaturationInSpace) {
+    let amt = match sat {
+      SaturationInSpace::Hsl(s) | SaturationInSpace::Hsv(s) => s,
+    };
+    self.set_chroma(self.units[1] + amt * LCH_CHROMA_PERCENT_REF / PERCENT_MAX);
+  }
+
+  fn adjust_hue(&mut self, hue: f64) {
+    let h = bound_hue(self.units[2] + hue);
+    self.units.list[2].set(h);
+  }
+
+  fn grayscale_simple(&mut self) {
+    self.units.list[1].value = ALL_MIN;
+    self.units.list[2].value = ALL_MIN;
+  }
+
+  /// Inverts lightness and rotates hue by 180 degrees
+  fn invert(&mut self) {
+    self.units.list[0].set(PERCENT_MAX - self.units[0]);
+    let h = (self.units[2] + HUE_MAX * 0.5) % HUE_MAX;
+    self.units.list[2].set(h);
+  }
+}
@@ -0,0 +1,34 @@
+use crate::{ColorAlpha, Hsl, Luv, Rgb, Xyz};
+use crate::converters::{polar_to_rect, rect_to_polar};
+use crate::luv::new_luv_units;
+
+use super::{new_lchuv_units, LchUv};
+
+tuples_def!(LchUv, f32);
+tuples_def!(LchUv, f64);
+
+fn from_luv(luv: &Luv) -> LchUv {
+  let (l, c, h) = rect_to_polar(&luv.into());
+  let mut units = new_lchuv_units(l, c, h);
+  units.alpha.set(luv.alpha());
+  LchUv::from_units(units)
+}
+
+fn to_luv(lchuv: &LchUv) -> Luv {
+  let (l, u, v) = polar_to_rect(&lchuv.into());
+  let mut units = new_luv_units(l, u, v);
+  units.alpha.set(lchuv.alpha());
+  Luv::from_units(units)
+}
+
+from_for_some!(Luv, LchUv, luv, { from_luv(luv) });
+from_for_some!(LchUv, Luv, lchuv, { to_luv(lchuv) });
+
+from_for_some!(Xyz, LchUv, xyz, { from_luv(&xyz.into()) });
+from_for_some!(LchUv, Xyz, lchuv, { to_luv(lchuv).into() });
+
+from_for_some!(Rgb, LchUv, rgb, { from_luv(&rgb.into()) });
+from_for_some!(LchUv, Rgb, lchuv, { to_luv(lchuv).into() });
+
+from_for_some!(Hsl, LchUv, hsl, { from_luv(&hsl.into()) });
+from_for_some!(LchUv, Hsl, lchuv, { to_luv(lchuv).into() });
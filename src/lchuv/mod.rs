@@ -0,0 +1,105 @@
+use crate::ColorUnitsIter;
+use crate::consts::ALL_MIN;
+use crate::units::{Alpha, GetColorUnits, Unit, Units};
+
+#[cfg(test)]
+mod tests;
+
+mod from;
+mod ops;
+mod transform;
+
+/// The CIE LCh(uv) color space: cylindrical form of `Luv`.
+///
+/// Ranges:
+/// * lightness: 0.0 - 100.0
+/// * chroma: 0.0 - not restricted, usually up to 180.0
+/// * hue: 0.0 - 360.0
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{LchUv, Rgb, prelude::*};
+///
+/// let mut lchuv = LchUv::new(53.24, 179.04, 12.17, None);
+/// lchuv.adjust_hue(120.0);
+/// lchuv.saturate(SaturationInSpace::Hsl(-50.0));
+/// assert_eq!(lchuv.hue(), 132.17);
+/// assert!((lchuv.chroma() - 89.04).abs() < 1e-10);
+///
+/// let rgb = Rgb::from(&lchuv);
+/// assert_eq!(rgb.to_css_string(), "rgb(0,151,3)");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LchUv {
+  pub(crate) units: Units,
+}
+
+iter_def!(LchUv);
+
+pub(crate) fn new_lchuv_units(l: f64, c: f64, h: f64) -> Units {
+  let ul = [
+    Unit::new_percent(l),
+    Unit::new_unbounded(c.max(ALL_MIN)),
+    Unit::new_hue(h),
+    Unit::default(),
+  ];
+  Units { len: 3, list: ul, alpha: Alpha::default() }
+}
+
+impl LchUv {
+  pub fn new(l: f64, c: f64, h: f64, a: Option<f64>) -> LchUv {
+    let mut units = new_lchuv_units(l, c, h);
+    units.alpha.set_opt(a);
+    units.restrict();
+    LchUv { units }
+  }
+
+  pub(crate) fn from_units(u: Units) -> Self { LchUv { units: u } }
+
+  pub fn lightness(&self) -> f64 { self.units[0] }
+  pub fn chroma(&self) -> f64 { self.units[1] }
+  pub fn hue(&self) -> f64 { self.units[2] }
+
+  pub fn set_lightness(&mut self, val: f64) { self.units.list[0].set(val); }
+  pub fn set_chroma(&mut self, val: f64) { self.units.list[1].set(val.max(ALL_MIN)); }
+  pub fn set_hue(&mut self, val: f64) { self.units.list[2].set(val); }
+
+  /// Returns an iterator over three color units and the possibly alpha value.
+  pub fn iter(&self) -> ColorUnitsIter {
+    ColorUnitsIter::from_units(&self.units)
+  }
+}
+
+//
+//
+//
+// Default
+//
+impl Default for LchUv {
+  fn default() -> LchUv {
+    LchUv::from_units(new_lchuv_units(0.0, 0.0, 0.0))
+  }
+}
+
+//
+//
+//
+// AsRef<LchUv>
+//
+impl AsRef<LchUv> for LchUv {
+  fn as_ref(&self) -> &LchUv {
+    self
+  }
+}
+
+
+impl GetColorUnits for LchUv {
+  fn get_units(&self) -> &Units {
+    &self.units
+  }
+  fn get_units_mut(&mut self) -> &mut Units {
+    &mut self.units
+  }
+}
@@ -0,0 +1,66 @@
+use crate::{
+  ApproxEq, ColorTransform, ColorTuple, ColorTupleA, Hsl, LchUv, Luv, Rgb,
+  SaturationInSpace,
+};
+
+#[test]
+fn lchuv_from_rgb() {
+  let asserts = [
+    ([255, 0, 0], (53.2371, 179.0381, 12.1771)),
+    ([255, 255, 255], (100.0, 0.0, 0.0)),
+    ([128, 128, 128], (53.5850, 0.0, 0.0)),
+    ([34, 111, 235], (49.1376, 109.4868, 257.6457)),
+    ([255, 255, 0], (97.1386, 107.0856, 85.8743)),
+  ];
+  for (rgb_arr, lchuv_t) in asserts.iter() {
+    let rgb = Rgb::from(rgb_arr);
+    let lchuv = LchUv::from(&rgb);
+    assert!(lchuv.approx_eq_clarify(&LchUv::from(lchuv_t), 0.0001));
+    assert!(Rgb::from(&lchuv).approx_eq_clarify(&rgb, 0.000_001));
+  }
+}
+
+#[test]
+fn lchuv_luv() {
+  let luv = Luv::new(50.0, -3.0, -4.0, Some(0.1));
+  let lchuv = LchUv::from(&luv);
+  assert!(lchuv.approx_eq(&LchUv::new(50.0, 5.0, 233.130_102_354_155_98, Some(0.1))));
+  assert!(Luv::from(&lchuv).approx_eq(&luv));
+
+  let hsl = Hsl::from((300.0, 70.0, 40.0));
+  assert!(Hsl::from(LchUv::from(&hsl)).approx_eq_clarify(&hsl, 0.000_001));
+
+  let t: ColorTupleA = LchUv::new(50.0, -10.0, 270.0, Some(0.3)).into();
+  assert_eq!(t, (50.0, 0.0, 270.0, 0.3));
+
+  let rgb = Rgb::from([255, 255, 0]);
+  assert!(LchUv::from(&rgb).approx_eq(&rgb));
+  assert!(!LchUv::new(97.0, 107.0, 86.0, None).approx_eq(&rgb));
+
+  let mut lchuv = LchUv::new(10.0, 20.0, 30.0, None);
+  lchuv += LchUv::new(20.0, 30.0, 40.0, None);
+  assert_eq!(Into::<ColorTuple>::into(&lchuv), (30.0, 50.0, 70.0));
+  lchuv -= LchUv::new(20.0, 30.0, 40.0, None);
+  assert_eq!(Into::<ColorTuple>::into(&lchuv), (10.0, 20.0, 30.0));
+}
+
+#[test]
+fn lchuv_transform() {
+  let mut lchuv = LchUv::new(50.0, 30.0, 40.0, None);
+  lchuv.lighten(60.0);
+  assert_eq!(lchuv.lightness(), 100.0);
+
+  lchuv.saturate(SaturationInSpace::Hsv(-30.0));
+  assert_eq!(lchuv.chroma(), 0.0);
+  lchuv.saturate(SaturationInSpace::Hsl(20.0));
+  assert_eq!(lchuv.chroma(), 36.0);
+
+  lchuv.adjust_hue(-60.0);
+  assert_eq!(lchuv.hue(), 340.0);
+
+  lchuv.invert();
+  assert_eq!(Into::<ColorTuple>::into(&lchuv), (0.0, 36.0, 160.0));
+
+  lchuv.grayscale_simple();
+  assert_eq!(Into::<ColorTuple>::into(&lchuv), (0.0, 0.0, 0.0));
+}
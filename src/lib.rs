@@ -1,5 +1,5 @@
 //! A module for color conversion and mutation written in Rust.
-//! For now works with RGB(a)( as hexadecimal too), HSL(a) color models
+//! For now works with RGB(a)( as hexadecimal too), linear-light sRGB, Display P3, Rec.2020, Rec.2100 PQ/HLG, Adobe RGB (1998), ProPhoto RGB, ACES (ACES2065-1, ACEScg, ACEScct), user-defined RGB spaces, HSL(a), HSV(a), HWB(a), CIE XYZ, CIE xyY, CIE L\*a\*b\*, LCh, CIE L\*u\*v\*, LCh(uv), HSLuv, HPLuv, Oklab, Oklch, Okhsl, Okhsv, CAM16, CAM16-UCS, HCT, Jzazbz, JzCzhz, ICtCp (PQ, HLG), YCbCr (BT.601, BT.709, BT.2020), YIQ, YUV, YCoCg, YCoCg-R color models
 //!
 //! ## What It Can Do
 //!
@@ -145,9 +145,41 @@
 //! - red - 0.0 .. 255.0
 //! - green - 0.0 .. 255.0
 //! - blue - 0.0 .. 255.0
+//! - red, green, blue (linear-light sRGB, Display P3, Rec.2020, Rec.2100 PQ/HLG, Adobe RGB, ProPhoto RGB, user-defined RGB spaces) - 0.0 .. 1.0
+//! - red, green, blue (ACES2065-1, ACEScg, ACEScct) - not restricted, `1.0` of linear values is scene diffuse white
 //! - hue - 0.0 .. 360.0
 //! - saturation - 0.0 .. 100.0
 //! - lightness - 0.0 .. 100.0
+//! - value - 0.0 .. 100.0
+//! - whiteness - 0.0 .. 100.0
+//! - blackness - 0.0 .. 100.0
+//! - x, y, z (CIE XYZ) - not restricted, `y` of reference white is 1.0
+//! - x, y (CIE xyY) - not restricted, 0.0 .. 1.0 for real colors
+//! - luminance (CIE xyY) - not restricted, reference white is 1.0
+//! - lightness (CIE L\*a\*b\*, LCh) - 0.0 .. 100.0
+//! - a, b (CIE L\*a\*b\*) - not restricted, usually -125.0 .. 125.0
+//! - chroma (LCh) - 0.0 .. not restricted, usually up to 150.0
+//! - lightness (CIE L\*u\*v\*, LCh(uv)) - 0.0 .. 100.0
+//! - u, v (CIE L\*u\*v\*) - not restricted
+//! - chroma (LCh(uv)) - 0.0 .. not restricted, usually up to 180.0
+//! - saturation (HPLuv) - 0.0 .. not restricted, 0.0 .. 100.0 is always in sRGB gamut
+//! - lightness (Oklab, Oklch) - 0.0 .. 1.0
+//! - a, b (Oklab) - not restricted, usually -0.4 .. 0.4
+//! - chroma (Oklch) - 0.0 .. not restricted, usually up to 0.4
+//! - y, cb, cr (YCbCr) - code values, 0.0 .. 255.0 (0.0 .. 1023.0 for 10 bits)
+//! - lightness (CAM16, CAM16-UCS) - 0.0 .. not restricted, 100.0 is reference white
+//! - chroma (CAM16) - 0.0 .. not restricted, usually up to 115.0
+//! - a, b (CAM16-UCS) - not restricted, usually -50.0 .. 50.0
+//! - jz (Jzazbz, JzCzhz) - 0.0 .. not restricted, 1.0 is about 10000 cd/m²
+//! - az, bz (Jzazbz) - not restricted, usually -0.25 .. 0.25
+//! - cz (JzCzhz) - 0.0 .. not restricted, usually up to 0.25
+//! - i (ICtCp) - 0.0 .. 1.0
+//! - ct, cp (ICtCp) - not restricted, usually -0.5 .. 0.5
+//! - hue, chroma, tone (HCT) - 0.0 .. 360.0, 0.0 .. not restricted (depends on hue and tone), 0.0 .. 100.0
+//! - y (YIQ, YUV, YCoCg) - 0.0 .. 1.0
+//! - i, q (YIQ), u, v (YUV), co, cg (YCoCg) - not restricted, usually -0.6 .. 0.6
+//! - y (YCoCg-R) - 0 .. 255, integer
+//! - co, cg (YCoCg-R) - -255 .. 255, integer
 //! - alpha - 0.0 .. 1.0
 //!
 //! If you specify a value that does not fit within these ranges, they are replaced with a minimum or maximum value.
@@ -164,27 +196,93 @@ extern crate alloc;
 
 mod macros;
 
+mod aces;
+mod adobe_rgb;
+mod cam16;
+mod cam16_ucs;
 mod common;
 mod consts;
 mod converters;
+mod custom_rgb;
+mod display_p3;
 mod err;
+mod hct;
+mod hpluv;
 mod hsl;
+mod hsluv;
+mod hsv;
+mod hwb;
+mod ictcp;
+mod jzazbz;
+mod jzczhz;
+mod lab;
+mod lch;
+mod lchuv;
+mod linear_rgb;
+mod luv;
 mod normalize;
+mod okhsl;
+mod okhsv;
+mod oklab;
+mod oklch;
+mod prophoto_rgb;
+mod rec2020;
+mod rec2100;
 mod rgb;
 mod ansi;
 mod cmyk;
 mod units;
+mod xyy;
+mod xyz;
+mod ycbcr;
+mod ycocg;
+mod yiq;
+mod yuv;
 
 pub mod prelude;
 pub mod ratio_converters;
+pub mod spectral;
+pub mod transfer;
 
+pub use aces::{Aces2065, AcesCct, AcesCg};
+pub use adobe_rgb::AdobeRgb;
+pub use cam16::{Cam16, Surround, ViewingConditions};
+pub use cam16_ucs::Cam16Ucs;
 pub use common::approx::{ApproxEq, DEFAULT_APPROX_EQ_PRECISION};
 pub use common::{ColorUnitsIter, ColorAlpha};
+pub use custom_rgb::{CustomRgb, RgbSpace, TransferFunction};
+pub use display_p3::DisplayP3;
 pub use err::ParseError;
+pub use hct::{Hct, TonalPalette};
+pub use hpluv::{Hpluv, HpluvRatio};
 pub use hsl::{Hsl, HslRatio};
+pub use hsluv::{Hsluv, HsluvRatio};
+pub use hsv::{Hsv, HsvRatio};
+pub use hwb::{Hwb, HwbRatio};
+pub use ictcp::{ICtCp, ICtCpTransfer};
+pub use jzazbz::Jzazbz;
+pub use jzczhz::JzCzhz;
+pub use lab::Lab;
+pub use lch::Lch;
+pub use lchuv::LchUv;
+pub use linear_rgb::LinearRgb;
+pub use luv::Luv;
+pub use okhsl::Okhsl;
+pub use okhsv::Okhsv;
+pub use oklab::Oklab;
+pub use oklch::Oklch;
+pub use prophoto_rgb::ProPhotoRgb;
+pub use rec2020::Rec2020;
+pub use rec2100::{Rec2100Hlg, Rec2100Pq, HDR_REFERENCE_WHITE};
 pub use rgb::{GrayScaleMethod, Rgb, RgbRatio};
 pub use cmyk::{Cmyk, CmykRatio};
 pub use ansi::{Ansi256};
+pub use xyy::XyY;
+pub use xyz::{ChromaticAdaptation, WhitePoint, Xyz};
+pub use ycbcr::{YCbCr, YCbCrBitDepth, YCbCrFormat, YCbCrMatrix, YCbCrRange};
+pub use ycocg::{YCoCg, YCoCgR};
+pub use yiq::Yiq;
+pub use yuv::Yuv;
 
 /// Use to transfer and collect color values.
 /// May be for example `($red,$green,$blue)` or `($hue,$saturation,$value)`
@@ -0,0 +1,55 @@
+use crate::{ColorAlpha, Rgb, RgbRatio, WhitePoint, Xyz};
+use crate::consts::RGB_UNIT_MAX;
+use crate::converters::{linear_rgb_to_xyz, xyz_to_linear_rgb};
+use crate::rgb::new_rgb_units;
+use crate::transfer::{linear_to_srgb, srgb_to_linear};
+use crate::xyz::new_xyz_units;
+
+use super::{new_linear_rgb_units, LinearRgb};
+
+tuples_def!(LinearRgb, f32);
+tuples_def!(LinearRgb, f64);
+
+fn from_ratio(r: f64, g: f64, b: f64, alpha: f64) -> LinearRgb {
+  let mut units = new_linear_rgb_units(srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b));
+  units.alpha.set(alpha);
+  units.restrict();
+  LinearRgb::from_units(units)
+}
+
+fn to_ratio(lin: &LinearRgb) -> (f64, f64, f64) {
+  let u = &lin.units;
+  (linear_to_srgb(u[0]), linear_to_srgb(u[1]), linear_to_srgb(u[2]))
+}
+
+from_for_some!(Rgb, LinearRgb, rgb, {
+  let [r, g, b]: [f64; 3] = rgb.units.as_ratio().into();
+  from_ratio(r, g, b, rgb.alpha())
+});
+from_for_some!(LinearRgb, Rgb, lin, {
+  let (r, g, b) = to_ratio(lin);
+  let mut units = new_rgb_units(r * RGB_UNIT_MAX, g * RGB_UNIT_MAX, b * RGB_UNIT_MAX);
+  units.alpha.set(lin.alpha());
+  units.restrict();
+  Rgb::from_units(units)
+});
+
+from_for_some!(RgbRatio, LinearRgb, ratio, { from_ratio(ratio.r(), ratio.g(), ratio.b(), ratio.a()) });
+from_for_some!(LinearRgb, RgbRatio, lin, {
+  let (r, g, b) = to_ratio(lin);
+  RgbRatio::new(r, g, b, lin.alpha())
+});
+
+from_for_some!(Xyz, LinearRgb, xyz, {
+  let (r, g, b) = xyz_to_linear_rgb(&xyz.adapt(WhitePoint::D65).into());
+  let mut units = new_linear_rgb_units(r, g, b);
+  units.alpha.set(xyz.alpha());
+  units.restrict();
+  LinearRgb::from_units(units)
+});
+from_for_some!(LinearRgb, Xyz, lin, {
+  let (x, y, z) = linear_rgb_to_xyz(&lin.into());
+  let mut units = new_xyz_units(x, y, z);
+  units.alpha.set(lin.alpha());
+  Xyz::from_units(units, WhitePoint::D65)
+});
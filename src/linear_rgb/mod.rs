@@ -0,0 +1,105 @@
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+
+use crate::ColorUnitsIter;
+use crate::units::{Alpha, GetColorUnits, Unit, Units};
+
+#[cfg(test)]
+mod tests;
+
+mod from;
+
+/// The linear-light sRGB color model.
+///
+/// Has the same primaries and white point as `Rgb`, but values are proportional
+/// to the light intensity (sRGB transfer function is removed, see `transfer` module).
+/// So adding, subtracting and averaging of colors are physically correct in this model.
+///
+/// Ranges:
+/// * red: 0.0 - 1.0
+/// * green: 0.0 - 1.0
+/// * blue: 0.0 - 1.0
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{LinearRgb, Rgb};
+///
+/// let red = LinearRgb::from(Rgb::from([255, 0, 0]));
+/// let green = LinearRgb::from(Rgb::from([0, 255, 0]));
+///
+/// let sum = &red + &green;
+/// assert_eq!(Rgb::from(&sum).to_css_string(), "rgb(255,255,0)");
+///
+/// let mut half = LinearRgb::from(Rgb::from([255, 255, 255]));
+/// half.set_green(0.5);
+/// assert_eq!(Rgb::from(&half).to_css_string(), "rgb(255,188,255)");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LinearRgb {
+  pub(crate) units: Units,
+}
+
+iter_def!(LinearRgb);
+ops_def!(LinearRgb);
+
+pub(crate) fn new_linear_rgb_units(r: f64, g: f64, b: f64) -> Units {
+  let ul = [Unit::new_ratio(r), Unit::new_ratio(g), Unit::new_ratio(b), Unit::default()];
+  Units { len: 3, list: ul, alpha: Alpha::default() }
+}
+
+impl LinearRgb {
+  pub fn new(r: f64, g: f64, b: f64, a: Option<f64>) -> LinearRgb {
+    let mut units = new_linear_rgb_units(r, g, b);
+    units.alpha.set_opt(a);
+    units.restrict();
+    LinearRgb { units }
+  }
+
+  pub(crate) fn from_units(u: Units) -> Self { LinearRgb { units: u } }
+
+  pub fn red(&self) -> f64 { self.units[0] }
+  pub fn green(&self) -> f64 { self.units[1] }
+  pub fn blue(&self) -> f64 { self.units[2] }
+
+  pub fn set_red(&mut self, val: f64) { self.units.list[0].set(val); }
+  pub fn set_green(&mut self, val: f64) { self.units.list[1].set(val); }
+  pub fn set_blue(&mut self, val: f64) { self.units.list[2].set(val); }
+
+  /// Returns an iterator over three color units and the possibly alpha value.
+  pub fn iter(&self) -> ColorUnitsIter {
+    ColorUnitsIter::from_units(&self.units)
+  }
+}
+
+//
+//
+//
+// Default
+//
+impl Default for LinearRgb {
+  fn default() -> LinearRgb {
+    LinearRgb::from_units(new_linear_rgb_units(0.0, 0.0, 0.0))
+  }
+}
+
+//
+//
+//
+// AsRef<LinearRgb>
+//
+impl AsRef<LinearRgb> for LinearRgb {
+  fn as_ref(&self) -> &LinearRgb {
+    self
+  }
+}
+
+
+impl GetColorUnits for LinearRgb {
+  fn get_units(&self) -> &Units {
+    &self.units
+  }
+  fn get_units_mut(&mut self) -> &mut Units {
+    &mut self.units
+  }
+}
@@ -0,0 +1,40 @@
+use crate::{ApproxEq, ColorAlpha, ColorTuple, LinearRgb, Rgb, RgbRatio, WhitePoint, Xyz};
+
+#[test]
+fn linear_rgb_from_rgb() {
+  let asserts = [
+    ([255.0, 255.0, 255.0], (1.0, 1.0, 1.0)),
+    ([0.0, 0.0, 0.0], (0.0, 0.0, 0.0)),
+    ([127.5, 5.1, 255.0], (0.214_041_140_5, 0.001_547_987_6, 1.0)),
+  ];
+  for (rgb_arr, lin_t) in asserts.iter() {
+    let rgb = Rgb::from(rgb_arr);
+    let lin = LinearRgb::from(&rgb);
+    assert!(lin.approx_eq_clarify(&LinearRgb::from(lin_t), 1e-9));
+    assert!(Rgb::from(&lin).approx_eq_clarify(&rgb, 1e-9));
+  }
+}
+
+#[test]
+fn linear_rgb_conversions() {
+  let ratio = RgbRatio::new(0.5, 0.2, 0.9, 0.4);
+  let lin = LinearRgb::from(&ratio);
+  assert_eq!(lin.alpha(), 0.4);
+  assert!(RgbRatio::from(&lin).approx_eq(&ratio));
+  assert!(Rgb::from(&lin).approx_eq(&Rgb::from(&ratio)));
+
+  let xyz = Xyz::from(&lin);
+  assert!(xyz.approx_eq(&Xyz::from(Rgb::from(&ratio))));
+  assert!(LinearRgb::from(xyz.adapt(WhitePoint::D50)).approx_eq(&lin));
+}
+
+#[test]
+fn linear_rgb_ops() {
+  let gray = LinearRgb::new(0.2, 0.2, 0.2, None);
+  let sum = &gray + &LinearRgb::new(0.3, 0.9, 0.0, None);
+  assert!(sum.approx_eq(&LinearRgb::new(0.5, 1.0, 0.2, None)));
+
+  let diff = sum - gray;
+  let t: ColorTuple = diff.into();
+  assert!(LinearRgb::from(t).approx_eq(&LinearRgb::new(0.3, 0.8, 0.0, None)));
+}
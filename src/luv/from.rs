@@ -0,0 +1,33 @@
+use crate::{ColorAlpha, Hsl, Rgb, WhitePoint, Xyz};
+use crate::converters::{luv_to_xyz, xyz_to_luv};
+use crate::xyz::new_xyz_units;
+
+use super::{new_luv_units, Luv};
+
+tuples_def!(Luv, f32);
+tuples_def!(Luv, f64);
+
+fn from_xyz(xyz: &Xyz) -> Luv {
+  let d65 = xyz.adapt(WhitePoint::D65);
+  let (l, u, v) = xyz_to_luv(&d65.into(), &WhitePoint::D65);
+  let mut units = new_luv_units(l, u, v);
+  units.alpha.set(xyz.alpha());
+  units.restrict();
+  Luv::from_units(units)
+}
+
+fn to_xyz(luv: &Luv) -> Xyz {
+  let (x, y, z) = luv_to_xyz(&luv.into(), &WhitePoint::D65);
+  let mut units = new_xyz_units(x, y, z);
+  units.alpha.set(luv.alpha());
+  Xyz::from_units(units, WhitePoint::D65)
+}
+
+from_for_some!(Xyz, Luv, xyz, { from_xyz(xyz) });
+from_for_some!(Luv, Xyz, luv, { to_xyz(luv) });
+
+from_for_some!(Rgb, Luv, rgb, { from_xyz(&rgb.into()) });
+from_for_some!(Luv, Rgb, luv, { to_xyz(luv).into() });
+
+from_for_some!(Hsl, Luv, hsl, { Luv::from(Rgb::from(hsl)) });
+from_for_some!(Luv, Hsl, luv, { Rgb::from(luv).into() });
@@ -0,0 +1,100 @@
+use crate::ColorUnitsIter;
+use crate::units::{Alpha, GetColorUnits, Unit, Units};
+
+#[cfg(test)]
+mod tests;
+
+mod from;
+mod ops;
+mod transform;
+
+/// The CIE L\*u\*v\* (CIELUV) color space.
+///
+/// Perceptually uniform color space built on CIE 1976 u'v' chromaticity diagram.
+/// `D65` is used as the reference white. Conversion from/to other color models is
+/// performed through `Xyz` with Bradford chromatic adaptation when needed.
+///
+/// Ranges:
+/// * lightness: 0.0 - 100.0
+/// * u: not restricted, usually -100.0 - 180.0 (green - red)
+/// * v: not restricted, usually -140.0 - 110.0 (blue - yellow)
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{ApproxEq, LchUv, Luv, Rgb};
+///
+/// let luv = Luv::from(Rgb::from([255, 0, 0]));
+/// assert!(luv.approx_eq_clarify(&Luv::new(53.2371, 175.0098, 37.7651, None), 0.0001));
+///
+/// let lchuv: LchUv = luv.as_ref().into();
+/// assert!(lchuv.approx_eq_clarify(&LchUv::new(53.2371, 179.0381, 12.1771, None), 0.0001));
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Luv {
+  pub(crate) units: Units,
+}
+
+iter_def!(Luv);
+
+pub(crate) fn new_luv_units(l: f64, u: f64, v: f64) -> Units {
+  let ul = [Unit::new_percent(l), Unit::new_unbounded(u), Unit::new_unbounded(v), Unit::default()];
+  Units { len: 3, list: ul, alpha: Alpha::default() }
+}
+
+impl Luv {
+  pub fn new(l: f64, u: f64, v: f64, a: Option<f64>) -> Luv {
+    let mut units = new_luv_units(l, u, v);
+    units.alpha.set_opt(a);
+    units.restrict();
+    Luv { units }
+  }
+
+  pub(crate) fn from_units(u: Units) -> Self { Luv { units: u } }
+
+  pub fn lightness(&self) -> f64 { self.units[0] }
+  pub fn u(&self) -> f64 { self.units[1] }
+  pub fn v(&self) -> f64 { self.units[2] }
+
+  pub fn set_lightness(&mut self, val: f64) { self.units.list[0].set(val); }
+  pub fn set_u(&mut self, val: f64) { self.units.list[1].set(val); }
+  pub fn set_v(&mut self, val: f64) { self.units.list[2].set(val); }
+
+  /// Returns an iterator over three color units and the possibly alpha value.
+  pub fn iter(&self) -> ColorUnitsIter {
+    ColorUnitsIter::from_units(&self.units)
+  }
+}
+
+//
+//
+//
+// Default
+//
+impl Default for Luv {
+  fn default() -> Luv {
+    Luv::from_units(new_luv_units(0.0, 0.0, 0.0))
+  }
+}
+
+//
+//
+//
+// AsRef<Luv>
+//
+impl AsRef<Luv> for Luv {
+  fn as_ref(&self) -> &Luv {
+    self
+  }
+}
+
+
+impl GetColorUnits for Luv {
+  fn get_units(&self) -> &Units {
+    &self.units
+  }
+  fn get_units_mut(&mut self) -> &mut Units {
+    &mut self.units
+  }
+}
@@ -0,0 +1,68 @@
+use crate::{
+  ApproxEq, ColorTransform, ColorTuple, Hsl, LchUv, Luv, Rgb, SaturationInSpace,
+  WhitePoint, Xyz,
+};
+
+#[test]
+fn luv_from_rgb() {
+  let asserts = [
+    ([255, 0, 0], (53.2371, 175.0098, 37.7651)),
+    ([255, 255, 255], (100.0, 0.0, 0.0)),
+    ([0, 0, 0], (0.0, 0.0, 0.0)),
+    ([34, 111, 235], (49.1376, -23.4254, -106.9514)),
+    ([0, 255, 0], (87.7355, -83.0671, 107.4181)),
+  ];
+  for (rgb_arr, luv_t) in asserts.iter() {
+    let rgb = Rgb::from(rgb_arr);
+    let luv = Luv::from(&rgb);
+    assert!(luv.approx_eq_clarify(&Luv::from(luv_t), 0.0001));
+    assert!(Rgb::from(&luv).approx_eq_clarify(&rgb, 0.000_001));
+  }
+}
+
+#[test]
+fn luv_conversions() {
+  let hsl = Hsl::new(200.0, 40.0, 30.0, Some(0.5));
+  let luv = Luv::from(&hsl);
+  assert!(Hsl::from(&luv).approx_eq_clarify(&hsl, 0.000_001));
+
+  let xyz = Xyz::from(&luv);
+  assert!(Luv::from(&xyz).approx_eq(&luv));
+  assert!(Luv::from(xyz.adapt(WhitePoint::D50)).approx_eq(&luv));
+
+  let t: ColorTuple = Luv::new(120.0, -500.0, 500.0, None).into();
+  assert_eq!(t, (100.0, -500.0, 500.0));
+
+  // negative `v'`, out of the spectral locus
+  let out = Luv::new(50.0, 0.0, -400.0, None);
+  assert_eq!(Into::<ColorTuple>::into(Xyz::from(&out)), (0.0, 0.0, 0.0));
+  assert_eq!(Into::<[u8; 3]>::into(Rgb::from(&out)), [0, 0, 0]);
+
+  let rgb = Rgb::from([34, 111, 235]);
+  assert!(Luv::from(&rgb).approx_eq(&rgb));
+  assert!(!Luv::new(49.0, -23.0, -107.0, None).approx_eq(&rgb));
+
+  let sum = Luv::new(10.0, -20.0, 30.0, None) + Luv::new(20.0, 30.0, -40.0, None);
+  assert_eq!(Into::<ColorTuple>::into(sum), (30.0, 10.0, -10.0));
+  let diff = Luv::new(50.0, -20.0, 30.0, None) - Luv::new(20.0, 30.0, -40.0, None);
+  assert_eq!(Into::<ColorTuple>::into(diff), (30.0, -50.0, 70.0));
+}
+
+#[test]
+fn luv_transform() {
+  let mut luv = Luv::new(50.0, 30.0, 40.0, None);
+  luv.lighten(-20.0);
+  assert_eq!(luv.lightness(), 30.0);
+
+  luv.saturate(SaturationInSpace::Hsl(10.0));
+  assert!(LchUv::from(&luv).approx_eq(&LchUv::new(30.0, 68.0, 53.130_102_354_155_98, None)));
+
+  luv.adjust_hue(180.0);
+  assert!(luv.approx_eq(&Luv::new(30.0, -40.8, -54.4, None)));
+
+  luv.invert();
+  assert!(luv.approx_eq(&Luv::new(70.0, 40.8, 54.4, None)));
+
+  luv.grayscale_simple();
+  assert_eq!(luv, Luv::new(70.0, 0.0, 0.0, None));
+}
@@ -0,0 +1,41 @@
+use crate::{ColorTransform, LchUv, SaturationInSpace};
+use crate::consts::{ALL_MIN, PERCENT_MAX};
+
+use super::Luv;
+
+impl Luv {
+  fn _transform_as_lchuv<F: FnOnce(&mut LchUv)>(&mut self, f: F) {
+    let mut lchuv = LchUv::from(&*self);
+    f(&mut lchuv);
+    *self = Luv::from(lchuv);
+  }
+}
+
+/// Modifications are performed in perceptual terms:
+/// `lighten` changes L\*, `saturate` changes chroma and `adjust_hue` rotates hue
+/// (see `LchUv` for details).
+impl ColorTransform for Luv {
+  fn lighten(&mut self, amt: f64) {
+    self.units.list[0].increase(amt);
+  }
+
+  fn saturate(&mut self, sat: SaturationInSpace) {
+    self._transform_as_lchuv(|lchuv| lchuv.saturate(sat));
+  }
+
+  fn adjust_hue(&mut self, hue: f64) {
+    self._transform_as_lchuv(|lchuv| lchuv.adjust_hue(hue));
+  }
+
+  fn grayscale_simple(&mut self) {
+    self.units.list[1].value = ALL_MIN;
+    self.units.list[2].value = ALL_MIN;
+  }
+
+  /// Inverts lightness and opposes both `u` and `v` axes
+  fn invert(&mut self) {
+    self.units.list[0].set(PERCENT_MAX - self.units[0]);
+    self.units.list[1].value = -self.units[1];
+    self.units.list[2].value = -self.units[2];
+  }
+}
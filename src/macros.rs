@@ -89,3 +89,230 @@ impl core::iter::IntoIterator for $name {
 
 };
 }
+
+
+
+macro_rules! from_for_some {
+  ($from: ty, $to: ty, $val: ident, $conv: block) => {
+    impl From<&$from> for $to {
+      fn from($val: &$from) -> $to {
+        ($conv)
+      }
+    }
+
+    impl From<&mut $from> for $to {
+      fn from($val: &mut $from) -> $to {
+        let $val: &$from = $val;
+        ($conv)
+      }
+    }
+
+    impl From<$from> for $to {
+      fn from($val: $from) -> $to {
+        <$to>::from(&$val)
+      }
+    }
+  };
+}
+
+
+
+macro_rules! tuples_def {
+  ($name: ident, $t: ty) => {
+impl From<&($t, $t, $t)> for $name {
+  fn from(v: &($t, $t, $t)) -> $name { $name::new(v.0 as f64, v.1 as f64, v.2 as f64, None) }
+}
+impl From<($t, $t, $t)> for $name {
+  fn from(v: ($t, $t, $t)) -> $name { $name::from(&v) }
+}
+impl From<&[$t; 3]> for $name {
+  fn from(v: &[$t; 3]) -> $name { $name::new(v[0] as f64, v[1] as f64, v[2] as f64, None) }
+}
+impl From<[$t; 3]> for $name {
+  fn from(v: [$t; 3]) -> $name { $name::from(&v) }
+}
+impl From<&($t, $t, $t, $t)> for $name {
+  fn from(v: &($t, $t, $t, $t)) -> $name {
+    $name::new(v.0 as f64, v.1 as f64, v.2 as f64, Some(v.3 as f64))
+  }
+}
+impl From<($t, $t, $t, $t)> for $name {
+  fn from(v: ($t, $t, $t, $t)) -> $name { $name::from(&v) }
+}
+impl From<&[$t; 4]> for $name {
+  fn from(v: &[$t; 4]) -> $name {
+    $name::new(v[0] as f64, v[1] as f64, v[2] as f64, Some(v[3] as f64))
+  }
+}
+impl From<[$t; 4]> for $name {
+  fn from(v: [$t; 4]) -> $name { $name::from(&v) }
+}
+
+into_for_some!(($t, $t, $t), $name, self, {
+  let u = &self.units;
+  (u[0] as $t, u[1] as $t, u[2] as $t)
+});
+into_for_some!([$t; 3], $name, self, {
+  let u = &self.units;
+  [u[0] as $t, u[1] as $t, u[2] as $t]
+});
+into_for_some!(($t, $t, $t, $t), $name, self, {
+  let u = &self.units;
+  (u[0] as $t, u[1] as $t, u[2] as $t, u.alpha.get_f64() as $t)
+});
+into_for_some!([$t; 4], $name, self, {
+  let u = &self.units;
+  [u[0] as $t, u[1] as $t, u[2] as $t, u.alpha.get_f64() as $t]
+});
+
+};
+}
+
+
+
+// Common part of RGB color spaces which are defined by primaries, white point and transfer
+// function (`DisplayP3`, `Rec2020`..). Values are gamma-encoded ratios, conversion to and from
+// other color models is performed through `Xyz`. Also adds parsing and serializing of CSS
+// `color()` function with `$css` color space.
+// `String` and `ColorUnitsIter` must be imported at the call site.
+macro_rules! rgb_space_def {
+  ($name: ident, $css: expr, $primaries: expr, $white: expr, $to_linear: expr, $from_linear: expr) => {
+
+rgb_space_base_def!($name, Unit::new_ratio, $primaries, $white, $to_linear, $from_linear);
+
+impl $name {
+  /// Returns a String that can be used in CSS (`color()` function).
+  pub fn to_css_string(&self) -> String {
+    use crate::common::css_fn::{css_color_fn_to_string, css_num_to_string};
+    let u = &self.units;
+    let components = [
+      css_num_to_string(u[0], 4),
+      css_num_to_string(u[1], 4),
+      css_num_to_string(u[2], 4),
+    ];
+    css_color_fn_to_string($css, &components, u.alpha.get_f64())
+  }
+}
+
+impl core::str::FromStr for $name {
+  type Err = crate::ParseError;
+  /// Parses CSS `color()` function. Percentages and `none` keyword are allowed
+  fn from_str(s: &str) -> Result<$name, crate::ParseError> {
+    use crate::common::css_fn::{css_alpha, css_color_fn_from_str, css_number};
+    use crate::consts::RATIO_MAX;
+    let make_err = || crate::err::make_parse_err(s, $css);
+    let (c, alpha) = css_color_fn_from_str(s, &[$css], 3).ok_or_else(make_err)?;
+
+    let r = css_number(&c[0], RATIO_MAX).ok_or_else(make_err)?;
+    let g = css_number(&c[1], RATIO_MAX).ok_or_else(make_err)?;
+    let b = css_number(&c[2], RATIO_MAX).ok_or_else(make_err)?;
+
+    let mut color = $name::new(r, g, b, None);
+    if let Some(a) = alpha {
+      color.units.alpha.set(css_alpha(&a).ok_or_else(make_err)?);
+    }
+    Ok(color)
+  }
+}
+
+};
+}
+
+
+
+// RGB color space without CSS representation. `$unit` is constructor of `Unit` of
+// red, green and blue values (`Unit::new_ratio`, `Unit::new_unbounded`..).
+// `ColorUnitsIter` must be imported at the call site.
+macro_rules! rgb_space_base_def {
+  ($name: ident, $unit: path, $primaries: expr, $white: expr, $to_linear: expr, $from_linear: expr) => {
+
+iter_def!($name);
+tuples_def!($name, f32);
+tuples_def!($name, f64);
+
+impl $name {
+  pub fn new(r: f64, g: f64, b: f64, a: Option<f64>) -> $name {
+    use crate::units::{Alpha, Unit};
+    let ul = [$unit(r), $unit(g), $unit(b), Unit::default()];
+    let mut units = Units { len: 3, list: ul, alpha: Alpha::default() };
+    units.alpha.set_opt(a);
+    units.restrict();
+    $name { units }
+  }
+
+  /// Creates color from linear-light values
+  pub fn from_linear(r: f64, g: f64, b: f64, a: Option<f64>) -> $name {
+    $name::new($from_linear(r), $from_linear(g), $from_linear(b), a)
+  }
+
+  #[allow(dead_code)]
+  pub(crate) fn from_units(u: Units) -> Self { $name { units: u } }
+
+  /// Returns linear-light values
+  pub fn to_linear(&self) -> crate::ColorTuple {
+    let u = &self.units;
+    ($to_linear(u[0]), $to_linear(u[1]), $to_linear(u[2]))
+  }
+
+  fn _to_xyz_tuple(&self) -> crate::ColorTuple {
+    crate::converters::linear_to_xyz(&self.to_linear(), &$primaries, &$white)
+  }
+
+  /// Checks whether the color can be represented in sRGB without clipping
+  pub fn in_srgb_gamut(&self) -> bool {
+    let d65 = crate::xyz::adapt(&self._to_xyz_tuple(), &$white, &crate::WhitePoint::D65);
+    crate::converters::in_unit_cube(&crate::converters::xyz_to_linear_rgb(&d65))
+  }
+
+  pub fn red(&self) -> f64 { self.units[0] }
+  pub fn green(&self) -> f64 { self.units[1] }
+  pub fn blue(&self) -> f64 { self.units[2] }
+
+  pub fn set_red(&mut self, val: f64) { self.units.list[0].set(val); }
+  pub fn set_green(&mut self, val: f64) { self.units.list[1].set(val); }
+  pub fn set_blue(&mut self, val: f64) { self.units.list[2].set(val); }
+
+  /// Returns an iterator over three color units and the possibly alpha value.
+  pub fn iter(&self) -> ColorUnitsIter {
+    ColorUnitsIter::from_units(&self.units)
+  }
+}
+
+impl Default for $name {
+  fn default() -> $name {
+    $name::new(0.0, 0.0, 0.0, None)
+  }
+}
+
+impl AsRef<$name> for $name {
+  fn as_ref(&self) -> &$name {
+    self
+  }
+}
+
+impl crate::units::GetColorUnits for $name {
+  fn get_units(&self) -> &Units {
+    &self.units
+  }
+  fn get_units_mut(&mut self) -> &mut Units {
+    &mut self.units
+  }
+}
+
+from_for_some!(crate::Xyz, $name, xyz, {
+  let t: crate::ColorTuple = xyz.adapt($white).into();
+  let (r, g, b) = crate::converters::xyz_to_linear(&t, &$primaries, &$white);
+  let mut color = $name::from_linear(r, g, b, None);
+  color.units.alpha = xyz.units.alpha.clone();
+  color
+});
+from_for_some!($name, crate::Xyz, color, {
+  let (x, y, z) = color._to_xyz_tuple();
+  crate::Xyz::new_with_white(x, y, z, color.units.alpha.get(), $white)
+});
+
+from_for_some!(crate::Rgb, $name, rgb, { $name::from(crate::Xyz::from(rgb)) });
+from_for_some!($name, crate::Rgb, color, { crate::Rgb::from(crate::Xyz::from(color)) });
+
+};
+}
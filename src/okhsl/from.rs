@@ -0,0 +1,26 @@
+use crate::{ColorAlpha, Okhsv, Rgb};
+use crate::converters::{okhsl_to_rgb, rgb_to_okhsl};
+
+use super::{new_okhsl_units, Okhsl};
+
+tuples_def!(Okhsl, f32);
+tuples_def!(Okhsl, f64);
+
+fn from_rgb(rgb: &Rgb) -> Okhsl {
+  let (h, s, l) = rgb_to_okhsl(rgb);
+  let mut units = new_okhsl_units(h, s, l);
+  units.alpha.set(rgb.alpha());
+  units.restrict();
+  Okhsl::from_units(units)
+}
+
+fn to_rgb(okhsl: &Okhsl) -> Rgb {
+  let mut units = okhsl_to_rgb(&okhsl.into());
+  units.alpha.set(okhsl.alpha());
+  Rgb::from_units(units)
+}
+
+from_for_some!(Rgb, Okhsl, rgb, { from_rgb(rgb) });
+from_for_some!(Okhsl, Rgb, okhsl, { to_rgb(okhsl) });
+
+from_for_some!(Okhsv, Okhsl, okhsv, { from_rgb(&okhsv.into()) });
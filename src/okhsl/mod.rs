@@ -0,0 +1,102 @@
+use crate::ColorUnitsIter;
+use crate::units::{Alpha, GetColorUnits, Unit, Units};
+
+#[cfg(test)]
+mod tests;
+
+mod from;
+mod ops;
+mod transform;
+
+/// The Okhsl color space by Björn Ottosson: HSL built on top of `Oklab`.
+///
+/// Hue is the hue of `Oklab`, lightness is `Oklab` lightness remapped to be close to CIE L\*,
+/// and saturation is the chroma relative to the sRGB gamut boundary for given hue and lightness,
+/// so every combination of components is a valid sRGB color.
+/// Unlike `Hsl`, colors with the same lightness look equally light whatever hue is.
+///
+/// Ranges:
+/// * hue: 0.0 - 360.0
+/// * saturation: 0.0 - 100.0
+/// * lightness: 0.0 - 100.0
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{Okhsl, Rgb, prelude::*};
+///
+/// let mut okhsl = Okhsl::from(Rgb::from([255, 0, 0]));
+/// assert!(okhsl.approx_eq_clarify(&Okhsl::new(29.2339, 100.0, 56.8085, None), 0.0001));
+///
+/// okhsl.set_lightness(80.0);
+/// okhsl.set_saturation(50.0);
+/// assert_eq!(Rgb::from(&okhsl).to_css_string(), "rgb(233,186,178)");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Okhsl {
+  pub(crate) units: Units,
+}
+
+iter_def!(Okhsl);
+
+pub(crate) fn new_okhsl_units(h: f64, s: f64, l: f64) -> Units {
+  let ul = [Unit::new_hue(h), Unit::new_percent(s), Unit::new_percent(l), Unit::default()];
+  Units { len: 3, list: ul, alpha: Alpha::default() }
+}
+
+impl Okhsl {
+  pub fn new(h: f64, s: f64, l: f64, a: Option<f64>) -> Okhsl {
+    let mut units = new_okhsl_units(h, s, l);
+    units.alpha.set_opt(a);
+    units.restrict();
+    Okhsl { units }
+  }
+
+  pub(crate) fn from_units(u: Units) -> Self { Okhsl { units: u } }
+
+  pub fn hue(&self) -> f64 { self.units[0] }
+  pub fn saturation(&self) -> f64 { self.units[1] }
+  pub fn lightness(&self) -> f64 { self.units[2] }
+
+  pub fn set_hue(&mut self, val: f64) { self.units.list[0].set(val); }
+  pub fn set_saturation(&mut self, val: f64) { self.units.list[1].set(val); }
+  pub fn set_lightness(&mut self, val: f64) { self.units.list[2].set(val); }
+
+  /// Returns an iterator over three color units and the possibly alpha value.
+  pub fn iter(&self) -> ColorUnitsIter {
+    ColorUnitsIter::from_units(&self.units)
+  }
+}
+
+//
+//
+//
+// Default
+//
+impl Default for Okhsl {
+  fn default() -> Okhsl {
+    Okhsl::from_units(new_okhsl_units(0.0, 0.0, 0.0))
+  }
+}
+
+//
+//
+//
+// AsRef<Okhsl>
+//
+impl AsRef<Okhsl> for Okhsl {
+  fn as_ref(&self) -> &Okhsl {
+    self
+  }
+}
+
+
+impl GetColorUnits for Okhsl {
+  fn get_units(&self) -> &Units {
+    &self.units
+  }
+  fn get_units_mut(&mut self) -> &mut Units {
+    &mut self.units
+  }
+}
@@ -0,0 +1,77 @@
+use crate::{
+  ApproxEq, ColorAlpha, ColorTransform, ColorTuple, Okhsl, Okhsv, Rgb, SaturationInSpace,
+};
+
+#[test]
+fn okhsl_from_rgb() {
+  let asserts = [
+    ([255, 0, 0], (29.233_885, 100.0, 56.808_465)),
+    ([255, 255, 255], (0.0, 0.0, 100.0)),
+    ([0, 0, 0], (0.0, 0.0, 0.0)),
+    ([128, 128, 128], (0.0, 0.0, 53.570_646)),
+    ([34, 111, 235], (260.007_721, 94.018_692, 50.043_621)),
+  ];
+  for (rgb_arr, okhsl_t) in asserts.iter() {
+    let rgb = Rgb::from(rgb_arr);
+    let okhsl = Okhsl::from(&rgb);
+    assert!(okhsl.approx_eq_clarify(&Okhsl::from(okhsl_t), 0.0001));
+    assert!(Rgb::from(&okhsl).approx_eq_clarify(&rgb, 0.0001));
+  }
+}
+
+#[test]
+fn okhsl_is_in_gamut() {
+  for h in (5..360).step_by(15) {
+    for l in (5..100).step_by(10) {
+      let okhsl = Okhsl::new(h as f64, 100.0, l as f64, None);
+      let rgb = Rgb::from(&okhsl);
+      // the gamut boundary is approximated, the most saturated colors are slightly clipped
+      assert!(Okhsl::from(&rgb).approx_eq_clarify(&okhsl, 0.05));
+    }
+  }
+}
+
+#[test]
+fn okhsl_conversions() {
+  let okhsl = Okhsl::new(200.0, 40.0, 30.0, Some(0.5));
+  let okhsv = Okhsv::from(&okhsl);
+  assert_eq!(okhsv.alpha(), 0.5);
+  assert!(Okhsl::from(&okhsv).approx_eq_clarify(&okhsl, 0.0001));
+
+  let sum = Okhsl::new(10.0, 20.0, 30.0, None) + Okhsl::new(20.0, 30.0, 40.0, None);
+  assert_eq!(Into::<ColorTuple>::into(sum), (30.0, 50.0, 70.0));
+}
+
+#[test]
+fn okhsl_transform() {
+  let mut okhsl = Okhsl::new(40.0, 50.0, 50.0, None);
+  okhsl.lighten(-20.0);
+  okhsl.saturate(SaturationInSpace::Hsl(60.0));
+  assert_eq!(Into::<ColorTuple>::into(&okhsl), (40.0, 100.0, 30.0));
+
+  okhsl.adjust_hue(-60.0);
+  assert_eq!(okhsl.hue(), 340.0);
+
+  okhsl.invert();
+  assert_eq!(okhsl.hue(), 160.0);
+
+  okhsl.grayscale_simple();
+  assert_eq!(Into::<ColorTuple>::into(&okhsl), (0.0, 0.0, 30.0));
+
+  let mut okhsl = Okhsl::new(40.0, 50.0, 50.0, None);
+  okhsl.saturate(SaturationInSpace::Hsv(20.0));
+  let mut okhsv = Okhsv::from(Okhsl::new(40.0, 50.0, 50.0, None));
+  okhsv.saturate(SaturationInSpace::Hsv(20.0));
+  assert!(okhsl.approx_eq_clarify(&Rgb::from(&okhsv), 0.0001));
+}
+
+#[test]
+fn okhsl_iter() {
+  let okhsl = Okhsl::new(1.0, 2.0, 3.0, Some(0.5));
+  let mut it = okhsl.iter();
+  assert_eq!(it.next(), Some(1.0));
+  assert_eq!(it.next(), Some(2.0));
+  assert_eq!(it.next(), Some(3.0));
+  assert_eq!(it.next(), Some(0.5));
+  assert_eq!(it.next(), None);
+}
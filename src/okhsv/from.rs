@@ -0,0 +1,26 @@
+use crate::{ColorAlpha, Okhsl, Rgb};
+use crate::converters::{okhsv_to_rgb, rgb_to_okhsv};
+
+use super::{new_okhsv_units, Okhsv};
+
+tuples_def!(Okhsv, f32);
+tuples_def!(Okhsv, f64);
+
+fn from_rgb(rgb: &Rgb) -> Okhsv {
+  let (h, s, v) = rgb_to_okhsv(rgb);
+  let mut units = new_okhsv_units(h, s, v);
+  units.alpha.set(rgb.alpha());
+  units.restrict();
+  Okhsv::from_units(units)
+}
+
+fn to_rgb(okhsv: &Okhsv) -> Rgb {
+  let mut units = okhsv_to_rgb(&okhsv.into());
+  units.alpha.set(okhsv.alpha());
+  Rgb::from_units(units)
+}
+
+from_for_some!(Rgb, Okhsv, rgb, { from_rgb(rgb) });
+from_for_some!(Okhsv, Rgb, okhsv, { to_rgb(okhsv) });
+
+from_for_some!(Okhsl, Okhsv, okhsl, { from_rgb(&okhsl.into()) });
@@ -0,0 +1,100 @@
+use crate::ColorUnitsIter;
+use crate::units::{Alpha, GetColorUnits, Unit, Units};
+
+#[cfg(test)]
+mod tests;
+
+mod from;
+mod ops;
+mod transform;
+
+/// The Okhsv color space by Björn Ottosson: HSV built on top of `Oklab`.
+///
+/// Like in `Hsv`, value `100` with saturation `100` gives the most colorful sRGB color
+/// of the hue, and saturation `0` with value `100` gives white. Hue is the hue of `Oklab`
+/// and the sRGB gamut is fitted, so every combination of components is a valid sRGB color.
+///
+/// Ranges:
+/// * hue: 0.0 - 360.0
+/// * saturation: 0.0 - 100.0
+/// * value: 0.0 - 100.0
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{Okhsv, Rgb, prelude::*};
+///
+/// let mut okhsv = Okhsv::new(90.0, 100.0, 100.0, None);
+/// assert_eq!(Rgb::from(&okhsv).to_css_string(), "rgb(255,203,0)");
+///
+/// okhsv.saturate(SaturationInSpace::Hsv(-100.0));
+/// assert_eq!(Rgb::from(&okhsv).to_css_string(), "rgb(255,255,255)");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Okhsv {
+  pub(crate) units: Units,
+}
+
+iter_def!(Okhsv);
+
+pub(crate) fn new_okhsv_units(h: f64, s: f64, v: f64) -> Units {
+  let ul = [Unit::new_hue(h), Unit::new_percent(s), Unit::new_percent(v), Unit::default()];
+  Units { len: 3, list: ul, alpha: Alpha::default() }
+}
+
+impl Okhsv {
+  pub fn new(h: f64, s: f64, v: f64, a: Option<f64>) -> Okhsv {
+    let mut units = new_okhsv_units(h, s, v);
+    units.alpha.set_opt(a);
+    units.restrict();
+    Okhsv { units }
+  }
+
+  pub(crate) fn from_units(u: Units) -> Self { Okhsv { units: u } }
+
+  pub fn hue(&self) -> f64 { self.units[0] }
+  pub fn saturation(&self) -> f64 { self.units[1] }
+  pub fn value(&self) -> f64 { self.units[2] }
+
+  pub fn set_hue(&mut self, val: f64) { self.units.list[0].set(val); }
+  pub fn set_saturation(&mut self, val: f64) { self.units.list[1].set(val); }
+  pub fn set_value(&mut self, val: f64) { self.units.list[2].set(val); }
+
+  /// Returns an iterator over three color units and the possibly alpha value.
+  pub fn iter(&self) -> ColorUnitsIter {
+    ColorUnitsIter::from_units(&self.units)
+  }
+}
+
+//
+//
+//
+// Default
+//
+impl Default for Okhsv {
+  fn default() -> Okhsv {
+    Okhsv::from_units(new_okhsv_units(0.0, 0.0, 0.0))
+  }
+}
+
+//
+//
+//
+// AsRef<Okhsv>
+//
+impl AsRef<Okhsv> for Okhsv {
+  fn as_ref(&self) -> &Okhsv {
+    self
+  }
+}
+
+
+impl GetColorUnits for Okhsv {
+  fn get_units(&self) -> &Units {
+    &self.units
+  }
+  fn get_units_mut(&mut self) -> &mut Units {
+    &mut self.units
+  }
+}
@@ -0,0 +1,17 @@
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+
+use crate::{ApproxEq, DEFAULT_APPROX_EQ_PRECISION, Rgb};
+
+use super::Okhsv;
+
+ops_def!(Okhsv);
+
+impl ApproxEq<Rgb> for Okhsv {
+  fn approx_eq(&self, rgb: &Rgb) -> bool {
+    self.approx_eq_clarify(rgb, DEFAULT_APPROX_EQ_PRECISION)
+  }
+  fn approx_eq_clarify(&self, rgb: &Rgb, precision: f64) -> bool {
+    let okhsv_from_rgb: Okhsv = rgb.into();
+    self.units.approx_eq_clarify(&okhsv_from_rgb.units, precision)
+  }
+}
@@ -0,0 +1,53 @@
+use crate::{
+  ApproxEq, ColorTransform, ColorTuple, Okhsl, Okhsv, Rgb, SaturationInSpace,
+};
+
+#[test]
+fn okhsv_from_rgb() {
+  let asserts = [
+    ([255, 0, 0], (29.233_885, 99.952_197, 100.0)),
+    ([255, 255, 255], (0.0, 0.0, 100.0)),
+    ([0, 0, 0], (0.0, 0.0, 0.0)),
+    ([128, 128, 128], (0.0, 0.0, 53.570_646)),
+    ([34, 111, 235], (260.007_721, 91.136_459, 92.459_787)),
+  ];
+  for (rgb_arr, okhsv_t) in asserts.iter() {
+    let rgb = Rgb::from(rgb_arr);
+    let okhsv = Okhsv::from(&rgb);
+    assert!(okhsv.approx_eq_clarify(&Okhsv::from(okhsv_t), 0.0001));
+    assert!(Rgb::from(&okhsv).approx_eq_clarify(&rgb, 0.0001));
+  }
+}
+
+#[test]
+fn okhsv_is_in_gamut() {
+  for h in (5..360).step_by(15) {
+    for v in (5..100).step_by(10) {
+      let okhsv = Okhsv::new(h as f64, 90.0, v as f64, None);
+      let rgb = Rgb::from(&okhsv);
+      assert!(Okhsv::from(&rgb).approx_eq_clarify(&okhsv, 0.0001));
+    }
+  }
+}
+
+#[test]
+fn okhsv_transform() {
+  let mut okhsv = Okhsv::new(40.0, 50.0, 50.0, None);
+  okhsv.saturate(SaturationInSpace::Hsv(30.0));
+  assert_eq!(Into::<ColorTuple>::into(&okhsv), (40.0, 80.0, 50.0));
+
+  // lightness is changed in Okhsl
+  let mut okhsl = Okhsl::from(&okhsv);
+  okhsl.lighten(10.0);
+  okhsv.lighten(10.0);
+  assert!(okhsv.approx_eq_clarify(&Rgb::from(&okhsl), 0.0001));
+
+  okhsv.adjust_hue(-60.0);
+  assert_eq!(okhsv.hue(), 340.0);
+
+  okhsv.invert();
+  assert_eq!(okhsv.hue(), 160.0);
+
+  okhsv.grayscale_simple();
+  assert_eq!(okhsv.saturation(), 0.0);
+}
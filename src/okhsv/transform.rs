@@ -0,0 +1,47 @@
+use crate::{ColorTransform, Okhsl, SaturationInSpace};
+use crate::consts::{ALL_MIN, HUE_MAX};
+use crate::normalize::bound_hue;
+
+use super::Okhsv;
+
+impl Okhsv {
+  fn _apply_okhsl(&mut self, okhsl: &Okhsl) {
+    let okhsv = Okhsv::from(okhsl);
+    self.units.list[1].value = okhsv.units[1];
+    self.units.list[2].value = okhsv.units[2];
+  }
+}
+
+/// Works the same as for `Hsv`: lightness and `SaturationInSpace::Hsl` are changed in `Okhsl`.
+impl ColorTransform for Okhsv {
+  fn lighten(&mut self, amt: f64) {
+    let mut okhsl: Okhsl = self.as_ref().into();
+    okhsl.lighten(amt);
+    self._apply_okhsl(&okhsl);
+  }
+
+  fn saturate(&mut self, sat: SaturationInSpace) {
+    match sat {
+      SaturationInSpace::Hsv(s) => self.units.list[1].increase(s),
+      SaturationInSpace::Hsl(s) => {
+        let mut okhsl: Okhsl = self.as_ref().into();
+        okhsl.saturate(SaturationInSpace::Hsl(s));
+        self._apply_okhsl(&okhsl);
+      }
+    }
+  }
+
+  fn adjust_hue(&mut self, hue: f64) {
+    let h = bound_hue(self.units[0] + hue);
+    self.units.list[0].set(h);
+  }
+
+  fn grayscale_simple(&mut self) {
+    self.units.list[0].value = ALL_MIN;
+    self.units.list[1].value = ALL_MIN;
+  }
+  fn invert(&mut self) {
+    let h = (self.units[0] + HUE_MAX * 0.5) % HUE_MAX;
+    self.units.list[0].set(h);
+  }
+}
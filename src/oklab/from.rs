@@ -0,0 +1,33 @@
+use crate::{ColorAlpha, Hsl, Rgb, WhitePoint, Xyz};
+use crate::converters::{oklab_to_xyz, xyz_to_oklab};
+use crate::xyz::new_xyz_units;
+
+use super::{new_oklab_units, Oklab};
+
+tuples_def!(Oklab, f32);
+tuples_def!(Oklab, f64);
+
+fn from_xyz(xyz: &Xyz) -> Oklab {
+  let d65 = xyz.adapt(WhitePoint::D65);
+  let (l, a, b) = xyz_to_oklab(&d65.into());
+  let mut units = new_oklab_units(l, a, b);
+  units.alpha.set(xyz.alpha());
+  units.restrict();
+  Oklab::from_units(units)
+}
+
+fn to_xyz(oklab: &Oklab) -> Xyz {
+  let (x, y, z) = oklab_to_xyz(&oklab.into());
+  let mut units = new_xyz_units(x, y, z);
+  units.alpha.set(oklab.alpha());
+  Xyz::from_units(units, WhitePoint::D65)
+}
+
+from_for_some!(Xyz, Oklab, xyz, { from_xyz(xyz) });
+from_for_some!(Oklab, Xyz, oklab, { to_xyz(oklab) });
+
+from_for_some!(Rgb, Oklab, rgb, { from_xyz(&rgb.into()) });
+from_for_some!(Oklab, Rgb, oklab, { to_xyz(oklab).into() });
+
+from_for_some!(Hsl, Oklab, hsl, { Oklab::from(Rgb::from(hsl)) });
+from_for_some!(Oklab, Hsl, oklab, { Rgb::from(oklab).into() });
@@ -0,0 +1,150 @@
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+use crate::{ColorAlpha, ColorUnitsIter, ParseError};
+use crate::common::css_fn::{css_alpha, css_fn_from_str, css_fn_to_string, css_num_to_string, css_number};
+use crate::consts::RATIO_MAX;
+use crate::err::make_parse_err;
+use crate::units::{Alpha, GetColorUnits, Unit, Units};
+
+#[cfg(test)]
+mod tests;
+
+mod from;
+mod transform;
+
+/// Value of `a` and `b` axes which corresponds to `100%` in CSS `oklab()`
+pub(crate) const OKLAB_AB_PERCENT_REF: f64 = 0.4;
+
+/// The Oklab color space by Björn Ottosson.
+///
+/// Perceptual color space with better hue linearity than CIE L\*a\*b\*.
+/// Conversion from/to other color models is performed through `Xyz` (D65).
+///
+/// Ranges:
+/// * lightness: 0.0 - 1.0
+/// * a: not restricted, usually -0.4 - 0.4 (green - red)
+/// * b: not restricted, usually -0.4 - 0.4 (blue - yellow)
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{Oklab, Rgb, prelude::*};
+///
+/// let oklab = Oklab::from(Rgb::from([255, 0, 0]));
+/// assert_eq!(oklab.to_css_string(), "oklab(0.628 0.2249 0.1258)");
+///
+/// let mut oklab: Oklab = "oklab(40% 0.1 none / 0.5)".parse().unwrap();
+/// oklab.lighten(10.0);
+/// assert_eq!(oklab.to_css_string(), "oklab(0.5 0.1 0 / 0.5)");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Oklab {
+  pub(crate) units: Units,
+}
+
+iter_def!(Oklab);
+
+pub(crate) fn new_oklab_units(l: f64, a: f64, b: f64) -> Units {
+  let ul = [Unit::new_ratio(l), Unit::new_unbounded(a), Unit::new_unbounded(b), Unit::default()];
+  Units { len: 3, list: ul, alpha: Alpha::default() }
+}
+
+impl Oklab {
+  pub fn new(l: f64, a: f64, b: f64, alpha: Option<f64>) -> Oklab {
+    let mut units = new_oklab_units(l, a, b);
+    units.alpha.set_opt(alpha);
+    units.restrict();
+    Oklab { units }
+  }
+
+  pub(crate) fn from_units(u: Units) -> Self { Oklab { units: u } }
+
+  /// Returns a String that can be used in CSS.
+  /// # Example
+  /// ```
+  /// use colorsys::Oklab;
+  ///
+  /// let oklab = Oklab::new(0.62796, -0.02249, 0.1, Some(0.7));
+  /// assert_eq!(oklab.to_css_string(), "oklab(0.628 -0.0225 0.1 / 0.7)");
+  /// ```
+  pub fn to_css_string(&self) -> String {
+    let u = &self.units;
+    let components = [
+      css_num_to_string(u[0], 4),
+      css_num_to_string(u[1], 4),
+      css_num_to_string(u[2], 4),
+    ];
+    css_fn_to_string("oklab", &components, u.alpha.get_f64())
+  }
+
+  pub fn lightness(&self) -> f64 { self.units[0] }
+  pub fn a(&self) -> f64 { self.units[1] }
+  pub fn b(&self) -> f64 { self.units[2] }
+
+  pub fn set_lightness(&mut self, val: f64) { self.units.list[0].set(val); }
+  pub fn set_a(&mut self, val: f64) { self.units.list[1].set(val); }
+  pub fn set_b(&mut self, val: f64) { self.units.list[2].set(val); }
+
+  /// Returns an iterator over three color units and the possibly alpha value.
+  pub fn iter(&self) -> ColorUnitsIter {
+    ColorUnitsIter::from_units(&self.units)
+  }
+}
+
+//
+//
+//
+// Default
+//
+impl Default for Oklab {
+  fn default() -> Oklab {
+    Oklab::from_units(new_oklab_units(0.0, 0.0, 0.0))
+  }
+}
+
+//
+//
+//
+// AsRef<Oklab>
+//
+impl AsRef<Oklab> for Oklab {
+  fn as_ref(&self) -> &Oklab {
+    self
+  }
+}
+
+//
+//
+//
+// FromStr
+//
+impl core::str::FromStr for Oklab {
+  type Err = ParseError;
+  /// Parses CSS `oklab(..)` string. Percentages and `none` keyword are allowed for all components.
+  fn from_str(s: &str) -> Result<Oklab, ParseError> {
+    let make_err = || make_parse_err(s, "oklab");
+    let (c, alpha) = css_fn_from_str(s, &["oklab"], 3).ok_or_else(make_err)?;
+
+    let l = css_number(&c[0], RATIO_MAX).ok_or_else(make_err)?;
+    let a = css_number(&c[1], OKLAB_AB_PERCENT_REF).ok_or_else(make_err)?;
+    let b = css_number(&c[2], OKLAB_AB_PERCENT_REF).ok_or_else(make_err)?;
+
+    let mut oklab = Oklab::new(l, a, b, None);
+    if let Some(a) = alpha {
+      oklab.set_alpha(css_alpha(&a).ok_or_else(make_err)?);
+    }
+    Ok(oklab)
+  }
+}
+
+
+impl GetColorUnits for Oklab {
+  fn get_units(&self) -> &Units {
+    &self.units
+  }
+  fn get_units_mut(&mut self) -> &mut Units {
+    &mut self.units
+  }
+}
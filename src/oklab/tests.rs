@@ -0,0 +1,75 @@
+use crate::{
+  ApproxEq, ColorTransform, ColorTuple, Hsl, Oklab, Oklch, ParseError, Rgb,
+  SaturationInSpace, WhitePoint, Xyz,
+};
+
+#[test]
+fn oklab_from_rgb() {
+  let asserts = [
+    ([255, 0, 0], (0.627_955, 0.224_863, 0.125_846)),
+    ([255, 255, 255], (1.0, 0.0, 0.0)),
+    ([0, 0, 0], (0.0, 0.0, 0.0)),
+    ([34, 111, 235], (0.569_218, -0.035_002, -0.198_664)),
+    ([0, 255, 0], (0.866_440, -0.233_888, 0.179_498)),
+  ];
+  for (rgb_arr, oklab_t) in asserts.iter() {
+    let rgb = Rgb::from(rgb_arr);
+    let oklab = Oklab::from(&rgb);
+    assert!(oklab.approx_eq_clarify(&Oklab::from(oklab_t), 0.000_001));
+    assert!(Rgb::from(&oklab).approx_eq_clarify(&rgb, 0.000_001));
+  }
+}
+
+#[test]
+fn oklab_conversions() {
+  let hsl = Hsl::new(200.0, 40.0, 30.0, Some(0.5));
+  let oklab = Oklab::from(&hsl);
+  assert!(Hsl::from(&oklab).approx_eq_clarify(&hsl, 0.000_001));
+
+  let xyz = Xyz::from(&oklab);
+  assert!(Oklab::from(&xyz).approx_eq(&oklab));
+  let d50 = xyz.adapt(WhitePoint::D50);
+  assert!(Oklab::from(&d50).approx_eq(&oklab));
+}
+
+#[test]
+fn from_str_tst() {
+  fn parse_oklab(s: &str) -> Result<Oklab, ParseError> {
+    s.parse::<Oklab>()
+  }
+
+  assert_eq!(
+    Into::<ColorTuple>::into(parse_oklab("oklab(0.4 0.1 -0.05)").unwrap()),
+    (0.4, 0.1, -0.05)
+  );
+  let oklab = parse_oklab("OKLAB(59.69% 25% -50% / 25%)").unwrap();
+  assert!(oklab.approx_eq(&Oklab::new(0.5969, 0.1, -0.2, Some(0.25))));
+  assert_eq!(parse_oklab("oklab(none none none)").unwrap(), Oklab::default());
+  assert_eq!(parse_oklab("oklab(1.2 0 0)").unwrap(), Oklab::new(1.0, 0.0, 0.0, None));
+
+  assert!(parse_oklab("oklab(0.5 0.1)").is_err());
+  assert!(parse_oklab("oklab(0.5 0.1 0.1 / x)").is_err());
+  assert!(parse_oklab("lab(50 10 10)").is_err());
+
+  let oklab = Oklab::new(0.5, -0.000_01, 0.123_456, None);
+  assert_eq!(oklab.to_css_string(), "oklab(0.5 0 0.1235)");
+}
+
+#[test]
+fn oklab_transform() {
+  let mut oklab = Oklab::new(0.5, 0.03, 0.04, None);
+  oklab.lighten(-20.0);
+  assert!((oklab.lightness() - 0.3).abs() < 1e-12);
+
+  oklab.saturate(SaturationInSpace::Hsl(5.0));
+  assert!(Oklch::from(&oklab).approx_eq(&Oklch::new(0.3, 0.07, 53.130_102_354_155_98, None)));
+
+  oklab.adjust_hue(180.0);
+  assert!(oklab.approx_eq(&Oklab::new(0.3, -0.042, -0.056, None)));
+
+  oklab.invert();
+  assert!(oklab.approx_eq(&Oklab::new(0.7, 0.042, 0.056, None)));
+
+  oklab.grayscale_simple();
+  assert!(oklab.approx_eq(&Oklab::new(0.7, 0.0, 0.0, None)));
+}
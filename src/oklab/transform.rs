@@ -0,0 +1,42 @@
+use crate::{ColorTransform, Oklch, SaturationInSpace};
+use crate::consts::{ALL_MIN, PERCENT_MAX, RATIO_MAX};
+
+use super::Oklab;
+
+impl Oklab {
+  fn _transform_as_oklch<F: FnOnce(&mut Oklch)>(&mut self, f: F) {
+    let mut oklch = Oklch::from(&*self);
+    f(&mut oklch);
+    *self = Oklab::from(oklch);
+  }
+}
+
+/// Modifications are performed in perceptual terms:
+/// `lighten` changes L, `saturate` changes chroma and `adjust_hue` rotates hue
+/// (see `Oklch` for details).
+impl ColorTransform for Oklab {
+  /// Changes lightness. Amt is percent - `-100..100`, so `10.0` adds `0.1` to L
+  fn lighten(&mut self, amt: f64) {
+    self.units.list[0].increase(amt / PERCENT_MAX);
+  }
+
+  fn saturate(&mut self, sat: SaturationInSpace) {
+    self._transform_as_oklch(|oklch| oklch.saturate(sat));
+  }
+
+  fn adjust_hue(&mut self, hue: f64) {
+    self._transform_as_oklch(|oklch| oklch.adjust_hue(hue));
+  }
+
+  fn grayscale_simple(&mut self) {
+    self.units.list[1].value = ALL_MIN;
+    self.units.list[2].value = ALL_MIN;
+  }
+
+  /// Inverts lightness and opposes both `a` and `b` axes
+  fn invert(&mut self) {
+    self.units.list[0].set(RATIO_MAX - self.units[0]);
+    self.units.list[1].value = -self.units[1];
+    self.units.list[2].value = -self.units[2];
+  }
+}
@@ -0,0 +1,34 @@
+use crate::{ColorAlpha, Hsl, Oklab, Rgb, Xyz};
+use crate::converters::{polar_to_rect, rect_to_polar};
+use crate::oklab::new_oklab_units;
+
+use super::{new_oklch_units, Oklch};
+
+tuples_def!(Oklch, f32);
+tuples_def!(Oklch, f64);
+
+fn from_oklab(oklab: &Oklab) -> Oklch {
+  let (l, c, h) = rect_to_polar(&oklab.into());
+  let mut units = new_oklch_units(l, c, h);
+  units.alpha.set(oklab.alpha());
+  Oklch::from_units(units)
+}
+
+fn to_oklab(oklch: &Oklch) -> Oklab {
+  let (l, a, b) = polar_to_rect(&oklch.into());
+  let mut units = new_oklab_units(l, a, b);
+  units.alpha.set(oklch.alpha());
+  Oklab::from_units(units)
+}
+
+from_for_some!(Oklab, Oklch, oklab, { from_oklab(oklab) });
+from_for_some!(Oklch, Oklab, oklch, { to_oklab(oklch) });
+
+from_for_some!(Xyz, Oklch, xyz, { from_oklab(&xyz.into()) });
+from_for_some!(Oklch, Xyz, oklch, { to_oklab(oklch).into() });
+
+from_for_some!(Rgb, Oklch, rgb, { from_oklab(&rgb.into()) });
+from_for_some!(Oklch, Rgb, oklch, { to_oklab(oklch).into() });
+
+from_for_some!(Hsl, Oklch, hsl, { from_oklab(&hsl.into()) });
+from_for_some!(Oklch, Hsl, oklch, { to_oklab(oklch).into() });
@@ -0,0 +1,154 @@
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+use crate::{ColorAlpha, ColorUnitsIter, ParseError};
+use crate::common::css_fn::{css_alpha, css_fn_from_str, css_fn_to_string, css_hue, css_num_to_string, css_number};
+use crate::consts::{ALL_MIN, RATIO_MAX};
+use crate::err::make_parse_err;
+use crate::units::{Alpha, GetColorUnits, Unit, Units};
+
+#[cfg(test)]
+mod tests;
+
+mod from;
+mod transform;
+
+/// Value of chroma which corresponds to `100%` in CSS `oklch()`
+pub(crate) const OKLCH_CHROMA_PERCENT_REF: f64 = 0.4;
+
+/// Cylindrical form of `Oklab`.
+///
+/// Ranges:
+/// * lightness: 0.0 - 1.0
+/// * chroma: 0.0 - not restricted, usually up to 0.4
+/// * hue: 0.0 - 360.0
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{Oklch, Rgb, prelude::*};
+///
+/// let mut oklch: Oklch = "oklch(62.8% 0.2577 29.23)".parse().unwrap();
+/// oklch.adjust_hue(180.0);
+/// oklch.saturate(SaturationInSpace::Hsl(-25.0));
+/// assert_eq!(oklch.to_css_string(), "oklch(0.628 0.1577 209.23)");
+///
+/// let rgb = Rgb::from(&oklch);
+/// assert_eq!(rgb.to_css_string(), "rgb(0,160,187)");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Oklch {
+  pub(crate) units: Units,
+}
+
+iter_def!(Oklch);
+
+pub(crate) fn new_oklch_units(l: f64, c: f64, h: f64) -> Units {
+  let ul = [
+    Unit::new_ratio(l),
+    Unit::new_unbounded(c.max(ALL_MIN)),
+    Unit::new_hue(h),
+    Unit::default(),
+  ];
+  Units { len: 3, list: ul, alpha: Alpha::default() }
+}
+
+impl Oklch {
+  pub fn new(l: f64, c: f64, h: f64, a: Option<f64>) -> Oklch {
+    let mut units = new_oklch_units(l, c, h);
+    units.alpha.set_opt(a);
+    units.restrict();
+    Oklch { units }
+  }
+
+  pub(crate) fn from_units(u: Units) -> Self { Oklch { units: u } }
+
+  /// Returns a String that can be used in CSS.
+  /// # Example
+  /// ```
+  /// use colorsys::Oklch;
+  ///
+  /// let oklch = Oklch::new(0.70023, 0.1, 120.0, Some(0.5));
+  /// assert_eq!(oklch.to_css_string(), "oklch(0.7002 0.1 120 / 0.5)");
+  /// ```
+  pub fn to_css_string(&self) -> String {
+    let u = &self.units;
+    let components = [
+      css_num_to_string(u[0], 4),
+      css_num_to_string(u[1], 4),
+      css_num_to_string(u[2], 2),
+    ];
+    css_fn_to_string("oklch", &components, u.alpha.get_f64())
+  }
+
+  pub fn lightness(&self) -> f64 { self.units[0] }
+  pub fn chroma(&self) -> f64 { self.units[1] }
+  pub fn hue(&self) -> f64 { self.units[2] }
+
+  pub fn set_lightness(&mut self, val: f64) { self.units.list[0].set(val); }
+  pub fn set_chroma(&mut self, val: f64) { self.units.list[1].set(val.max(ALL_MIN)); }
+  pub fn set_hue(&mut self, val: f64) { self.units.list[2].set(val); }
+
+  /// Returns an iterator over three color units and the possibly alpha value.
+  pub fn iter(&self) -> ColorUnitsIter {
+    ColorUnitsIter::from_units(&self.units)
+  }
+}
+
+//
+//
+//
+// Default
+//
+impl Default for Oklch {
+  fn default() -> Oklch {
+    Oklch::from_units(new_oklch_units(0.0, 0.0, 0.0))
+  }
+}
+
+//
+//
+//
+// AsRef<Oklch>
+//
+impl AsRef<Oklch> for Oklch {
+  fn as_ref(&self) -> &Oklch {
+    self
+  }
+}
+
+//
+//
+//
+// FromStr
+//
+impl core::str::FromStr for Oklch {
+  type Err = ParseError;
+  /// Parses CSS `oklch(..)` string. Percentages and `none` keyword are allowed,
+  /// hue can be specified with angle units (`deg`, `rad`, etc.)
+  fn from_str(s: &str) -> Result<Oklch, ParseError> {
+    let make_err = || make_parse_err(s, "oklch");
+    let (c, alpha) = css_fn_from_str(s, &["oklch"], 3).ok_or_else(make_err)?;
+
+    let l = css_number(&c[0], RATIO_MAX).ok_or_else(make_err)?;
+    let ch = css_number(&c[1], OKLCH_CHROMA_PERCENT_REF).ok_or_else(make_err)?;
+    let h = css_hue(&c[2]).ok_or_else(make_err)?;
+
+    let mut oklch = Oklch::new(l, ch, h, None);
+    if let Some(a) = alpha {
+      oklch.set_alpha(css_alpha(&a).ok_or_else(make_err)?);
+    }
+    Ok(oklch)
+  }
+}
+
+
+impl GetColorUnits for Oklch {
+  fn get_units(&self) -> &Units {
+    &self.units
+  }
+  fn get_units_mut(&mut self) -> &mut Units {
+    &mut self.units
+  }
+}
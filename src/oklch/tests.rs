@@ -0,0 +1,76 @@
+use crate::{
+  ApproxEq, ColorTransform, ColorTuple, ColorTupleA, Hsl, Oklab, Oklch, ParseError,
+  Rgb, SaturationInSpace,
+};
+
+#[test]
+fn oklch_from_rgb() {
+  let asserts = [
+    ([255, 0, 0], (0.627_955, 0.257_683, 29.233_880)),
+    ([255, 255, 255], (1.0, 0.0, 0.0)),
+    ([128, 128, 128], (0.599_871, 0.0, 0.0)),
+    ([34, 111, 235], (0.569_218, 0.201_724, 260.007_723)),
+    ([255, 255, 0], (0.967_983, 0.211_006, 109.769_236)),
+  ];
+  for (rgb_arr, oklch_t) in asserts.iter() {
+    let rgb = Rgb::from(rgb_arr);
+    let oklch = Oklch::from(&rgb);
+    assert!(oklch.approx_eq_clarify(&Oklch::from(oklch_t), 0.000_001));
+    assert!(Rgb::from(&oklch).approx_eq_clarify(&rgb, 0.000_001));
+  }
+}
+
+#[test]
+fn oklch_oklab() {
+  let oklab = Oklab::new(0.5, -0.03, -0.04, Some(0.1));
+  let oklch = Oklch::from(&oklab);
+  assert!(oklch.approx_eq(&Oklch::new(0.5, 0.05, 233.130_102_354_155_98, Some(0.1))));
+  assert!(Oklab::from(&oklch).approx_eq(&oklab));
+
+  let hsl = Hsl::from((300.0, 70.0, 40.0));
+  assert!(Hsl::from(Oklch::from(&hsl)).approx_eq_clarify(&hsl, 0.000_001));
+}
+
+#[test]
+fn from_str_tst() {
+  fn parse_oklch(s: &str) -> Result<Oklch, ParseError> {
+    s.parse::<Oklch>()
+  }
+
+  assert_eq!(
+    Into::<ColorTuple>::into(parse_oklch("oklch(0.5 0.2 27)").unwrap()),
+    (0.5, 0.2, 27.0)
+  );
+  assert_eq!(
+    Into::<ColorTupleA>::into(parse_oklch("oklch(50% 50% 0.5turn / 0.3)").unwrap()),
+    (0.5, 0.2, 180.0, 0.3)
+  );
+  assert_eq!(parse_oklch("oklch(0.5 0.1 none)").unwrap(), Oklch::new(0.5, 0.1, 0.0, None));
+  assert_eq!(parse_oklch("oklch(0.5 -0.1 -90deg)").unwrap(), Oklch::new(0.5, 0.0, 270.0, None));
+  assert!(parse_oklch("oklch(0.5 0.1 10deg 5)").is_err());
+  assert!(parse_oklch("oklch(0.5 0.1 10px)").is_err());
+
+  let oklch = Oklch::new(0.5, 0.3, 359.996, Some(0.25));
+  assert_eq!(oklch.to_css_string(), "oklch(0.5 0.3 360 / 0.25)");
+}
+
+#[test]
+fn oklch_transform() {
+  let mut oklch = Oklch::new(0.5, 0.1, 40.0, None);
+  oklch.lighten(60.0);
+  assert_eq!(oklch.lightness(), 1.0);
+
+  oklch.saturate(SaturationInSpace::Hsv(-30.0));
+  assert_eq!(oklch.chroma(), 0.0);
+  oklch.saturate(SaturationInSpace::Hsl(25.0));
+  assert_eq!(oklch.chroma(), 0.1);
+
+  oklch.adjust_hue(-60.0);
+  assert_eq!(oklch.hue(), 340.0);
+
+  oklch.invert();
+  assert_eq!(Into::<ColorTuple>::into(&oklch), (0.0, 0.1, 160.0));
+
+  oklch.grayscale_simple();
+  assert_eq!(Into::<ColorTuple>::into(&oklch), (0.0, 0.0, 0.0));
+}
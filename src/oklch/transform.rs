@@ -0,0 +1,38 @@
+use crate::{ColorTransform, SaturationInSpace};
+use crate::consts::{ALL_MIN, HUE_MAX, PERCENT_MAX, RATIO_MAX};
+use crate::normalize::bound_hue;
+
+use super::{Oklch, OKLCH_CHROMA_PERCENT_REF};
+
+impl ColorTransform for Oklch {
+  /// Changes lightness. Amt is percent - `-100..100`, so `10.0` adds `0.1` to L
+  fn lighten(&mut self, amt: f64) {
+    self.units.list[0].increase(amt / PERCENT_MAX);
+  }
+
+  /// Changes chroma. Value is percent of chroma `0.4` (as `100%` in CSS `oklch()`),
+  /// so `10.0` increases chroma by `0.04`. Both variants of `SaturationInSpace` are treated the same.
+  fn saturate(&mut self, sat: SaturationInSpace) {
+    let amt = match sat {
+      SaturationInSpace::Hsl(s) | SaturationInSpace::Hsv(s) => s,
+    };
+    self.set_chroma(self.units[1] + amt * OKLCH_CHROMA_PERCENT_REF / PERCENT_MAX);
+  }
+
+  fn adjust_hue(&mut self, hue: f64) {
+    let h = bound_hue(self.units[2] + hue);
+    self.units.list[2].set(h);
+  }
+
+  fn grayscale_simple(&mut self) {
+    self.units.list[1].value = ALL_MIN;
+    self.units.list[2].value = ALL_MIN;
+  }
+
+  /// Inverts lightness and rotates hue by 180 degrees
+  fn invert(&mut self) {
+    self.units.list[0].set(RATIO_MAX - self.units[0]);
+    let h = (self.units[2] + HUE_MAX * 0.5) % HUE_MAX;
+    self.units.list[2].set(h);
+  }
+}
@@ -0,0 +1,55 @@
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+use crate::{Cmyk, ColorUnitsIter, Rgb, WhitePoint};
+use crate::converters::PROPHOTO_PRIMARIES;
+use crate::transfer::{linear_to_prophoto, prophoto_to_linear};
+use crate::units::Units;
+
+#[cfg(test)]
+mod tests;
+
+/// The ProPhoto RGB (ROMM RGB) color space.
+///
+/// Very wide-gamut RGB space of raw photo processing, with `D50` white and 1.8 gamma.
+/// Values are gamma-encoded ratios, linear-light values can be got with `to_linear`
+/// and `from_linear` methods. Colors of other models are chromatically adapted
+/// from their white (`D65` for `Rgb`) with the Bradford transform.
+///
+/// Conversion to `Rgb` and `Cmyk` clips colors which are out of sRGB gamut,
+/// use `in_srgb_gamut` to check it before.
+///
+/// Ranges:
+/// * red: 0.0 - 1.0
+/// * green: 0.0 - 1.0
+/// * blue: 0.0 - 1.0
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{ProPhotoRgb, Rgb, WhitePoint, Xyz};
+///
+/// let prophoto = ProPhotoRgb::from(Rgb::from([255, 255, 255]));
+/// assert_eq!(prophoto.to_css_string(), "color(prophoto-rgb 1 1 1)");
+/// assert_eq!(Xyz::from(&prophoto).white_point(), WhitePoint::D50);
+///
+/// let red = ProPhotoRgb::from(Rgb::from([255, 0, 0]));
+/// assert_eq!(red.to_css_string(), "color(prophoto-rgb 0.7022 0.2757 0.1035)");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProPhotoRgb {
+  pub(crate) units: Units,
+}
+
+rgb_space_def!(
+  ProPhotoRgb,
+  "prophoto-rgb",
+  PROPHOTO_PRIMARIES,
+  WhitePoint::D50,
+  prophoto_to_linear,
+  linear_to_prophoto
+);
+
+from_for_some!(Cmyk, ProPhotoRgb, cmyk, { ProPhotoRgb::from(Rgb::from(cmyk)) });
+from_for_some!(ProPhotoRgb, Cmyk, color, { Cmyk::from(Rgb::from(color)) });
@@ -0,0 +1,60 @@
+use crate::{ApproxEq, Cmyk, ColorTupleA, ParseError, ProPhotoRgb, Rgb, WhitePoint, Xyz};
+
+#[test]
+fn prophoto_rgb_from_rgb() {
+  let asserts = [
+    ([255, 0, 0], (0.702_248, 0.275_721, 0.103_548)),
+    ([0, 255, 0], (0.540_282, 0.927_599, 0.304_566)),
+    ([255, 255, 255], (1.0, 1.0, 1.0)),
+    ([0, 0, 0], (0.0, 0.0, 0.0)),
+    ([34, 111, 235], (0.382_999, 0.366_045, 0.844_676)),
+  ];
+  for (rgb_arr, prophoto_t) in asserts.iter() {
+    let rgb = Rgb::from(rgb_arr);
+    let prophoto = ProPhotoRgb::from(&rgb);
+    assert!(prophoto.approx_eq_clarify(&ProPhotoRgb::from(prophoto_t), 0.000_001));
+    assert!(prophoto.in_srgb_gamut());
+    assert!(Rgb::from(&prophoto).approx_eq_clarify(&rgb, 0.000_001));
+  }
+}
+
+#[test]
+fn prophoto_rgb_conversions() {
+  let white = Xyz::from(ProPhotoRgb::new(1.0, 1.0, 1.0, None));
+  assert_eq!(white.white_point(), WhitePoint::D50);
+  let (x, y, z) = WhitePoint::D50.xyz();
+  assert!(white.approx_eq(&Xyz::new_with_white(x, y, z, None, WhitePoint::D50)));
+
+  // XYZ relative to other white is adapted to D50
+  let xyz = Xyz::new(0.2, 0.3, 0.4, Some(0.5));
+  let prophoto = ProPhotoRgb::from(&xyz);
+  assert!(prophoto.approx_eq(&ProPhotoRgb::from(xyz.adapt(WhitePoint::D50))));
+  assert!(Xyz::from(&prophoto).adapt(WhitePoint::D65).approx_eq(&xyz));
+
+  let blue = ProPhotoRgb::new(0.0, 0.0, 1.0, None);
+  assert!(!blue.in_srgb_gamut());
+
+  let cmyk = Cmyk::new(0.0, 30.0, 80.0, 5.0, Some(0.7));
+  let prophoto = ProPhotoRgb::from(&cmyk);
+  assert!(Rgb::from(&prophoto).approx_eq(&Rgb::from(&cmyk)));
+  assert!(Rgb::from(Cmyk::from(&prophoto)).approx_eq(&Rgb::from(&cmyk)));
+}
+
+#[test]
+fn from_str_tst() {
+  fn parse_prophoto(s: &str) -> Result<ProPhotoRgb, ParseError> {
+    s.parse::<ProPhotoRgb>()
+  }
+
+  assert_eq!(
+    Into::<ColorTupleA>::into(parse_prophoto("color(prophoto-rgb 0.1 20% 1)").unwrap()),
+    (0.1, 0.2, 1.0, 1.0)
+  );
+  assert!(parse_prophoto("color(a98-rgb 0.1 0.2 0.3)").is_err());
+  assert!(parse_prophoto("prophoto-rgb(0.1 0.2 0.3)").is_err());
+
+  assert_eq!(
+    ProPhotoRgb::new(0.1, 0.2, 1.0, Some(0.25)).to_css_string(),
+    "color(prophoto-rgb 0.1 0.2 1 / 0.25)"
+  );
+}
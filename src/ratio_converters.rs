@@ -1,4 +1,4 @@
-use super::consts::{HUE_MAX, PERCENT_MAX, RGB_UNIT_MAX};
+use super::consts::{ALL_MIN, HUE_MAX, PERCENT_MAX, RGB_UNIT_MAX};
 use super::normalize::{
   bound_hue, bound_ratio, normalize_percent, normalize_ratio,
   normalize_rgb_unit,
@@ -21,6 +21,15 @@ fn to_hue(n: f64) -> f64 {
   bound_ratio(n) * HUE_MAX
 }
 
+// saturation of HPLuv is not restricted
+fn unbounded_per_to(n: f64) -> f64 {
+  n.max(ALL_MIN) / PERCENT_MAX
+}
+
+fn to_unbounded_per(n: f64) -> f64 {
+  n.max(ALL_MIN) * PERCENT_MAX
+}
+
 fn per_to(n: f64) -> f64 {
   normalize_ratio(n / PERCENT_MAX)
 }
@@ -65,6 +74,62 @@ pub fn hsv_to_ratio(t: &ColorTuple) -> ColorTuple {
   hsl_to_ratio(t)
 }
 
+pub fn hsva_to_ratio(t: &ColorTupleA) -> ColorTupleA {
+  hsla_to_ratio(t)
+}
+
 pub fn ratio_to_hsv(t: &ColorTuple) -> ColorTuple {
   ratio_to_hsl(t)
 }
+
+pub fn ratio_to_hsva(t: &ColorTupleA) -> ColorTupleA {
+  ratio_to_hsla(t)
+}
+
+pub fn hwb_to_ratio(t: &ColorTuple) -> ColorTuple {
+  hsl_to_ratio(t)
+}
+
+pub fn hwba_to_ratio(t: &ColorTupleA) -> ColorTupleA {
+  hsla_to_ratio(t)
+}
+
+pub fn ratio_to_hwb(t: &ColorTuple) -> ColorTuple {
+  ratio_to_hsl(t)
+}
+
+pub fn ratio_to_hwba(t: &ColorTupleA) -> ColorTupleA {
+  ratio_to_hsla(t)
+}
+
+pub fn hsluv_to_ratio(t: &ColorTuple) -> ColorTuple {
+  hsl_to_ratio(t)
+}
+
+pub fn hsluva_to_ratio(t: &ColorTupleA) -> ColorTupleA {
+  hsla_to_ratio(t)
+}
+
+pub fn ratio_to_hsluv(t: &ColorTuple) -> ColorTuple {
+  ratio_to_hsl(t)
+}
+
+pub fn ratio_to_hsluva(t: &ColorTupleA) -> ColorTupleA {
+  ratio_to_hsla(t)
+}
+
+pub fn hpluv_to_ratio(t: &ColorTuple) -> ColorTuple {
+  (hue_to(t.0), unbounded_per_to(t.1), per_to(t.2))
+}
+
+pub fn hpluva_to_ratio(t: &ColorTupleA) -> ColorTupleA {
+  (hue_to(t.0), unbounded_per_to(t.1), per_to(t.2), normalize_ratio(t.3))
+}
+
+pub fn ratio_to_hpluv(t: &ColorTuple) -> ColorTuple {
+  (to_hue(t.0), to_unbounded_per(t.1), to_per(t.2))
+}
+
+pub fn ratio_to_hpluva(t: &ColorTupleA) -> ColorTupleA {
+  (to_hue(t.0), to_unbounded_per(t.1), to_per(t.2), normalize_ratio(t.3))
+}
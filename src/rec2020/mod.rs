@@ -0,0 +1,45 @@
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+use crate::{ColorUnitsIter, WhitePoint};
+use crate::converters::REC2020_PRIMARIES;
+use crate::transfer::{linear_to_rec2020, rec2020_to_linear};
+use crate::units::Units;
+
+#[cfg(test)]
+mod tests;
+
+/// The ITU-R BT.2020 (Rec.2020) color space of UHDTV.
+///
+/// Very wide-gamut RGB space with `D65` white and the BT.2020 transfer function.
+/// Values are gamma-encoded ratios, linear-light values can be got with `to_linear`
+/// and `from_linear` methods. For HDR encodings with the same primaries see
+/// `Rec2100Pq` and `Rec2100Hlg`.
+///
+/// Conversion to `Rgb` clips colors which are out of sRGB gamut,
+/// use `in_srgb_gamut` to check it before.
+///
+/// Ranges:
+/// * red: 0.0 - 1.0
+/// * green: 0.0 - 1.0
+/// * blue: 0.0 - 1.0
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{Rec2020, Rgb};
+///
+/// let rec2020 = Rec2020::from(Rgb::from([255, 0, 0]));
+/// assert_eq!(rec2020.to_css_string(), "color(rec2020 0.792 0.231 0.0738)");
+///
+/// let green: Rec2020 = "color(rec2020 0 1 0 / 0.5)".parse().unwrap();
+/// assert!(!green.in_srgb_gamut());
+/// assert_eq!(Rgb::from(&green).to_css_string(), "rgba(0,255,0,0.5)");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rec2020 {
+  pub(crate) units: Units,
+}
+
+rgb_space_def!(Rec2020, "rec2020", REC2020_PRIMARIES, WhitePoint::D65, rec2020_to_linear, linear_to_rec2020);
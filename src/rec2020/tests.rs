@@ -0,0 +1,49 @@
+use crate::{ApproxEq, ColorTupleA, DisplayP3, ParseError, Rec2020, Rgb, WhitePoint, Xyz};
+
+#[test]
+fn rec2020_from_rgb() {
+  let asserts = [
+    ([255, 0, 0], (0.791_977, 0.230_976, 0.073_761)),
+    ([255, 255, 255], (1.0, 1.0, 1.0)),
+    ([0, 0, 0], (0.0, 0.0, 0.0)),
+    ([34, 111, 235], (0.287_861, 0.378_140, 0.871_302)),
+  ];
+  for (rgb_arr, rec_t) in asserts.iter() {
+    let rgb = Rgb::from(rgb_arr);
+    let rec2020 = Rec2020::from(&rgb);
+    assert!(rec2020.approx_eq_clarify(&Rec2020::from(rec_t), 0.000_001));
+    assert!(rec2020.in_srgb_gamut());
+    assert!(Rgb::from(&rec2020).approx_eq_clarify(&rgb, 0.000_001));
+  }
+}
+
+#[test]
+fn rec2020_conversions() {
+  let p3 = DisplayP3::new(1.0, 0.0, 0.5, Some(0.3));
+  let rec2020 = Rec2020::from(Xyz::from(&p3));
+  assert!(!rec2020.in_srgb_gamut());
+  assert!(DisplayP3::from(Xyz::from(&rec2020)).approx_eq(&p3));
+
+  let white = Xyz::from(Rec2020::new(1.0, 1.0, 1.0, None));
+  assert!(white.approx_eq(&Xyz::from(WhitePoint::D65.xyz())));
+
+  let (r, g, b) = rec2020.to_linear();
+  assert!(Rec2020::from_linear(r, g, b, Some(0.3)).approx_eq(&rec2020));
+}
+
+#[test]
+fn from_str_tst() {
+  fn parse_rec2020(s: &str) -> Result<Rec2020, ParseError> {
+    s.parse::<Rec2020>()
+  }
+
+  assert_eq!(
+    Into::<ColorTupleA>::into(parse_rec2020("color(rec2020 0.2 40% none / 0.5)").unwrap()),
+    (0.2, 0.4, 0.0, 0.5)
+  );
+  assert!(parse_rec2020("color(display-p3 0.2 0.4 0.6)").is_err());
+  assert!(parse_rec2020("rec2020(0.2 0.4 0.6)").is_err());
+
+  let rec2020 = Rec2020::new(0.123_45, 0.0, 1.0, None);
+  assert_eq!(rec2020.to_css_string(), "color(rec2020 0.1235 0 1)");
+}
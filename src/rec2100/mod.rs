@@ -0,0 +1,97 @@
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+use crate::{ColorUnitsIter, WhitePoint};
+use crate::converters::REC2020_PRIMARIES;
+use crate::transfer::{hlg_to_linear, linear_to_hlg, linear_to_pq, pq_to_linear, PQ_MAX_LUMINANCE};
+use crate::units::Units;
+
+#[cfg(test)]
+mod tests;
+
+/// Luminance (cd/m²) of HDR reference white (ITU-R BT.2408), it corresponds to `1.0` of
+/// linear values of `Rec2100Pq` and to white of `Rgb`
+pub const HDR_REFERENCE_WHITE: f64 = 203.0;
+
+// HLG signal of HDR reference white (ITU-R BT.2408)
+const HLG_REFERENCE_WHITE_SIGNAL: f64 = 0.75;
+
+pub(crate) fn pq_to_relative(c: f64) -> f64 {
+  pq_to_linear(c) * PQ_MAX_LUMINANCE / HDR_REFERENCE_WHITE
+}
+
+pub(crate) fn relative_to_pq(c: f64) -> f64 {
+  linear_to_pq(c * HDR_REFERENCE_WHITE / PQ_MAX_LUMINANCE)
+}
+
+pub(crate) fn hlg_to_relative(c: f64) -> f64 {
+  hlg_to_linear(c) / hlg_to_linear(HLG_REFERENCE_WHITE_SIGNAL)
+}
+
+pub(crate) fn relative_to_hlg(c: f64) -> f64 {
+  linear_to_hlg(c * hlg_to_linear(HLG_REFERENCE_WHITE_SIGNAL))
+}
+
+/// ITU-R BT.2100 color with the PQ (SMPTE ST 2084) transfer function.
+///
+/// Has the same primaries and white as `Rec2020`. Values are non-linear PQ signals.
+/// Linear-light values got with `to_linear` are relative to HDR reference white
+/// (`HDR_REFERENCE_WHITE` cd/m²), so values above `1.0` are HDR highlights.
+/// They are clipped on conversion to `Rgb`.
+///
+/// Ranges:
+/// * red: 0.0 - 1.0
+/// * green: 0.0 - 1.0
+/// * blue: 0.0 - 1.0
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{Rec2100Pq, Rgb};
+///
+/// let white = Rec2100Pq::from(Rgb::from([255, 255, 255]));
+/// assert_eq!(white.to_css_string(), "color(rec2100-pq 0.5807 0.5807 0.5807)");
+///
+/// // 1000 cd/m²
+/// let highlight = Rec2100Pq::new(0.7518, 0.7518, 0.7518, None);
+/// assert!((highlight.to_linear().0 - 4.925).abs() < 0.001);
+/// assert_eq!(Rgb::from(&highlight).to_css_string(), "rgb(255,255,255)");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rec2100Pq {
+  pub(crate) units: Units,
+}
+
+rgb_space_def!(Rec2100Pq, "rec2100-pq", REC2020_PRIMARIES, WhitePoint::D65, pq_to_relative, relative_to_pq);
+
+/// ITU-R BT.2100 color with the HLG (Hybrid Log-Gamma) transfer function.
+///
+/// Has the same primaries and white as `Rec2020`. Values are non-linear HLG signals.
+/// Linear-light values got with `to_linear` are scene light relative to HDR reference white
+/// (signal `0.75`), so values above `1.0` are HDR highlights. They are clipped on conversion
+/// to `Rgb`. The OOTF (system gamma of display) is not applied.
+///
+/// Ranges:
+/// * red: 0.0 - 1.0
+/// * green: 0.0 - 1.0
+/// * blue: 0.0 - 1.0
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{Rec2100Hlg, Rgb};
+///
+/// let white = Rec2100Hlg::from(Rgb::from([255, 255, 255]));
+/// assert_eq!(white.to_css_string(), "color(rec2100-hlg 0.75 0.75 0.75)");
+///
+/// let peak = Rec2100Hlg::new(1.0, 1.0, 1.0, None);
+/// assert!((peak.to_linear().0 - 3.774).abs() < 0.001);
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rec2100Hlg {
+  pub(crate) units: Units,
+}
+
+rgb_space_def!(Rec2100Hlg, "rec2100-hlg", REC2020_PRIMARIES, WhitePoint::D65, hlg_to_relative, relative_to_hlg);
@@ -0,0 +1,58 @@
+use crate::{ApproxEq, ParseError, Rec2020, Rec2100Hlg, Rec2100Pq, Rgb, Xyz};
+use crate::common::approx::approx;
+
+#[test]
+fn rec2100_pq() {
+  let rgb = Rgb::from([255, 0, 0]);
+  let pq = Rec2100Pq::from(&rgb);
+  assert!(pq.approx_eq_clarify(&Rec2100Pq::new(0.532_546, 0.327_023, 0.220_069, None), 0.000_001));
+  assert!(Rgb::from(&pq).approx_eq_clarify(&rgb, 0.000_001));
+
+  // same linear light as Rec.2020
+  let rec2020 = Rec2020::from(&rgb);
+  let (r, g, b) = pq.to_linear();
+  assert!(Rec2020::from_linear(r, g, b, None).approx_eq(&rec2020));
+
+  // 10000 cd/m²
+  let max = Rec2100Pq::new(1.0, 1.0, 1.0, None);
+  assert!(approx(Xyz::from(&max).y(), 10_000.0 / 203.0, 1e-9));
+  assert!(!max.in_srgb_gamut());
+  assert_eq!(Into::<[u8; 3]>::into(Rgb::from(&max)), [255, 255, 255]);
+
+  let hdr = Rec2100Pq::from_linear(4.0, 0.5, 0.0, Some(0.5));
+  assert!(approx(hdr.to_linear().0, 4.0, 1e-9));
+  // black level of PQ is not exactly zero
+  assert!(Rec2100Pq::from(Xyz::from(&hdr)).approx_eq_clarify(&hdr, 0.000_001));
+}
+
+#[test]
+fn rec2100_hlg() {
+  let rgb = Rgb::from([34, 111, 235]);
+  let hlg = Rec2100Hlg::from(&rgb);
+  assert!(Rgb::from(&hlg).approx_eq_clarify(&rgb, 0.000_001));
+
+  let white = Rec2100Hlg::from(Rgb::from([255, 255, 255]));
+  assert!(white.approx_eq(&Rec2100Hlg::new(0.75, 0.75, 0.75, None)));
+  assert_eq!(Rec2100Hlg::default().to_linear(), (0.0, 0.0, 0.0));
+
+  let hdr = Rec2100Hlg::new(0.9, 0.2, 0.1, None);
+  assert!(!hdr.in_srgb_gamut());
+  assert!(Rec2100Hlg::from(Xyz::from(&hdr)).approx_eq(&hdr));
+}
+
+#[test]
+fn from_str_tst() {
+  fn parse_pq(s: &str) -> Result<Rec2100Pq, ParseError> {
+    s.parse::<Rec2100Pq>()
+  }
+  fn parse_hlg(s: &str) -> Result<Rec2100Hlg, ParseError> {
+    s.parse::<Rec2100Hlg>()
+  }
+
+  assert_eq!(parse_pq("color(rec2100-pq 0.5 0.5 0.5)").unwrap(), Rec2100Pq::new(0.5, 0.5, 0.5, None));
+  assert_eq!(parse_hlg("color(rec2100-hlg 75% 75% 75% / 0.1)").unwrap(), Rec2100Hlg::new(0.75, 0.75, 0.75, Some(0.1)));
+  assert!(parse_pq("color(rec2100-hlg 0.5 0.5 0.5)").is_err());
+  assert!(parse_hlg("color(rec2020 0.5 0.5 0.5)").is_err());
+
+  assert_eq!(Rec2100Hlg::new(0.75, 0.5, 0.0, Some(0.3)).to_css_string(), "color(rec2100-hlg 0.75 0.5 0 / 0.3)");
+}
@@ -1,5 +1,5 @@
 use crate::{
-  ColorAlpha, Hsl, ratio_converters::ratio_to_rgba, Rgb,
+  ColorAlpha, Hsl, Hsv, Hwb, ratio_converters::ratio_to_rgba, Rgb,
 };
 use crate::converters::*;
 use crate::rgb::RgbRatio;
@@ -103,6 +103,63 @@ impl From<Hsl> for Rgb {
   }
 }
 
+fn from_hsv(hsv: &Hsv) -> Rgb {
+  let a = hsv.alpha();
+  let mut rgb = Rgb::from_units(hsv_to_rgb(hsv));
+  rgb.set_alpha(a);
+  rgb
+}
+
+impl From<&Hsv> for Rgb {
+  /// # Example
+  /// ```
+  /// use colorsys::{Rgb,Hsv};
+  /// let hsv = Hsv::from(&(48.0, 82.5, 85.0));
+  /// let rgb: Rgb = Rgb::from(&hsv);
+  /// assert_eq!(rgb.to_css_string(), "rgb(217,181,38)");
+  /// ```
+  fn from(hsv: &Hsv) -> Self {
+    from_hsv(hsv)
+  }
+}
+
+impl From<&mut Hsv> for Rgb {
+  fn from(hsv: &mut Hsv) -> Self {
+    from_hsv(hsv)
+  }
+}
+
+impl From<Hsv> for Rgb {
+  fn from(hsv: Hsv) -> Self {
+    from_hsv(&hsv)
+  }
+}
+
+impl From<&Hwb> for Rgb {
+  /// # Example
+  /// ```
+  /// use colorsys::{Rgb,Hwb};
+  /// let hwb = Hwb::from(&(48.0, 15.0, 15.0));
+  /// let rgb: Rgb = Rgb::from(&hwb);
+  /// assert_eq!(rgb.to_css_string(), "rgb(217,181,38)");
+  /// ```
+  fn from(hwb: &Hwb) -> Self {
+    from_hsv(&Hsv::from(hwb))
+  }
+}
+
+impl From<&mut Hwb> for Rgb {
+  fn from(hwb: &mut Hwb) -> Self {
+    from_hsv(&Hsv::from(hwb))
+  }
+}
+
+impl From<Hwb> for Rgb {
+  fn from(hwb: Hwb) -> Self {
+    from_hsv(&Hsv::from(hwb))
+  }
+}
+
 fn from_rgb_ratio(ratio: &RgbRatio) -> Rgb {
   let ru = &ratio.units;
   let t = ratio_to_rgba(&(ru[0], ru[1], ru[2], ru.alpha.get_f64()));
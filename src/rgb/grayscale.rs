@@ -14,18 +14,18 @@ pub enum GrayScaleMethod {
 static R_YUV_FACTOR: f64 = 0.299;
 static G_YUV_FACTOR: f64 = 0.587;
 static B_YUV_FACTOR: f64 = 0.114;
-static YUV_FACTORS: ColorTuple = (R_YUV_FACTOR, G_YUV_FACTOR, B_YUV_FACTOR);
+pub(crate) static YUV_FACTORS: ColorTuple = (R_YUV_FACTOR, G_YUV_FACTOR, B_YUV_FACTOR);
 
 static R_REC709_FACTOR: f64 = 0.2126;
 static G_REC709_FACTOR: f64 = 0.7152;
 static B_REC709_FACTOR: f64 = 0.0722;
-static REC709_FACTORS: ColorTuple =
+pub(crate) static REC709_FACTORS: ColorTuple =
   (R_REC709_FACTOR, G_REC709_FACTOR, B_REC709_FACTOR);
 
 static R_REC2100_FACTOR: f64 = 0.2627;
 static G_REC2100_FACTOR: f64 = 0.6780;
 static B_REC2100_FACTOR: f64 = 0.0593;
-static REC2100_FACTORS: ColorTuple =
+pub(crate) static REC2100_FACTORS: ColorTuple =
   (R_REC2100_FACTOR, G_REC2100_FACTOR, B_REC2100_FACTOR);
 
 fn mul(rgb: &mut Rgb, factors: ColorTuple) {
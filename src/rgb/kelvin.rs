@@ -0,0 +1,57 @@
+use crate::converters::{
+  daylight_xy, planck_xy, rgb_to_xyz, xy_to_brightest_rgb, xy_to_cct_duv,
+};
+
+use super::Rgb;
+
+impl Rgb {
+  /// Returns the color of black body radiator of temperature `kelvin`, as bright as
+  /// sRGB allows. Temperature is restricted to 1000K - 40000K. Components which are
+  /// out of sRGB gamut (blue below about 2000K) are clipped.
+  ///
+  /// # Example
+  /// ```
+  /// use colorsys::Rgb;
+  ///
+  /// assert_eq!(Rgb::from_kelvin(2700.0).to_css_string(), "rgb(255,173,89)");
+  /// ```
+  pub fn from_kelvin(kelvin: f64) -> Rgb {
+    Rgb::from_units(xy_to_brightest_rgb(&planck_xy(kelvin)))
+  }
+
+  /// Returns the color of CIE daylight illuminant of temperature `kelvin`, as bright as
+  /// sRGB allows. Temperature is restricted to 4000K - 25000K, where the daylight locus
+  /// is defined. `Rgb::from_daylight(6504.0)` is almost the white of sRGB (D65).
+  pub fn from_daylight(kelvin: f64) -> Rgb {
+    Rgb::from_units(xy_to_brightest_rgb(&daylight_xy(kelvin)))
+  }
+
+  /// Returns correlated color temperature (K) and Duv: the signed distance in CIE 1960 UCS
+  /// to the Planckian locus, positive for greenish colors above it, negative for pinkish ones.
+  /// `None` for black, if temperature is out of 1000K - 40000K, or if |Duv| is greater than
+  /// `0.05`, where CCT is not meaningful.
+  ///
+  /// # Example
+  /// ```
+  /// use colorsys::Rgb;
+  ///
+  /// let (cct, duv) = Rgb::from([255, 255, 255]).cct_duv().unwrap();
+  /// assert!((cct - 6504.0).abs() < 5.0);
+  /// assert_eq!((duv * 10_000.0).round(), 32.0);
+  ///
+  /// assert_eq!(Rgb::from([255, 0, 0]).cct_duv(), None);
+  /// ```
+  pub fn cct_duv(&self) -> Option<(f64, f64)> {
+    let (x, y, z) = rgb_to_xyz(self);
+    let sum = x + y + z;
+    if sum <= 0.0 {
+      return None;
+    }
+    xy_to_cct_duv(&(x / sum, y / sum))
+  }
+
+  /// Returns correlated color temperature (K), see `cct_duv`
+  pub fn cct(&self) -> Option<f64> {
+    self.cct_duv().map(|(cct, _)| cct)
+  }
+}
@@ -3,9 +3,10 @@ use alloc::string::String;
 
 pub use grayscale::GrayScaleMethod;
 use grayscale::rgb_grayscale;
+pub(crate) use grayscale::{REC2100_FACTORS, REC709_FACTORS, YUV_FACTORS};
 pub use ratio::RgbRatio;
 
-use crate::{ColorAlpha, ColorTuple, ColorTupleA, converters, Hsl, ColorUnitsIter};
+use crate::{ColorAlpha, ColorTuple, ColorTupleA, converters, Hsl, Hsv, ColorUnitsIter};
 use crate::common::{tuple_to_string};
 use crate::err::ParseError;
 use crate::units::{Alpha, GetColorUnits, Unit, Units};
@@ -16,6 +17,7 @@ mod tests;
 mod from;
 mod from_str;
 mod grayscale;
+mod kelvin;
 mod ops;
 mod ratio;
 mod transform;
@@ -1,6 +1,6 @@
 use core::ops::{Add, AddAssign, Sub, SubAssign};
 
-use crate::{ApproxEq, DEFAULT_APPROX_EQ_PRECISION, Hsl, Rgb};
+use crate::{ApproxEq, DEFAULT_APPROX_EQ_PRECISION, Hsl, Hsv, Rgb};
 
 ops_def!(Rgb);
 
@@ -15,6 +15,17 @@ impl ApproxEq<Hsl> for Rgb {
   }
 }
 
+impl ApproxEq<Hsv> for Rgb {
+  fn approx_eq(&self, hsv: &Hsv) -> bool {
+    self.approx_eq_clarify(hsv, DEFAULT_APPROX_EQ_PRECISION)
+  }
+
+  fn approx_eq_clarify(&self, hsv: &Hsv, precision: f64) -> bool {
+    let rgb: Rgb = hsv.into();
+    self.units.approx_eq_clarify(&rgb.units, precision)
+  }
+}
+
 #[cfg(test)]
 mod test {
   use crate::{ApproxEq, ColorTupleA, Hsl, Rgb};
@@ -101,3 +101,26 @@ fn rgb_from() {
   assert_eq!(rgb1, rgb2);
 }
 
+
+#[test]
+fn rgb_kelvin() {
+  for t in [1500.0, 2700.0, 4000.0, 6500.0, 10_000.0, 30_000.0] {
+    let rgb = Rgb::from_kelvin(t);
+    assert_eq!(rgb.red().max(rgb.green()).max(rgb.blue()), 255.0);
+    if t >= 2000.0 {
+      let cct = rgb.cct().unwrap();
+      assert!((cct - t).abs() < t * 0.001);
+    }
+  }
+  // warmer is redder
+  assert!(Rgb::from_kelvin(2000.0).blue() < Rgb::from_kelvin(3000.0).blue());
+  assert_eq!(Rgb::from_kelvin(100.0), Rgb::from_kelvin(1000.0));
+
+  let d65 = Rgb::from_daylight(6504.0);
+  assert!(d65.red() > 254.5 && d65.green() > 254.5 && d65.blue() > 254.5);
+  let (cct, duv) = Rgb::from_daylight(5000.0).cct_duv().unwrap();
+  assert!((cct - 5000.0).abs() < 10.0 && duv > 0.0);
+
+  assert_eq!(Rgb::from([0, 0, 0]).cct_duv(), None);
+  assert_eq!(Rgb::from([0, 255, 0]).cct_duv(), None);
+}
@@ -2,7 +2,7 @@ use consts::RGB_UNIT_MAX;
 
 use crate::{ColorTransform, consts, SaturationInSpace};
 
-use super::{grayscale, Hsl, Rgb};
+use super::{grayscale, Hsl, Hsv, Rgb};
 
 impl ColorTransform for Rgb {
   /// Lighten or darken color. amt is a percent with negative values - `-100..100`
@@ -40,7 +40,10 @@ impl ColorTransform for Rgb {
         self._apply_tuple(&new_rgb.into());
       }
       SaturationInSpace::Hsv(amt) => {
-        unimplemented!("{}", amt);
+        let mut hsv: Hsv = self.into();
+        hsv.set_saturation(hsv.saturation() + amt);
+        let new_rgb = Rgb::from(hsv);
+        self._apply_tuple(&new_rgb.into());
       }
     }
   }
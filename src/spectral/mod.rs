@@ -0,0 +1,169 @@
+//! Colors of spectral data: monochromatic lights, spectral power distributions
+//! of light sources and reflectances of surfaces.
+//!
+//! Spectra are integrated with CIE color matching functions from 380 to 780 nm,
+//! values out of this range are ignored.
+//!
+//! # Example
+//! ```
+//! use colorsys::{ApproxEq, Rgb, Xyz};
+//! use colorsys::spectral::{reflectance_to_xyz, wavelength_to_rgb, Observer, Spectrum};
+//!
+//! assert_eq!(wavelength_to_rgb(580.0).to_css_string(), "rgb(255,187,0)");
+//!
+//! // perfect white surface under illuminant A is the white of the illuminant,
+//! // converted to Rgb it is adapted to D65
+//! let a = Spectrum::blackbody(2856.0);
+//! let white = Spectrum::new(380.0, 10.0, vec![1.0; 41]);
+//! let xyz = reflectance_to_xyz(&white, &a, Observer::Cie1931);
+//! assert!(Rgb::from(&xyz).approx_eq_clarify(&Rgb::from([255, 255, 255]), 0.001));
+//! ```
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::{Rgb, WhitePoint, Xyz};
+use crate::converters::{
+  blackbody, cmf_at, integrate_spd, xyz_to_brightest_rgb_desaturated, CIE1931_2, CIE1964_10,
+  PLANCK_MAX_KELVIN, PLANCK_MIN_KELVIN, SPECTRAL_MAX_NM, SPECTRAL_MIN_NM,
+};
+use crate::xyz::new_xyz_units;
+use crate::ColorTuple;
+
+#[cfg(test)]
+mod tests;
+
+// wavelength step (nm) of generated spectra
+const SPECTRUM_STEP: f64 = 5.0;
+// wavelength (nm) where relative spectra of illuminants are equal to 100
+const NORMALIZATION_NM: f64 = 560.0;
+
+/// CIE standard colorimetric observer: color matching functions of an average
+/// human eye for a given field of view
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Observer {
+  /// CIE 1931 2° standard observer. XYZ values of all color models are based on it
+  #[default]
+  Cie1931,
+  /// CIE 1964 10° supplementary standard observer, for samples seen at wider angle
+  Cie1964,
+}
+
+impl Observer {
+  fn table(&self) -> &'static [[f64; 3]] {
+    match self {
+      Observer::Cie1931 => &CIE1931_2,
+      Observer::Cie1964 => &CIE1964_10,
+    }
+  }
+
+  /// Returns values of color matching functions `(x̄, ȳ, z̄)` at wavelength `nm`,
+  /// linearly interpolated from 5 nm tables. Zeros out of 380 - 780 nm
+  pub fn cmf(&self, nm: f64) -> ColorTuple {
+    cmf_at(self.table(), nm)
+  }
+}
+
+/// Spectral data sampled at equal wavelength intervals: power distribution of a light
+/// or reflectance (`0.0 - 1.0`) of a surface.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Spectrum {
+  start: f64,
+  step: f64,
+  values: Vec<f64>,
+}
+
+impl Spectrum {
+  /// Creates spectrum with `values[i]` at wavelength `start + i * step` nm.
+  /// `step` must be positive, otherwise spectrum is empty
+  pub fn new(start: f64, step: f64, values: Vec<f64>) -> Spectrum {
+    let values = if step > 0.0 { values } else { Vec::new() };
+    Spectrum { start, step, values }
+  }
+
+  /// Returns relative spectral power distribution of black body radiator, sampled from 380
+  /// to 780 nm at 5 nm, equal to `100.0` at 560 nm. Temperature is restricted to 1000K - 40000K.
+  /// `Spectrum::blackbody(2856.0)` is CIE illuminant A
+  pub fn blackbody(kelvin: f64) -> Spectrum {
+    let t = kelvin.clamp(PLANCK_MIN_KELVIN, PLANCK_MAX_KELVIN);
+    let norm = 100.0 / blackbody(NORMALIZATION_NM, t);
+    let len = ((SPECTRAL_MAX_NM - SPECTRAL_MIN_NM) / SPECTRUM_STEP) as usize + 1;
+    let values = (0..len)
+      .map(|i| blackbody(SPECTRAL_MIN_NM + i as f64 * SPECTRUM_STEP, t) * norm)
+      .collect();
+    Spectrum::new(SPECTRAL_MIN_NM, SPECTRUM_STEP, values)
+  }
+
+  /// Returns wavelength (nm) of the first sample
+  pub fn start(&self) -> f64 { self.start }
+  /// Returns wavelength interval (nm) between samples
+  pub fn step(&self) -> f64 { self.step }
+  pub fn values(&self) -> &[f64] { &self.values }
+
+  /// Returns value at wavelength `nm`, linearly interpolated between samples.
+  /// `0.0` out of sampled range
+  pub fn value_at(&self, nm: f64) -> f64 {
+    let pos = (nm - self.start) / self.step;
+    let last = self.values.len() as f64 - 1.0;
+    if self.values.is_empty() || !(0.0..=last).contains(&pos) {
+      return 0.0;
+    }
+    let i = pos as usize;
+    match self.values.get(i + 1) {
+      Some(next) => self.values[i] + (next - self.values[i]) * (pos - i as f64),
+      None => self.values[i],
+    }
+  }
+}
+
+/// Returns sRGB color of monochromatic light of wavelength `nm` (380 - 780 nm),
+/// as bright as possible. Spectral colors are out of sRGB gamut, so they are desaturated
+/// towards white keeping the hue. Black out of the visible range.
+pub fn wavelength_to_rgb(nm: f64) -> Rgb {
+  Rgb::from_units(xyz_to_brightest_rgb_desaturated(&cmf_at(&CIE1931_2, nm)))
+}
+
+fn to_xyz(t: &ColorTuple, white: WhitePoint) -> Xyz {
+  Xyz::from_units(new_xyz_units(t.0, t.1, t.2), white)
+}
+
+/// Returns color of a light with spectral power distribution `spd`. Values are scaled so that
+/// luminance `Y` is `1.0`, as bright as the reference white. Chromaticity is absolute,
+/// so the result is relative to `D65` as sRGB colors.
+/// Note that `Xyz` values of other color models are for the CIE 1931 observer.
+///
+/// # Example
+/// ```
+/// use colorsys::{Rgb, XyY};
+/// use colorsys::spectral::{spectrum_to_xyz, Observer, Spectrum};
+///
+/// // narrow band green LED
+/// let led = Spectrum::new(520.0, 5.0, vec![0.0, 0.5, 1.0, 0.5, 0.0]);
+/// let xyy = XyY::from(spectrum_to_xyz(&led, Observer::Cie1931));
+/// assert_eq!(xyy.dominant_wavelength(xyy.white_point()).unwrap().round(), 530.0);
+/// ```
+pub fn spectrum_to_xyz(spd: &Spectrum, observer: Observer) -> Xyz {
+  let (x, y, z) = integrate_spd(observer.table(), |nm| spd.value_at(nm));
+  if y <= 0.0 {
+    return to_xyz(&(0.0, 0.0, 0.0), WhitePoint::D65);
+  }
+  to_xyz(&(x / y, 1.0, z / y), WhitePoint::D65)
+}
+
+/// Returns color of a surface with spectral `reflectance` (`0.0 - 1.0`) lit by `illuminant`.
+/// Values are relative to the white of the illuminant, its luminance `Y` is `1.0`.
+/// The returned `Xyz` has the illuminant as the reference white, so conversion to `Rgb`
+/// adapts the color to `D65`.
+pub fn reflectance_to_xyz(reflectance: &Spectrum, illuminant: &Spectrum, observer: Observer) -> Xyz {
+  let table = observer.table();
+  let (wx, wy, wz) = integrate_spd(table, |nm| illuminant.value_at(nm));
+  let sum = wx + wy + wz;
+  if wy <= 0.0 {
+    return to_xyz(&(0.0, 0.0, 0.0), WhitePoint::D65);
+  }
+  let white = WhitePoint::Custom(wx / sum, wy / sum);
+  let (x, y, z) = integrate_spd(table, |nm| reflectance.value_at(nm) * illuminant.value_at(nm));
+  to_xyz(&(x / wy, y / wy, z / wy), white)
+}
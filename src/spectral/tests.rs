@@ -0,0 +1,72 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::{ApproxEq, ColorTuple, Rgb, WhitePoint, XyY, Xyz};
+
+use super::{reflectance_to_xyz, spectrum_to_xyz, wavelength_to_rgb, Observer, Spectrum};
+
+#[test]
+fn spectrum_values() {
+  let spd = Spectrum::new(400.0, 10.0, vec![1.0, 2.0, 4.0]);
+  assert_eq!(spd.value_at(400.0), 1.0);
+  assert_eq!(spd.value_at(405.0), 1.5);
+  assert_eq!(spd.value_at(415.0), 3.0);
+  assert_eq!(spd.value_at(420.0), 4.0);
+  assert_eq!(spd.value_at(399.0), 0.0);
+  assert_eq!(spd.value_at(421.0), 0.0);
+
+  let empty = Spectrum::new(400.0, 0.0, vec![1.0]);
+  assert!(empty.values().is_empty());
+  assert_eq!(empty.value_at(400.0), 0.0);
+}
+
+#[test]
+fn wavelength_colors() {
+  // desaturation keeps the hue
+  for nm in [420.0, 450.0, 500.0, 530.0, 580.0, 620.0, 700.0] {
+    let rgb = wavelength_to_rgb(nm);
+    assert_eq!(rgb.red().max(rgb.green()).max(rgb.blue()), 255.0);
+    let xyy = XyY::from(&rgb);
+    let wl = xyy.dominant_wavelength(WhitePoint::D65).unwrap();
+    assert!((wl - nm).abs() < 0.01);
+  }
+  assert_eq!(wavelength_to_rgb(300.0), Rgb::from([0, 0, 0]));
+  assert_eq!(wavelength_to_rgb(800.0), Rgb::from([0, 0, 0]));
+}
+
+#[test]
+fn spectrum_to_xyz_test() {
+  // equal energy light
+  let e = Spectrum::new(380.0, 5.0, vec![1.0; 81]);
+  for observer in [Observer::Cie1931, Observer::Cie1964] {
+    let xyz = spectrum_to_xyz(&e, observer);
+    assert!(xyz.approx_eq_clarify(&Xyz::new(1.0, 1.0, 1.0, None), 0.001));
+  }
+  let black = Spectrum::new(380.0, 5.0, vec![0.0; 81]);
+  let t: ColorTuple = spectrum_to_xyz(&black, Observer::Cie1931).into();
+  assert_eq!(t, (0.0, 0.0, 0.0));
+
+  // illuminant A
+  let xyy = XyY::from(spectrum_to_xyz(&Spectrum::blackbody(2856.0), Observer::Cie1931));
+  assert!((xyy.x() - 0.447_57).abs() < 0.0001 && (xyy.y() - 0.407_45).abs() < 0.0001);
+  let xyy = XyY::from(spectrum_to_xyz(&Spectrum::blackbody(2856.0), Observer::Cie1964));
+  assert!((xyy.x() - 0.451_17).abs() < 0.0001 && (xyy.y() - 0.405_94).abs() < 0.0001);
+}
+
+#[test]
+fn reflectance_to_xyz_test() {
+  let a = Spectrum::blackbody(2856.0);
+  let gray = Spectrum::new(380.0, 20.0, vec![0.2; 21]);
+  let xyz = reflectance_to_xyz(&gray, &a, Observer::Cie1931);
+  assert!((xyz.y() - 0.2).abs() < 1e-9);
+  let (x, y) = xyz.white_point().xy();
+  assert!((x - 0.447_57).abs() < 0.0001 && (y - 0.407_45).abs() < 0.0001);
+  let gray_rgb = Rgb::from(&xyz);
+  assert!((gray_rgb.red() - gray_rgb.blue()).abs() < 0.01);
+
+  // red surface reflects long waves only
+  let red: Vec<f64> = (0..81).map(|i| if i > 44 { 0.9 } else { 0.05 }).collect();
+  let red = Spectrum::new(380.0, 5.0, red);
+  let rgb = Rgb::from(reflectance_to_xyz(&red, &a, Observer::Cie1931));
+  assert!(rgb.red() > 200.0 && rgb.green() < 100.0 && rgb.blue() < 100.0);
+}
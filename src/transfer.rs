@@ -0,0 +1,284 @@
+//! Transfer functions which convert gamma-encoded color channel values to
+//! linear light and back.
+//!
+//! All functions take and return ratios (`0.0..1.0` for in-gamut colors).
+//! Values out of this range are extended symmetrically, so they can be used
+//! for extended-range colors too.
+//!
+//! # Example
+//! ```
+//! use colorsys::transfer::{linear_to_srgb, srgb_to_linear};
+//!
+//! // physically correct mean of black and white
+//! let mean = (srgb_to_linear(0.0) + srgb_to_linear(1.0)) / 2.0;
+//! assert_eq!((linear_to_srgb(mean) * 255.0).round(), 188.0);
+//! ```
+
+use core::f64::consts::LN_2;
+
+use crate::common::{f64_abs, f64_exp, f64_ln, f64_powf, f64_sqrt};
+
+/// sRGB electro-optical transfer function (EOTF): gamma-encoded value to linear light
+pub fn srgb_to_linear(c: f64) -> f64 {
+  let abs = f64_abs(c);
+  let lin = if abs <= 0.04045 {
+    abs / 12.92
+  } else {
+    f64_powf((abs + 0.055) / 1.055, 2.4)
+  };
+  if c < 0.0 { -lin } else { lin }
+}
+
+/// Inverse of sRGB EOTF: linear light to gamma-encoded value
+pub fn linear_to_srgb(c: f64) -> f64 {
+  let abs = f64_abs(c);
+  let enc = if abs <= 0.003_130_8 {
+    abs * 12.92
+  } else {
+    1.055 * f64_powf(abs, 1.0 / 2.4) - 0.055
+  };
+  if c < 0.0 { -enc } else { enc }
+}
+
+// ITU-R BT.2020 transfer function constants
+const REC2020_ALPHA: f64 = 1.099_296_826_809_44;
+const REC2020_BETA: f64 = 0.018_053_968_510_807;
+
+/// Inverse of ITU-R BT.2020 OETF: gamma-encoded value to linear light
+pub fn rec2020_to_linear(c: f64) -> f64 {
+  let abs = f64_abs(c);
+  let lin = if abs < REC2020_BETA * 4.5 {
+    abs / 4.5
+  } else {
+    f64_powf((abs + REC2020_ALPHA - 1.0) / REC2020_ALPHA, 1.0 / 0.45)
+  };
+  if c < 0.0 { -lin } else { lin }
+}
+
+/// ITU-R BT.2020 OETF: linear light to gamma-encoded value
+pub fn linear_to_rec2020(c: f64) -> f64 {
+  let abs = f64_abs(c);
+  let enc = if abs < REC2020_BETA {
+    abs * 4.5
+  } else {
+    REC2020_ALPHA * f64_powf(abs, 0.45) - (REC2020_ALPHA - 1.0)
+  };
+  if c < 0.0 { -enc } else { enc }
+}
+
+// SMPTE ST 2084 (PQ) constants
+const PQ_M1: f64 = 2_610.0 / 16_384.0;
+const PQ_M2: f64 = 2_523.0 / 4_096.0 * 128.0;
+const PQ_C1: f64 = 3_424.0 / 4_096.0;
+const PQ_C2: f64 = 2_413.0 / 4_096.0 * 32.0;
+const PQ_C3: f64 = 2_392.0 / 4_096.0 * 32.0;
+
+/// Luminance in cd/m² which corresponds to linear value `1.0` of PQ functions
+pub const PQ_MAX_LUMINANCE: f64 = 10_000.0;
+
+/// SMPTE ST 2084 (Rec.2100 PQ) EOTF: signal to absolute linear light,
+/// where `1.0` is `PQ_MAX_LUMINANCE` (10000 cd/m²)
+pub fn pq_to_linear(c: f64) -> f64 {
+  let abs = f64_abs(c);
+  let p = f64_powf(abs, 1.0 / PQ_M2);
+  let lin = f64_powf((p - PQ_C1).max(0.0) / (PQ_C2 - PQ_C3 * p), 1.0 / PQ_M1);
+  if c < 0.0 { -lin } else { lin }
+}
+
+/// Inverse of SMPTE ST 2084 (Rec.2100 PQ) EOTF: absolute linear light to signal
+pub fn linear_to_pq(c: f64) -> f64 {
+  let abs = f64_abs(c);
+  let y = f64_powf(abs, PQ_M1);
+  let enc = f64_powf((PQ_C1 + PQ_C2 * y) / (1.0 + PQ_C3 * y), PQ_M2);
+  if c < 0.0 { -enc } else { enc }
+}
+
+// ARIB STD-B67 (HLG) constants
+const HLG_A: f64 = 0.178_832_77;
+const HLG_B: f64 = 1.0 - 4.0 * HLG_A;
+const HLG_C: f64 = 0.559_910_729_529_562;
+
+/// Inverse of Rec.2100 HLG OETF: signal to relative scene linear light (`0.0..1.0`)
+pub fn hlg_to_linear(c: f64) -> f64 {
+  let abs = f64_abs(c);
+  let lin = if abs <= 0.5 {
+    abs * abs / 3.0
+  } else {
+    (f64_exp((abs - HLG_C) / HLG_A) + HLG_B) / 12.0
+  };
+  if c < 0.0 { -lin } else { lin }
+}
+
+/// Rec.2100 HLG OETF: relative scene linear light to signal
+pub fn linear_to_hlg(c: f64) -> f64 {
+  let abs = f64_abs(c);
+  let enc = if abs <= 1.0 / 12.0 {
+    f64_sqrt(3.0 * abs)
+  } else {
+    HLG_A * f64_ln(12.0 * abs - HLG_B) + HLG_C
+  };
+  if c < 0.0 { -enc } else { enc }
+}
+
+// Adobe RGB (1998) gamma
+const A98_GAMMA: f64 = 563.0 / 256.0;
+
+/// Adobe RGB (1998) decoding: gamma-encoded value to linear light
+pub fn a98_to_linear(c: f64) -> f64 {
+  let lin = f64_powf(f64_abs(c), A98_GAMMA);
+  if c < 0.0 { -lin } else { lin }
+}
+
+/// Adobe RGB (1998) encoding: linear light to gamma-encoded value
+pub fn linear_to_a98(c: f64) -> f64 {
+  let enc = f64_powf(f64_abs(c), 1.0 / A98_GAMMA);
+  if c < 0.0 { -enc } else { enc }
+}
+
+// ProPhoto RGB (ROMM RGB) constants
+const PROPHOTO_GAMMA: f64 = 1.8;
+const PROPHOTO_LINEAR_LIMIT: f64 = 1.0 / 512.0;
+
+/// ProPhoto RGB decoding: gamma-encoded value to linear light
+pub fn prophoto_to_linear(c: f64) -> f64 {
+  let abs = f64_abs(c);
+  let lin = if abs < PROPHOTO_LINEAR_LIMIT * 16.0 {
+    abs / 16.0
+  } else {
+    f64_powf(abs, PROPHOTO_GAMMA)
+  };
+  if c < 0.0 { -lin } else { lin }
+}
+
+/// ProPhoto RGB encoding: linear light to gamma-encoded value
+pub fn linear_to_prophoto(c: f64) -> f64 {
+  let abs = f64_abs(c);
+  let enc = if abs < PROPHOTO_LINEAR_LIMIT {
+    abs * 16.0
+  } else {
+    f64_powf(abs, 1.0 / PROPHOTO_GAMMA)
+  };
+  if c < 0.0 { -enc } else { enc }
+}
+
+// ACEScct constants (Academy S-2016-001)
+const ACESCCT_A: f64 = 10.540_237_741_654_5;
+const ACESCCT_B: f64 = 0.072_905_534_195_835_5;
+const ACESCCT_X_BRK: f64 = 0.007_812_5;
+const ACESCCT_Y_BRK: f64 = 0.155_251_141_552_511;
+// largest value of half-float, as in the ACES specification
+const ACESCCT_LINEAR_MAX: f64 = 65_504.0;
+
+/// ACEScct decoding: log-encoded value to scene linear light.
+/// Values below the break point are decoded with the linear toe, so negatives are preserved
+pub fn acescct_to_linear(c: f64) -> f64 {
+  if c <= ACESCCT_Y_BRK {
+    (c - ACESCCT_B) / ACESCCT_A
+  } else {
+    f64_powf(2.0, c * 17.52 - 9.72).min(ACESCCT_LINEAR_MAX)
+  }
+}
+
+/// ACEScct encoding: scene linear light to log-encoded value
+pub fn linear_to_acescct(c: f64) -> f64 {
+  if c <= ACESCCT_X_BRK {
+    ACESCCT_A * c + ACESCCT_B
+  } else {
+    (f64_ln(c.min(ACESCCT_LINEAR_MAX)) / LN_2 + 9.72) / 17.52
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::common::approx::approx;
+  use super::{
+    a98_to_linear, acescct_to_linear, hlg_to_linear, linear_to_a98, linear_to_acescct,
+    linear_to_hlg, linear_to_pq, linear_to_prophoto, linear_to_rec2020, linear_to_srgb,
+    pq_to_linear, prophoto_to_linear, rec2020_to_linear, srgb_to_linear,
+  };
+
+  #[test]
+  fn srgb_transfer_test() {
+    assert!(approx(srgb_to_linear(0.5), 0.214_041_140_5, 1e-9));
+    assert!(approx(srgb_to_linear(0.02), 0.001_547_987_6, 1e-9));
+    assert!(approx(srgb_to_linear(-0.5), -0.214_041_140_5, 1e-9));
+    for i in 0..=100 {
+      let v = i as f64 / 100.0;
+      assert!(approx(linear_to_srgb(srgb_to_linear(v)), v, 1e-12));
+    }
+  }
+
+  #[test]
+  fn rec2020_transfer_test() {
+    assert!(approx(linear_to_rec2020(0.018_053_968_510_807), 0.081_242_858_298_631_5, 1e-12));
+    assert!(approx(rec2020_to_linear(0.5), 0.259_719_437_101_177_5, 1e-9));
+    assert!(approx(rec2020_to_linear(1.0), 1.0, 1e-12));
+    for i in 0..=100 {
+      let v = i as f64 / 100.0;
+      assert!(approx(linear_to_rec2020(rec2020_to_linear(v)), v, 1e-12));
+      assert!(approx(linear_to_rec2020(-v), -linear_to_rec2020(v), 1e-12));
+    }
+  }
+
+  #[test]
+  fn pq_transfer_test() {
+    assert_eq!(pq_to_linear(0.0), 0.0);
+    assert!(approx(pq_to_linear(1.0), 1.0, 1e-12));
+    // 100 and 203 cd/m²
+    assert!(approx(linear_to_pq(0.01), 0.508_078_421_517_399, 1e-9));
+    assert!(approx(linear_to_pq(0.0203), 0.580_688_881_041_611, 1e-9));
+    // zero signal is a bit below the black level of the curve
+    for i in 1..=100 {
+      let v = i as f64 / 100.0;
+      assert!(approx(linear_to_pq(pq_to_linear(v)), v, 1e-9));
+    }
+  }
+
+  #[test]
+  fn hlg_transfer_test() {
+    assert!(approx(linear_to_hlg(1.0 / 12.0), 0.5, 1e-12));
+    assert!(approx(linear_to_hlg(1.0), 1.0, 1e-6));
+    assert!(approx(hlg_to_linear(0.75), 0.264_962_560_421_007, 1e-9));
+    for i in 0..=100 {
+      let v = i as f64 / 100.0;
+      assert!(approx(linear_to_hlg(hlg_to_linear(v)), v, 1e-12));
+    }
+  }
+
+  #[test]
+  fn a98_transfer_test() {
+    assert!(approx(a98_to_linear(0.5), 0.217_755_528_144_394_6, 1e-9));
+    assert!(approx(a98_to_linear(-0.5), -0.217_755_528_144_394_6, 1e-9));
+    for i in 0..=100 {
+      let v = i as f64 / 100.0;
+      assert!(approx(linear_to_a98(a98_to_linear(v)), v, 1e-12));
+    }
+  }
+
+  #[test]
+  fn prophoto_transfer_test() {
+    assert!(approx(prophoto_to_linear(0.5), 0.287_174_588_749_258_7, 1e-9));
+    assert!(approx(prophoto_to_linear(0.01), 0.000_625, 1e-12));
+    assert!(approx(linear_to_prophoto(1.0 / 512.0), 0.031_25, 1e-12));
+    for i in 0..=100 {
+      let v = i as f64 / 100.0;
+      assert!(approx(linear_to_prophoto(prophoto_to_linear(v)), v, 1e-12));
+    }
+  }
+
+  #[test]
+  fn acescct_transfer_test() {
+    // 18% grey
+    assert!(approx(linear_to_acescct(0.18), 0.413_588_402_492_442_1, 1e-9));
+    assert!(approx(acescct_to_linear(0.413_588_402_492_442_1), 0.18, 1e-9));
+    assert!(approx(linear_to_acescct(0.0), 0.072_905_534_195_835_5, 1e-12));
+    // both segments meet at the break point
+    assert!(approx(linear_to_acescct(0.007_812_5), 0.155_251_141_552_511, 1e-9));
+    assert!(approx(acescct_to_linear(1.0), 222.860_944_203_807_75, 1e-9));
+    assert_eq!(acescct_to_linear(1.5), 65_504.0);
+    for i in -10..=140 {
+      let v = i as f64 / 100.0;
+      assert!(approx(linear_to_acescct(acescct_to_linear(v)), v, 1e-12));
+    }
+  }
+}
@@ -14,6 +14,16 @@ pub mod iter;
 pub trait GetColorUnits {
   fn get_units(&self) -> &Units;
   fn get_units_mut(&mut self) -> &mut Units;
+
+  /// Returns `true` if both colors are expressed in the same space, e.g. the same
+  /// reference white or viewing conditions. Colors in different spaces are never
+  /// approximately equal
+  fn same_space(&self, _other: &Self) -> bool
+  where
+    Self: Sized,
+  {
+    true
+  }
 }
 
 
@@ -17,6 +17,7 @@ enum UnitType {
   Hue,
   Percent,
   Ratio,
+  Unbounded,
 }
 
 impl UnitType {
@@ -25,7 +26,7 @@ impl UnitType {
       UnitType::Rgb => RGB_UNIT_MAX,
       UnitType::Hue => HUE_MAX,
       UnitType::Percent => PERCENT_MAX,
-      UnitType::Ratio => RATIO_MAX,
+      UnitType::Ratio | UnitType::Unbounded => RATIO_MAX,
     }
   }
 }
@@ -78,8 +79,14 @@ impl Unit {
   pub(crate) fn new_ratio(v: f64) -> Self {
     Unit::new(v, UnitType::Ratio)
   }
+  pub(crate) fn new_unbounded(v: f64) -> Self {
+    Unit::new(v, UnitType::Unbounded)
+  }
 
   fn get_restricted(&self, val: f64) -> f64 {
+    if let UnitType::Unbounded = self.kind {
+      return val;
+    }
     if val < ALL_MIN {
       return ALL_MIN;
     }
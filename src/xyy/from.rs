@@ -0,0 +1,29 @@
+use crate::{ColorAlpha, Rgb, Xyz};
+use crate::xyz::new_xyz_units;
+
+use super::{new_xyy_units, XyY};
+
+tuples_def!(XyY, f32);
+tuples_def!(XyY, f64);
+
+fn from_xyz(xyz: &Xyz) -> XyY {
+  let sum = xyz.x() + xyz.y() + xyz.z();
+  let (x, y) = if sum == 0.0 { xyz.white.xy() } else { (xyz.x() / sum, xyz.y() / sum) };
+  let mut units = new_xyy_units(x, y, xyz.y());
+  units.alpha.set(xyz.alpha());
+  XyY::from_units(units, xyz.white)
+}
+
+fn to_xyz(xyy: &XyY) -> Xyz {
+  let (x, y, lum) = (xyy.x(), xyy.y(), xyy.luminance());
+  let (cx, cz) = if y == 0.0 { (0.0, 0.0) } else { (x * lum / y, (1.0 - x - y) * lum / y) };
+  let mut units = new_xyz_units(cx, lum, cz);
+  units.alpha.set(xyy.alpha());
+  Xyz::from_units(units, xyy.white)
+}
+
+from_for_some!(Xyz, XyY, xyz, { from_xyz(xyz) });
+from_for_some!(XyY, Xyz, xyy, { to_xyz(xyy) });
+
+from_for_some!(Rgb, XyY, rgb, { from_xyz(&rgb.into()) });
+from_for_some!(XyY, Rgb, xyy, { to_xyz(xyy).into() });
@@ -0,0 +1,184 @@
+use crate::{ChromaticAdaptation, ColorUnitsIter, WhitePoint};
+use crate::converters::{
+  complementary_wavelength, dominant_wavelength, excitation_purity, uv_prime_to_xy, xy_to_uv_prime,
+};
+use crate::units::{Alpha, GetColorUnits, Unit, Units};
+
+#[cfg(test)]
+mod tests;
+
+mod from;
+
+/// The CIE xyY color space: chromaticity coordinates `x`, `y` and luminance `Y`.
+///
+/// Same as `Xyz`, values are relative to a reference white, luminance of white is `1.0`
+/// and `D65` is used by default. Chromaticity of black is the chromaticity of white.
+/// Also provides CIE 1976 `u'v'` coordinates, dominant (or complementary) wavelength and
+/// excitation purity, computed with the CIE 1931 2° standard observer.
+///
+/// Ranges:
+/// * x: not restricted, 0.0 - 1.0 for real colors
+/// * y: not restricted, 0.0 - 1.0 for real colors
+/// * luminance: not restricted, `1.0` is reference white
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{ApproxEq, Rgb, WhitePoint, XyY};
+///
+/// let red = XyY::from(Rgb::from([255, 0, 0]));
+/// assert!(red.approx_eq_clarify(&XyY::new(0.64, 0.33, 0.2126, None), 0.0001));
+///
+/// let (u, v) = red.uv_prime();
+/// assert_eq!([(u * 1000.0).round(), (v * 1000.0).round()], [451.0, 523.0]);
+///
+/// let wavelength = red.dominant_wavelength(WhitePoint::D65).unwrap();
+/// assert_eq!(wavelength.round(), 611.0);
+/// assert!(red.excitation_purity(WhitePoint::D65) > 0.9);
+///
+/// // purples have no dominant wavelength
+/// let magenta = XyY::from(Rgb::from([255, 0, 255]));
+/// assert_eq!(magenta.dominant_wavelength(WhitePoint::D65), None);
+/// assert_eq!(magenta.complementary_wavelength(WhitePoint::D65).unwrap().round(), 549.0);
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct XyY {
+  pub(crate) units: Units,
+  pub(crate) white: WhitePoint,
+}
+
+iter_def!(XyY);
+
+pub(crate) fn new_xyy_units(x: f64, y: f64, lum: f64) -> Units {
+  let u = Unit::new_unbounded;
+  let ul = [u(x), u(y), u(lum), Unit::default()];
+  Units { len: 3, list: ul, alpha: Alpha::default() }
+}
+
+impl XyY {
+  /// Creates color relative to `D65` reference white
+  pub fn new(x: f64, y: f64, lum: f64, a: Option<f64>) -> XyY {
+    XyY::new_with_white(x, y, lum, a, WhitePoint::D65)
+  }
+
+  pub fn new_with_white(
+    x: f64,
+    y: f64,
+    lum: f64,
+    a: Option<f64>,
+    white: WhitePoint,
+  ) -> XyY {
+    let mut units = new_xyy_units(x, y, lum);
+    units.alpha.set_opt(a);
+    XyY { units, white }
+  }
+
+  /// Creates color relative to `D65` reference white from CIE 1976 `u'v'`
+  /// chromaticity coordinates and luminance
+  pub fn from_uv_prime(u: f64, v: f64, lum: f64, a: Option<f64>) -> XyY {
+    let (x, y) = uv_prime_to_xy(&(u, v));
+    XyY::new(x, y, lum, a)
+  }
+
+  pub(crate) fn from_units(units: Units, white: WhitePoint) -> Self {
+    XyY { units, white }
+  }
+
+  pub fn x(&self) -> f64 { self.units[0] }
+  pub fn y(&self) -> f64 { self.units[1] }
+  /// Luminance `Y`
+  pub fn luminance(&self) -> f64 { self.units[2] }
+
+  pub fn set_x(&mut self, val: f64) { self.units.list[0].set(val); }
+  pub fn set_y(&mut self, val: f64) { self.units.list[1].set(val); }
+  pub fn set_luminance(&mut self, val: f64) { self.units.list[2].set(val); }
+
+  /// Returns chromaticity coordinates `(x, y)`
+  pub fn xy(&self) -> (f64, f64) {
+    (self.x(), self.y())
+  }
+
+  /// Returns CIE 1976 `(u', v')` chromaticity coordinates
+  pub fn uv_prime(&self) -> (f64, f64) {
+    xy_to_uv_prime(&self.xy())
+  }
+
+  /// Returns the reference white which values are relative to
+  pub fn white_point(&self) -> WhitePoint {
+    self.white
+  }
+
+  /// Returns same color relative to another reference white.
+  /// Uses Bradford chromatic adaptation transform.
+  pub fn adapt(&self, white: WhitePoint) -> XyY {
+    XyY::from(crate::Xyz::from(self).adapt(white))
+  }
+
+  /// Returns same color relative to another reference white, using given
+  /// chromatic adaptation transform.
+  pub fn adapt_with(&self, white: WhitePoint, method: ChromaticAdaptation) -> XyY {
+    XyY::from(crate::Xyz::from(self).adapt_with(white, method))
+  }
+
+  /// Returns wavelength (nm) of monochromatic light which, mixed with `white`, matches
+  /// chromaticity of the color. `None` for purples, which have no dominant wavelength
+  /// (see `complementary_wavelength`), and for `white` itself
+  pub fn dominant_wavelength(&self, white: WhitePoint) -> Option<f64> {
+    dominant_wavelength(&self.xy(), &white.xy())
+  }
+
+  /// Returns wavelength (nm) of monochromatic light which, mixed with the color,
+  /// matches `white`. `None` if there is no such light, and for `white` itself
+  pub fn complementary_wavelength(&self, white: WhitePoint) -> Option<f64> {
+    complementary_wavelength(&self.xy(), &white.xy())
+  }
+
+  /// Returns excitation purity relative to `white`: `0.0` for `white`, `1.0` for
+  /// monochromatic lights and for colors on the line of purples
+  pub fn excitation_purity(&self, white: WhitePoint) -> f64 {
+    excitation_purity(&self.xy(), &white.xy())
+  }
+
+  /// Returns an iterator over three color units and the possibly alpha value.
+  pub fn iter(&self) -> ColorUnitsIter {
+    ColorUnitsIter::from_units(&self.units)
+  }
+}
+
+//
+//
+//
+// Default
+//
+impl Default for XyY {
+  fn default() -> XyY {
+    let white = WhitePoint::default();
+    let (x, y) = white.xy();
+    XyY::from_units(new_xyy_units(x, y, 0.0), white)
+  }
+}
+
+//
+//
+//
+// AsRef<XyY>
+//
+impl AsRef<XyY> for XyY {
+  fn as_ref(&self) -> &XyY {
+    self
+  }
+}
+
+impl GetColorUnits for XyY {
+  fn get_units(&self) -> &Units {
+    &self.units
+  }
+  fn get_units_mut(&mut self) -> &mut Units {
+    &mut self.units
+  }
+
+  fn same_space(&self, other: &XyY) -> bool {
+    self.white.xy() == other.white.xy()
+  }
+}
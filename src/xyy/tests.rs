@@ -0,0 +1,73 @@
+use crate::{ApproxEq, ColorAlpha, Rgb, WhitePoint, XyY, Xyz};
+
+#[test]
+fn xyy_from_rgb() {
+  let asserts = [
+    ([255, 0, 0], (0.64, 0.33, 0.212_639)),
+    ([0, 255, 0], (0.3, 0.6, 0.715_169)),
+    ([0, 0, 255], (0.15, 0.06, 0.072_192)),
+    ([255, 255, 255], (0.3127, 0.329, 1.0)),
+    ([0, 0, 0], (0.3127, 0.329, 0.0)),
+  ];
+  for (rgb_arr, xyy_t) in asserts.iter() {
+    let rgb = Rgb::from(rgb_arr);
+    let xyy = XyY::from(&rgb);
+    assert!(xyy.approx_eq_clarify(&XyY::from(xyy_t), 0.000_001));
+    assert!(Rgb::from(&xyy).approx_eq_clarify(&rgb, 0.000_001));
+  }
+}
+
+#[test]
+fn xyy_xyz() {
+  let xyz = Xyz::new_with_white(0.3, 0.2, 0.5, Some(0.4), WhitePoint::D50);
+  let xyy = XyY::from(&xyz);
+  assert_eq!(xyy.white_point(), WhitePoint::D50);
+  assert_eq!(xyy.alpha(), 0.4);
+  assert!(xyy.approx_eq(&XyY::new_with_white(0.3, 0.2, 0.2, Some(0.4), WhitePoint::D50)));
+  assert!(!xyy.approx_eq(&XyY::new(0.3, 0.2, 0.2, Some(0.4))));
+  assert!(Xyz::from(&xyy).approx_eq(&xyz));
+
+  let d65 = xyy.adapt(WhitePoint::D65);
+  assert_eq!(d65.white_point(), WhitePoint::D65);
+  assert!(Xyz::from(&d65).approx_eq(&xyz.adapt(WhitePoint::D65)));
+
+  let black = XyY::from(Xyz::new_with_white(0.0, 0.0, 0.0, None, WhitePoint::D50));
+  assert_eq!(black.xy(), WhitePoint::D50.xy());
+}
+
+#[test]
+fn xyy_uv_prime() {
+  let xyy = XyY::from_uv_prime(0.1978, 0.4683, 0.5, None);
+  assert!(xyy.approx_eq_clarify(&XyY::new(0.3127, 0.329, 0.5, None), 0.0001));
+  let (u, v) = xyy.uv_prime();
+  assert!((u - 0.1978).abs() < 1e-9 && (v - 0.4683).abs() < 1e-9);
+}
+
+#[test]
+fn xyy_wavelength() {
+  // dominant and complementary wavelengths (nm) and excitation purity relative to D65
+  let asserts = [
+    ([255, 0, 0], Some(611.374), Some(491.410), 0.916_813),
+    ([0, 255, 0], Some(549.136), None, 0.734_740),
+    ([0, 0, 255], Some(464.204), Some(570.464), 0.925_220),
+    ([255, 0, 255], None, Some(549.136), 0.685_715),
+    ([0, 255, 255], Some(491.410), Some(611.374), 0.321_957),
+    ([255, 255, 255], None, None, 0.0),
+  ];
+  let approx_opt = |a: Option<f64>, b: Option<f64>| match (a, b) {
+    (Some(a), Some(b)) => (a - b).abs() < 0.001,
+    (None, None) => true,
+    _ => false,
+  };
+  for (rgb_arr, dominant, complementary, purity) in asserts.iter() {
+    let xyy = XyY::from(Rgb::from(rgb_arr));
+    assert!(approx_opt(xyy.dominant_wavelength(WhitePoint::D65), *dominant));
+    assert!(approx_opt(xyy.complementary_wavelength(WhitePoint::D65), *complementary));
+    assert!((xyy.excitation_purity(WhitePoint::D65) - purity).abs() < 1e-6);
+  }
+
+  // relative to another white
+  let xyy = XyY::new(0.3457, 0.3585, 1.0, None);
+  assert_eq!(xyy.excitation_purity(WhitePoint::D50), 0.0);
+  assert!(xyy.dominant_wavelength(WhitePoint::D65).unwrap() > 570.0);
+}
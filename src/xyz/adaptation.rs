@@ -0,0 +1,162 @@
+use crate::ColorTuple;
+use crate::cam16::viewing_conditions::{M16, M16_INV};
+use crate::common::matrix::{mat3_diag, mat3_mul, mat3_mul_tuple, Mat3};
+
+use super::WhitePoint;
+
+static BRADFORD: Mat3 = [
+  [0.8951, 0.2664, -0.1614],
+  [-0.7502, 1.7135, 0.0367],
+  [0.0389, -0.0685, 1.0296],
+];
+
+// Hunt-Pointer-Estévez cone responses
+static VON_KRIES: Mat3 = [
+  [0.400_24, 0.707_6, -0.080_81],
+  [-0.226_3, 1.165_32, 0.045_7],
+  [0.0, 0.0, 0.918_22],
+];
+
+static CAT02: Mat3 = [
+  [0.7328, 0.4296, -0.1624],
+  [-0.7036, 1.6975, 0.0061],
+  [0.0030, 0.0136, 0.9834],
+];
+
+// inverses of the matrices above
+static BRADFORD_INV: Mat3 = [
+  [0.986_992_905_466_712_1, -0.147_054_256_420_990_1, 0.159_962_651_663_731_25],
+  [0.432_305_269_723_394_5, 0.518_360_271_536_777_6, 0.049_291_228_212_855_615],
+  [-0.008_528_664_575_177_331, 0.040_042_821_654_084_86, 0.968_486_695_787_55],
+];
+
+static VON_KRIES_INV: Mat3 = [
+  [1.859_936_387_455_839_7, -1.129_381_618_580_091_6, 0.219_897_409_596_193_28],
+  [0.361_191_436_241_767_5, 0.638_812_463_285_042_2, -0.000_006_370_596_838_657_058],
+  [0.0, 0.0, 1.089_063_623_096_861_3],
+];
+
+static CAT02_INV: Mat3 = [
+  [1.096_123_820_835_514_2, -0.278_869_000_218_287_26, 0.182_745_179_382_773_1],
+  [0.454_369_041_975_359_16, 0.473_533_154_307_411_7, 0.072_097_803_717_229_13],
+  [-0.009_627_608_738_429_355, -0.005_698_031_216_113_421, 1.015_325_639_954_542_7],
+];
+
+/// Chromatic adaptation transform: a method to find the color which looks under one
+/// reference white the same as the given color under another one.
+/// All of them scale cone-like responses by the ratio of responses to the whites
+/// and differ in the cone responses matrix.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChromaticAdaptation {
+  /// Bradford transform (linearized), used by ICC profiles
+  #[default]
+  Bradford,
+  /// Von Kries transform with Hunt-Pointer-Estévez cone responses
+  VonKries,
+  /// Transform of CIECAM02 color appearance model
+  Cat02,
+  /// Transform of CAM16 color appearance model
+  Cat16,
+}
+
+impl ChromaticAdaptation {
+  fn matrix(&self) -> &'static Mat3 {
+    match self {
+      ChromaticAdaptation::Bradford => &BRADFORD,
+      ChromaticAdaptation::VonKries => &VON_KRIES,
+      ChromaticAdaptation::Cat02 => &CAT02,
+      ChromaticAdaptation::Cat16 => &M16,
+    }
+  }
+
+  fn inverse_matrix(&self) -> &'static Mat3 {
+    match self {
+      ChromaticAdaptation::Bradford => &BRADFORD_INV,
+      ChromaticAdaptation::VonKries => &VON_KRIES_INV,
+      ChromaticAdaptation::Cat02 => &CAT02_INV,
+      ChromaticAdaptation::Cat16 => &M16_INV,
+    }
+  }
+}
+
+/// Returns matrix which converts XYZ values relative to `src` white
+/// to XYZ values relative to `dst` white
+pub(crate) fn adaptation_matrix(
+  src: &WhitePoint,
+  dst: &WhitePoint,
+  method: &ChromaticAdaptation,
+) -> Mat3 {
+  let m = method.matrix();
+  let src_lms = mat3_mul_tuple(m, &src.xyz());
+  let dst_lms = mat3_mul_tuple(m, &dst.xyz());
+  let scale = mat3_diag(&(
+    dst_lms.0 / src_lms.0,
+    dst_lms.1 / src_lms.1,
+    dst_lms.2 / src_lms.2,
+  ));
+  mat3_mul(method.inverse_matrix(), &mat3_mul(&scale, m))
+}
+
+pub(crate) fn adapt_with(
+  t: &ColorTuple,
+  src: &WhitePoint,
+  dst: &WhitePoint,
+  method: &ChromaticAdaptation,
+) -> ColorTuple {
+  if src == dst {
+    return *t;
+  }
+  mat3_mul_tuple(&adaptation_matrix(src, dst, method), t)
+}
+
+/// Adapts with Bradford transform
+pub(crate) fn adapt(t: &ColorTuple, src: &WhitePoint, dst: &WhitePoint) -> ColorTuple {
+  adapt_with(t, src, dst, &ChromaticAdaptation::Bradford)
+}
+
+#[cfg(test)]
+mod test {
+  use super::{adapt, adapt_with, ChromaticAdaptation};
+  use crate::common::approx::approx;
+  use crate::WhitePoint;
+
+  #[test]
+  fn adapt_test() {
+    let d65 = WhitePoint::D65;
+    let d50 = WhitePoint::D50;
+    let (x, y, z) = adapt(&d65.xyz(), &d65, &d50);
+    let (wx, wy, wz) = d50.xyz();
+    assert!(approx(x, wx, 1e-12) && approx(y, wy, 1e-12) && approx(z, wz, 1e-12));
+
+    let (x, y, z) = adapt(&(0.2, 0.3, 0.4), &d65, &d50);
+    let (x, y, z) = adapt(&(x, y, z), &d50, &d65);
+    assert!(approx(x, 0.2, 1e-12) && approx(y, 0.3, 1e-12) && approx(z, 0.4, 1e-12));
+  }
+
+  #[test]
+  fn adapt_methods_test() {
+    let methods = [
+      ChromaticAdaptation::Bradford,
+      ChromaticAdaptation::VonKries,
+      ChromaticAdaptation::Cat02,
+      ChromaticAdaptation::Cat16,
+    ];
+    let (a, d65) = (WhitePoint::A, WhitePoint::D65);
+    for method in methods.iter() {
+      // whites are mapped to each other
+      let (x, y, z) = adapt_with(&a.xyz(), &a, &d65, method);
+      let (wx, wy, wz) = d65.xyz();
+      assert!(approx(x, wx, 1e-12) && approx(y, wy, 1e-12) && approx(z, wz, 1e-12));
+
+      let (x, y, z) = adapt_with(&(0.2, 0.3, 0.4), &a, &d65, method);
+      let (x, y, z) = adapt_with(&(x, y, z), &d65, &a, method);
+      assert!(approx(x, 0.2, 1e-12) && approx(y, 0.3, 1e-12) && approx(z, 0.4, 1e-12));
+    }
+
+    // methods differ for colors other than white
+    let bradford = adapt(&(0.2, 0.3, 0.4), &a, &d65);
+    let cat16 = adapt_with(&(0.2, 0.3, 0.4), &a, &d65, &ChromaticAdaptation::Cat16);
+    assert!(!approx(bradford.0, cat16.0, 0.01) && !approx(bradford.2, cat16.2, 0.01));
+  }
+}
@@ -0,0 +1,58 @@
+use crate::{ColorAlpha, Rgb};
+use crate::converters::{rgb_to_xyz, xyz_to_rgb};
+
+use super::{new_xyz_units, WhitePoint, Xyz};
+
+tuples_def!(Xyz, f32);
+tuples_def!(Xyz, f64);
+
+fn from_rgb(rgb: &Rgb) -> Xyz {
+  let (x, y, z) = rgb_to_xyz(rgb);
+  let mut units = new_xyz_units(x, y, z);
+  units.alpha.set(rgb.alpha());
+  Xyz::from_units(units, WhitePoint::D65)
+}
+
+impl From<&Rgb> for Xyz {
+  fn from(rgb: &Rgb) -> Self {
+    from_rgb(rgb)
+  }
+}
+
+impl From<&mut Rgb> for Xyz {
+  fn from(rgb: &mut Rgb) -> Self {
+    from_rgb(rgb)
+  }
+}
+
+impl From<Rgb> for Xyz {
+  fn from(rgb: Rgb) -> Self {
+    from_rgb(&rgb)
+  }
+}
+
+fn to_rgb(xyz: &Xyz) -> Rgb {
+  let d65 = xyz.adapt(WhitePoint::D65);
+  let mut rgb = Rgb::from_units(xyz_to_rgb(&d65.into()));
+  rgb.set_alpha(xyz.alpha());
+  rgb
+}
+
+impl From<&Xyz> for Rgb {
+  /// Colors out of sRGB gamut are clipped
+  fn from(xyz: &Xyz) -> Self {
+    to_rgb(xyz)
+  }
+}
+
+impl From<&mut Xyz> for Rgb {
+  fn from(xyz: &mut Xyz) -> Self {
+    to_rgb(xyz)
+  }
+}
+
+impl From<Xyz> for Rgb {
+  fn from(xyz: Xyz) -> Self {
+    to_rgb(&xyz)
+  }
+}
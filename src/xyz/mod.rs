@@ -0,0 +1,177 @@
+pub use adaptation::ChromaticAdaptation;
+pub use white_point::WhitePoint;
+
+use crate::{ColorTuple, ColorUnitsIter};
+use crate::converters::xy_to_cct_duv;
+use crate::units::{Alpha, GetColorUnits, Unit, Units};
+
+#[cfg(test)]
+mod tests;
+
+mod adaptation;
+mod from;
+mod white_point;
+
+pub(crate) use adaptation::{adapt, adapt_with};
+
+/// The CIE 1931 XYZ color space.
+///
+/// Device-independent model which is used as a bridge between other color models.
+/// Values are relative to a reference white (see `WhitePoint`), luminance `Y` of the
+/// reference white is `1.0`. Values are not restricted: colors outside of visible
+/// or sRGB gamut are kept as is. `D65` is used by default, as in sRGB.
+///
+/// Conversion from and to `Rgb` takes into account sRGB transfer function,
+/// so XYZ values are linear light.
+///
+/// # Example
+/// ```
+/// use colorsys::{ApproxEq, Rgb, WhitePoint, Xyz};
+///
+/// let rgb = Rgb::from([255, 255, 255]);
+/// let xyz = Xyz::from(&rgb);
+/// assert!(xyz.approx_eq_clarify(&Xyz::new(0.9505, 1.0, 1.0891, None), 0.0001));
+///
+/// // colors relative to different reference whites are never approximately equal
+/// let xyz_d50 = xyz.adapt(WhitePoint::D50);
+/// assert!(!xyz_d50.approx_eq_clarify(&Xyz::new(0.9643, 1.0, 0.8251, None), 0.0001));
+/// let expected = Xyz::new_with_white(0.9643, 1.0, 0.8251, None, WhitePoint::D50);
+/// assert!(xyz_d50.approx_eq_clarify(&expected, 0.0001));
+///
+/// let back = Rgb::from(&xyz_d50);
+/// assert!(back.approx_eq_clarify(&rgb, 0.000_001));
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Xyz {
+  pub(crate) units: Units,
+  pub(crate) white: WhitePoint,
+}
+
+iter_def!(Xyz);
+
+pub(crate) fn new_xyz_units(x: f64, y: f64, z: f64) -> Units {
+  let u = Unit::new_unbounded;
+  let ul = [u(x), u(y), u(z), Unit::default()];
+  Units { len: 3, list: ul, alpha: Alpha::default() }
+}
+
+impl Xyz {
+  /// Creates color relative to `D65` reference white
+  pub fn new(x: f64, y: f64, z: f64, a: Option<f64>) -> Xyz {
+    Xyz::new_with_white(x, y, z, a, WhitePoint::D65)
+  }
+
+  pub fn new_with_white(
+    x: f64,
+    y: f64,
+    z: f64,
+    a: Option<f64>,
+    white: WhitePoint,
+  ) -> Xyz {
+    let mut units = new_xyz_units(x, y, z);
+    units.alpha.set_opt(a);
+    Xyz { units, white }
+  }
+
+  pub(crate) fn from_units(units: Units, white: WhitePoint) -> Self {
+    Xyz { units, white }
+  }
+
+  pub fn x(&self) -> f64 { self.units[0] }
+  pub fn y(&self) -> f64 { self.units[1] }
+  pub fn z(&self) -> f64 { self.units[2] }
+
+  pub fn set_x(&mut self, val: f64) { self.units.list[0].set(val); }
+  pub fn set_y(&mut self, val: f64) { self.units.list[1].set(val); }
+  pub fn set_z(&mut self, val: f64) { self.units.list[2].set(val); }
+
+  /// Returns the reference white which values are relative to
+  pub fn white_point(&self) -> WhitePoint {
+    self.white
+  }
+
+  /// Returns same color relative to another reference white.
+  /// Uses Bradford chromatic adaptation transform.
+  pub fn adapt(&self, white: WhitePoint) -> Xyz {
+    self.adapt_with(white, ChromaticAdaptation::Bradford)
+  }
+
+  /// Returns same color relative to another reference white, using given
+  /// chromatic adaptation transform.
+  ///
+  /// # Example
+  /// ```
+  /// use colorsys::{ChromaticAdaptation, Rgb, WhitePoint, Xyz};
+  ///
+  /// // white balance: a gray card photographed under incandescent light
+  /// let shot = Xyz::new_with_white(0.1977, 0.18, 0.0641, None, WhitePoint::A);
+  /// let balanced = shot.adapt_with(WhitePoint::D65, ChromaticAdaptation::Cat16);
+  /// let rgb = Rgb::from(&balanced);
+  /// assert!((rgb.red() - rgb.blue()).abs() < 0.5);
+  /// assert_eq!(rgb.to_css_string(), "rgb(118,118,118)");
+  /// ```
+  pub fn adapt_with(&self, white: WhitePoint, method: ChromaticAdaptation) -> Xyz {
+    let t = adapt_with(&self.into(), &self.white, &white, &method);
+    let mut units = new_xyz_units(t.0, t.1, t.2);
+    units.alpha = self.units.alpha.clone();
+    Xyz::from_units(units, white)
+  }
+
+  /// Returns correlated color temperature (K) and Duv of the color adapted to `D65`.
+  /// See `Rgb::cct_duv`
+  pub fn cct_duv(&self) -> Option<(f64, f64)> {
+    let (x, y, z): ColorTuple = self.adapt(WhitePoint::D65).into();
+    let sum = x + y + z;
+    if sum <= 0.0 {
+      return None;
+    }
+    xy_to_cct_duv(&(x / sum, y / sum))
+  }
+
+  /// Returns correlated color temperature (K) of the color adapted to `D65`.
+  /// See `Rgb::cct_duv`
+  pub fn cct(&self) -> Option<f64> {
+    self.cct_duv().map(|(cct, _)| cct)
+  }
+
+  /// Returns an iterator over three color units and the possibly alpha value.
+  pub fn iter(&self) -> ColorUnitsIter {
+    ColorUnitsIter::from_units(&self.units)
+  }
+}
+
+//
+//
+//
+// Default
+//
+impl Default for Xyz {
+  fn default() -> Xyz {
+    Xyz::from_units(new_xyz_units(0.0, 0.0, 0.0), WhitePoint::default())
+  }
+}
+
+//
+//
+//
+// AsRef<Xyz>
+//
+impl AsRef<Xyz> for Xyz {
+  fn as_ref(&self) -> &Xyz {
+    self
+  }
+}
+
+impl GetColorUnits for Xyz {
+  fn get_units(&self) -> &Units {
+    &self.units
+  }
+  fn get_units_mut(&mut self) -> &mut Units {
+    &mut self.units
+  }
+
+  fn same_space(&self, other: &Xyz) -> bool {
+    self.white.xy() == other.white.xy()
+  }
+}
@@ -0,0 +1,99 @@
+use crate::{ApproxEq, ChromaticAdaptation, ColorAlpha, ColorTuple, Rgb, WhitePoint, Xyz};
+
+#[test]
+fn xyz_from_rgb() {
+  let rgb = Rgb::new(34.0, 111.0, 235.0, Some(0.4));
+  let xyz = Xyz::from(&rgb);
+  assert_eq!(xyz.white_point(), WhitePoint::D65);
+  assert_eq!(xyz.alpha(), 0.4);
+  assert!(xyz.approx_eq_clarify(&Xyz::new(0.2134, 0.1771, 0.8089, Some(0.4)), 0.0001));
+
+  let rgb_new = Rgb::from(&xyz);
+  assert!(rgb_new.approx_eq_clarify(&rgb, 0.000_000_1));
+}
+
+#[test]
+fn xyz_white_points() {
+  let white = Xyz::from(Rgb::from([255, 255, 255]));
+  let t: ColorTuple = white.as_ref().into();
+  assert!(Xyz::from(&t).approx_eq(&Xyz::from(&WhitePoint::D65.xyz())));
+
+  let custom = WhitePoint::Custom(0.3457, 0.3585);
+  let xyz = white.adapt(custom);
+  assert_eq!(xyz.white_point(), custom);
+  let (x, y, z) = WhitePoint::D50.xyz();
+  assert!(xyz.approx_eq(&Xyz::new_with_white(x, y, z, None, WhitePoint::D50)));
+  // same values relative to another white are another color
+  assert!(!xyz.approx_eq(&Xyz::new(x, y, z, None)));
+
+  let xyz = Xyz::new_with_white(0.3, 0.2, 0.1, None, WhitePoint::D50);
+  let back = xyz.adapt(WhitePoint::D65).adapt(WhitePoint::D50);
+  assert!(back.approx_eq(&xyz));
+  assert!(Rgb::from(&xyz).approx_eq(&Rgb::from(xyz.adapt(WhitePoint::D65))));
+}
+
+#[test]
+fn xyz_out_of_srgb_gamut() {
+  let mut xyz = Xyz::new(0.1, 0.9, -0.1, None);
+  assert_eq!(xyz.z(), -0.1);
+
+  let rgb = Rgb::from(&xyz);
+  assert_eq!(rgb.red(), 0.0);
+  assert_eq!(rgb.green(), 255.0);
+
+  xyz.set_x(1.5);
+  assert_eq!(xyz.x(), 1.5);
+}
+
+#[test]
+fn xyz_cct() {
+  let rgb = Rgb::from_kelvin(3000.0);
+  let xyz = Xyz::from(&rgb).adapt(WhitePoint::D50);
+  let (cct, duv) = xyz.cct_duv().unwrap();
+  let (rgb_cct, rgb_duv) = rgb.cct_duv().unwrap();
+  assert!((cct - rgb_cct).abs() < 1e-6 && (duv - rgb_duv).abs() < 1e-9);
+  assert_eq!(Xyz::new(0.0, 0.0, 0.0, None).cct(), None);
+}
+
+#[test]
+fn xyz_illuminants() {
+  let e = WhitePoint::E.xyz();
+  assert!((e.0 - 1.0).abs() < 1e-12 && (e.2 - 1.0).abs() < 1e-12);
+
+  let asserts = [
+    (WhitePoint::A, 2856.0),
+    (WhitePoint::D50, 5003.0),
+    (WhitePoint::D55, 5503.0),
+    (WhitePoint::D65, 6504.0),
+    (WhitePoint::D75, 7504.0),
+    (WhitePoint::F2, 4230.0),
+    (WhitePoint::F11, 4000.0),
+  ];
+  for (white, cct) in asserts.iter() {
+    let xyz = Xyz::from(&white.xyz());
+    assert!((xyz.cct().unwrap() - cct).abs() < 10.0);
+  }
+}
+
+#[test]
+fn xyz_adapt_with() {
+  let xyz = Xyz::new_with_white(0.3, 0.2, 0.1, Some(0.5), WhitePoint::F7);
+  let methods = [
+    ChromaticAdaptation::Bradford,
+    ChromaticAdaptation::VonKries,
+    ChromaticAdaptation::Cat02,
+    ChromaticAdaptation::Cat16,
+  ];
+  for method in methods {
+    let d50 = xyz.adapt_with(WhitePoint::D50, method);
+    assert_eq!(d50.white_point(), WhitePoint::D50);
+    assert_eq!(d50.alpha(), 0.5);
+    assert!(d50.adapt_with(WhitePoint::F7, method).approx_eq(&xyz));
+  }
+  assert_eq!(xyz.adapt(WhitePoint::C), xyz.adapt_with(WhitePoint::C, ChromaticAdaptation::default()));
+
+  let white = Xyz::new_with_white(1.0, 1.0, 1.0, None, WhitePoint::E);
+  let c = white.adapt_with(WhitePoint::C, ChromaticAdaptation::VonKries);
+  let (x, y, z) = WhitePoint::C.xyz();
+  assert!(c.approx_eq(&Xyz::new_with_white(x, y, z, None, WhitePoint::C)));
+}
@@ -0,0 +1,68 @@
+use crate::ColorTuple;
+
+/// Reference white of device-independent color models.
+///
+/// # Example
+/// ```
+/// use colorsys::WhitePoint;
+///
+/// let (x, y, z) = WhitePoint::D50.xyz();
+/// assert!((x - 0.9643).abs() < 0.0001);
+/// assert_eq!(y, 1.0);
+/// assert!((z - 0.8251).abs() < 0.0001);
+///
+/// let custom = WhitePoint::Custom(0.3127, 0.329);
+/// assert_eq!(custom.xyz(), WhitePoint::D65.xyz());
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WhitePoint {
+  /// Incandescent light (~2856K)
+  A,
+  /// Average daylight (~6774K), obsolete, replaced by D-series
+  C,
+  /// Horizon daylight (~5000K). Used in print and ICC profiles
+  D50,
+  /// Mid-morning daylight (~5500K). Used in photography
+  D55,
+  /// Noon daylight (~6500K). White of sRGB and most of displays
+  #[default]
+  D65,
+  /// North sky daylight (~7500K)
+  D75,
+  /// Equal energy white
+  E,
+  /// Cool white fluorescent lamp (~4230K)
+  F2,
+  /// Broad-band fluorescent lamp, daylight simulator (~6500K)
+  F7,
+  /// Narrow tri-band fluorescent lamp (~4000K)
+  F11,
+  /// White specified by CIE 1931 chromaticity coordinates `(x, y)`
+  Custom(f64, f64),
+}
+
+impl WhitePoint {
+  /// Returns CIE 1931 chromaticity coordinates `(x, y)` (2° observer)
+  pub fn xy(&self) -> (f64, f64) {
+    match *self {
+      WhitePoint::A => (0.447_57, 0.407_45),
+      WhitePoint::C => (0.310_06, 0.316_16),
+      WhitePoint::D50 => (0.3457, 0.3585),
+      WhitePoint::D55 => (0.332_42, 0.347_43),
+      WhitePoint::D65 => (0.3127, 0.3290),
+      WhitePoint::D75 => (0.299_02, 0.314_85),
+      WhitePoint::E => (1.0 / 3.0, 1.0 / 3.0),
+      WhitePoint::F2 => (0.372_08, 0.375_29),
+      WhitePoint::F7 => (0.312_92, 0.329_33),
+      WhitePoint::F11 => (0.380_52, 0.377_13),
+      WhitePoint::Custom(x, y) => (x, y),
+    }
+  }
+
+  /// Returns tristimulus values `(X, Y, Z)` with luminance `Y` equal to `1.0`
+  pub fn xyz(&self) -> ColorTuple {
+    let (x, y) = self.xy();
+    (x / y, 1.0, (1.0 - x - y) / y)
+  }
+}
@@ -0,0 +1,123 @@
+use crate::ColorTuple;
+use crate::rgb::{REC2100_FACTORS, REC709_FACTORS, YUV_FACTORS};
+
+/// Matrix coefficients of `YCbCr`, i.e. luma weights of red, green and blue.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum YCbCrMatrix {
+  /// ITU-R BT.601. Standard definition video and JPEG
+  Bt601,
+  /// ITU-R BT.709. HD video
+  #[default]
+  Bt709,
+  /// ITU-R BT.2020 (non-constant luminance). UHD and HDR video
+  Bt2020,
+}
+
+impl YCbCrMatrix {
+  /// Returns luma coefficients `(Kr, Kg, Kb)`
+  pub fn coefficients(&self) -> ColorTuple {
+    match *self {
+      YCbCrMatrix::Bt601 => YUV_FACTORS,
+      YCbCrMatrix::Bt709 => REC709_FACTORS,
+      YCbCrMatrix::Bt2020 => REC2100_FACTORS,
+    }
+  }
+}
+
+/// Quantization range of `YCbCr` code values.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum YCbCrRange {
+  /// Whole range of code values, e.g. `0..255` for 8 bits. Used in JPEG
+  Full,
+  /// Studio (also known as limited, TV or video) range: luma `16..235`,
+  /// chroma `16..240` for 8 bits
+  #[default]
+  Limited,
+}
+
+/// Bit depth of `YCbCr` code values.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum YCbCrBitDepth {
+  /// Code values are `0..255`
+  #[default]
+  Eight,
+  /// Code values are `0..1023`
+  Ten,
+}
+
+/// Digital encoding of `YCbCr`: matrix coefficients, quantization range and bit depth.
+/// Default is 8-bit BT.709 in limited range.
+///
+/// # Example
+/// ```
+/// use colorsys::{YCbCrBitDepth, YCbCrFormat, YCbCrMatrix, YCbCrRange};
+///
+/// let hdr = YCbCrFormat::new(YCbCrMatrix::Bt2020, YCbCrRange::Limited, YCbCrBitDepth::Ten);
+/// assert_eq!(hdr.max_code(), 1023.0);
+/// assert_eq!(YCbCrFormat::JPEG.range, YCbCrRange::Full);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct YCbCrFormat {
+  pub matrix: YCbCrMatrix,
+  pub range: YCbCrRange,
+  pub bit_depth: YCbCrBitDepth,
+}
+
+impl YCbCrFormat {
+  /// 8-bit BT.601 in full range, as in JPEG (JFIF)
+  pub const JPEG: YCbCrFormat =
+    YCbCrFormat { matrix: YCbCrMatrix::Bt601, range: YCbCrRange::Full, bit_depth: YCbCrBitDepth::Eight };
+
+  pub fn new(matrix: YCbCrMatrix, range: YCbCrRange, bit_depth: YCbCrBitDepth) -> YCbCrFormat {
+    YCbCrFormat { matrix, range, bit_depth }
+  }
+
+  /// Returns the largest code value
+  pub fn max_code(&self) -> f64 {
+    match self.bit_depth {
+      YCbCrBitDepth::Eight => 255.0,
+      YCbCrBitDepth::Ten => 1023.0,
+    }
+  }
+
+  // scale of 8-bit limited range values
+  fn depth_scale(&self) -> f64 {
+    (self.max_code() + 1.0) / 256.0
+  }
+
+  /// Converts analog `(Y', Pb, Pr)` to code values
+  pub(crate) fn quantize(&self, ypbpr: &ColorTuple) -> ColorTuple {
+    let (y, pb, pr) = *ypbpr;
+    match self.range {
+      YCbCrRange::Full => {
+        let max = self.max_code();
+        let mid = (max + 1.0) / 2.0;
+        (y * max, pb * max + mid, pr * max + mid)
+      }
+      YCbCrRange::Limited => {
+        let s = self.depth_scale();
+        ((219.0 * y + 16.0) * s, (224.0 * pb + 128.0) * s, (224.0 * pr + 128.0) * s)
+      }
+    }
+  }
+
+  /// Inverse of `quantize`
+  pub(crate) fn dequantize(&self, codes: &ColorTuple) -> ColorTuple {
+    let (y, cb, cr) = *codes;
+    match self.range {
+      YCbCrRange::Full => {
+        let max = self.max_code();
+        let mid = (max + 1.0) / 2.0;
+        (y / max, (cb - mid) / max, (cr - mid) / max)
+      }
+      YCbCrRange::Limited => {
+        let s = self.depth_scale();
+        ((y / s - 16.0) / 219.0, (cb / s - 128.0) / 224.0, (cr / s - 128.0) / 224.0)
+      }
+    }
+  }
+}
@@ -0,0 +1,38 @@
+use crate::{ColorTuple, Rgb};
+use crate::consts::RGB_UNIT_MAX;
+use crate::converters::{rgb_to_ypbpr, ypbpr_to_rgb};
+
+use super::{new_ycbcr_units, YCbCr, YCbCrFormat, YCbCrMatrix};
+
+tuples_def!(YCbCr, f32);
+tuples_def!(YCbCr, f64);
+
+// re-encodes analog values with other matrix coefficients, values are not clipped
+pub(super) fn convert_matrix(ypbpr: &ColorTuple, from: &YCbCrMatrix, to: &YCbCrMatrix) -> ColorTuple {
+  rgb_to_ypbpr(&ypbpr_to_rgb(ypbpr, &from.coefficients()), &to.coefficients())
+}
+
+fn from_rgb(rgb: &Rgb, format: YCbCrFormat) -> YCbCr {
+  let u = &rgb.units;
+  let ratios = (u[0] / RGB_UNIT_MAX, u[1] / RGB_UNIT_MAX, u[2] / RGB_UNIT_MAX);
+  let (y, cb, cr) = format.quantize(&rgb_to_ypbpr(&ratios, &format.matrix.coefficients()));
+  let mut units = new_ycbcr_units(y, cb, cr, &format);
+  units.alpha = rgb.units.alpha.clone();
+  YCbCr::from_units(units, format)
+}
+
+impl YCbCr {
+  /// Creates color from `Rgb` encoded in given format
+  pub fn from_rgb_with_format(rgb: &Rgb, format: YCbCrFormat) -> YCbCr {
+    from_rgb(rgb, format)
+  }
+}
+
+from_for_some!(Rgb, YCbCr, rgb, { from_rgb(rgb, YCbCrFormat::default()) });
+
+from_for_some!(YCbCr, Rgb, ycbcr, {
+  let format = &ycbcr.format;
+  let analog = format.dequantize(&ycbcr.into());
+  let (r, g, b) = ypbpr_to_rgb(&analog, &format.matrix.coefficients());
+  Rgb::new(r * RGB_UNIT_MAX, g * RGB_UNIT_MAX, b * RGB_UNIT_MAX, ycbcr.units.alpha.get())
+});
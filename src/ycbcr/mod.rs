@@ -0,0 +1,154 @@
+pub use format::{YCbCrBitDepth, YCbCrFormat, YCbCrMatrix, YCbCrRange};
+
+use crate::{ColorUnitsIter, Rgb};
+use crate::units::{Alpha, GetColorUnits, Unit, Units};
+
+#[cfg(test)]
+mod tests;
+
+mod format;
+mod from;
+
+/// The Y'CbCr color model of digital video and JPEG.
+///
+/// Luma `y` and blue-difference and red-difference chroma `cb`, `cr` are code values
+/// of a digital encoding described by `YCbCrFormat` (matrix coefficients,
+/// full or limited range, bit depth). `YCbCr::new` and conversion from `Rgb` use
+/// the default format (8-bit BT.709 in limited range), for others see `new_with_format`
+/// and `to_format`.
+///
+/// Values out of nominal range (like "super-whites" of limited range) are kept,
+/// they are clipped on conversion to `Rgb`.
+///
+/// Ranges:
+/// * y: 0.0 - 255.0 (1023.0 for 10 bits)
+/// * cb: 0.0 - 255.0 (1023.0 for 10 bits)
+/// * cr: 0.0 - 255.0 (1023.0 for 10 bits)
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{ApproxEq, Rgb, YCbCr, YCbCrFormat};
+///
+/// let rgb = Rgb::from([255, 255, 255]);
+/// let ycbcr = YCbCr::from(&rgb);
+/// assert!(ycbcr.approx_eq(&YCbCr::new(235.0, 128.0, 128.0, None)));
+///
+/// let jpeg = ycbcr.to_format(YCbCrFormat::JPEG);
+/// assert!(jpeg.approx_eq(&YCbCr::new_with_format(255.0, 128.0, 128.0, None, YCbCrFormat::JPEG)));
+///
+/// let red = YCbCr::from(Rgb::from([255, 0, 0]));
+/// assert_eq!([red.y().round(), red.cb().round(), red.cr().round()], [63.0, 102.0, 240.0]);
+/// assert!(Rgb::from(&red).approx_eq(&Rgb::from([255, 0, 0])));
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct YCbCr {
+  pub(crate) units: Units,
+  pub(crate) format: YCbCrFormat,
+}
+
+iter_def!(YCbCr);
+
+pub(crate) fn new_ycbcr_units(y: f64, cb: f64, cr: f64, format: &YCbCrFormat) -> Units {
+  let max = format.max_code();
+  let u = |v: f64| Unit::new_unbounded(v.clamp(0.0, max));
+  let ul = [u(y), u(cb), u(cr), Unit::default()];
+  Units { len: 3, list: ul, alpha: Alpha::default() }
+}
+
+impl YCbCr {
+  /// Creates color in the default format (8-bit BT.709 in limited range)
+  pub fn new(y: f64, cb: f64, cr: f64, a: Option<f64>) -> YCbCr {
+    YCbCr::new_with_format(y, cb, cr, a, YCbCrFormat::default())
+  }
+
+  pub fn new_with_format(
+    y: f64,
+    cb: f64,
+    cr: f64,
+    a: Option<f64>,
+    format: YCbCrFormat,
+  ) -> YCbCr {
+    let mut units = new_ycbcr_units(y, cb, cr, &format);
+    units.alpha.set_opt(a);
+    YCbCr { units, format }
+  }
+
+  pub(crate) fn from_units(units: Units, format: YCbCrFormat) -> Self {
+    YCbCr { units, format }
+  }
+
+  pub fn y(&self) -> f64 { self.units[0] }
+  pub fn cb(&self) -> f64 { self.units[1] }
+  pub fn cr(&self) -> f64 { self.units[2] }
+
+  pub fn set_y(&mut self, val: f64) { self.set_code(0, val); }
+  pub fn set_cb(&mut self, val: f64) { self.set_code(1, val); }
+  pub fn set_cr(&mut self, val: f64) { self.set_code(2, val); }
+
+  fn set_code(&mut self, ind: usize, val: f64) {
+    self.units.list[ind].set(val.clamp(0.0, self.format.max_code()));
+  }
+
+  /// Returns the digital encoding of values
+  pub fn format(&self) -> YCbCrFormat {
+    self.format
+  }
+
+  /// Returns same color encoded in another format.
+  /// Colors are not clipped when only range or bit depth is changed
+  pub fn to_format(&self, format: YCbCrFormat) -> YCbCr {
+    let analog = self.format.dequantize(&self.into());
+    let analog = if format.matrix == self.format.matrix {
+      analog
+    } else {
+      from::convert_matrix(&analog, &self.format.matrix, &format.matrix)
+    };
+    let (y, cb, cr) = format.quantize(&analog);
+    let mut units = new_ycbcr_units(y, cb, cr, &format);
+    units.alpha = self.units.alpha.clone();
+    YCbCr::from_units(units, format)
+  }
+
+  /// Returns an iterator over three color units and the possibly alpha value.
+  pub fn iter(&self) -> ColorUnitsIter {
+    ColorUnitsIter::from_units(&self.units)
+  }
+}
+
+//
+//
+//
+// Default
+//
+impl Default for YCbCr {
+  /// Black in the default format, like `Rgb::default()`
+  fn default() -> YCbCr {
+    YCbCr::from_rgb_with_format(&Rgb::default(), YCbCrFormat::default())
+  }
+}
+
+//
+//
+//
+// AsRef<YCbCr>
+//
+impl AsRef<YCbCr> for YCbCr {
+  fn as_ref(&self) -> &YCbCr {
+    self
+  }
+}
+
+impl GetColorUnits for YCbCr {
+  fn get_units(&self) -> &Units {
+    &self.units
+  }
+  fn get_units_mut(&mut self) -> &mut Units {
+    &mut self.units
+  }
+
+  fn same_space(&self, other: &YCbCr) -> bool {
+    self.format == other.format
+  }
+}
@@ -0,0 +1,92 @@
+use crate::{
+  ApproxEq, ColorAlpha, Rgb, YCbCr, YCbCrBitDepth, YCbCrFormat, YCbCrMatrix, YCbCrRange,
+};
+
+fn round(ycbcr: &YCbCr) -> [f64; 3] {
+  [ycbcr.y().round(), ycbcr.cb().round(), ycbcr.cr().round()]
+}
+
+#[test]
+fn ycbcr_from_rgb() {
+  let bt601 = YCbCrFormat::new(YCbCrMatrix::Bt601, YCbCrRange::Limited, YCbCrBitDepth::Eight);
+  let bt2020 = YCbCrFormat::new(YCbCrMatrix::Bt2020, YCbCrRange::Limited, YCbCrBitDepth::Ten);
+  let asserts = [
+    ([255, 0, 0], [63.0, 102.0, 240.0], [81.0, 90.0, 240.0], [76.0, 85.0, 255.0], [294.0, 387.0, 960.0]),
+    ([0, 255, 0], [173.0, 42.0, 26.0], [145.0, 54.0, 34.0], [150.0, 44.0, 21.0], [658.0, 189.0, 100.0]),
+    ([0, 0, 255], [32.0, 240.0, 118.0], [41.0, 240.0, 110.0], [29.0, 255.0, 107.0], [116.0, 960.0, 476.0]),
+    ([255, 255, 255], [235.0, 128.0, 128.0], [235.0, 128.0, 128.0], [255.0, 128.0, 128.0], [940.0, 512.0, 512.0]),
+    ([0, 0, 0], [16.0, 128.0, 128.0], [16.0, 128.0, 128.0], [0.0, 128.0, 128.0], [64.0, 512.0, 512.0]),
+  ];
+  for (rgb_arr, bt709_codes, bt601_codes, jpeg_codes, bt2020_codes) in asserts.iter() {
+    let rgb = Rgb::from(rgb_arr);
+    let formats = [
+      (YCbCrFormat::default(), bt709_codes),
+      (bt601, bt601_codes),
+      (YCbCrFormat::JPEG, jpeg_codes),
+      (bt2020, bt2020_codes),
+    ];
+    for (format, codes) in formats.iter() {
+      let ycbcr = YCbCr::from_rgb_with_format(&rgb, *format);
+      assert_eq!(&round(&ycbcr), *codes);
+      assert_eq!(ycbcr.format(), *format);
+      // chroma of full range is clipped at the largest code value (`255.5` -> `255`)
+      let precision = if format.range == YCbCrRange::Full { 1.0 } else { 0.000_001 };
+      assert!(Rgb::from(&ycbcr).approx_eq_clarify(&rgb, precision));
+    }
+  }
+}
+
+#[test]
+fn ycbcr_to_format() {
+  let rgb = Rgb::new(34.0, 111.0, 235.0, Some(0.4));
+  let ycbcr = YCbCr::from(&rgb);
+  assert_eq!(ycbcr.alpha(), 0.4);
+
+  let jpeg = ycbcr.to_format(YCbCrFormat::JPEG);
+  assert!(jpeg.approx_eq(&YCbCr::from_rgb_with_format(&rgb, YCbCrFormat::JPEG)));
+  assert!(jpeg.to_format(YCbCrFormat::default()).approx_eq(&ycbcr));
+
+  let ten_bit = YCbCrFormat { bit_depth: YCbCrBitDepth::Ten, ..Default::default() };
+  let ycbcr10 = ycbcr.to_format(ten_bit);
+  assert!(approx_codes(&ycbcr10, &[ycbcr.y() * 4.0, ycbcr.cb() * 4.0, ycbcr.cr() * 4.0]));
+  assert!(Rgb::from(&ycbcr10).approx_eq(&rgb));
+}
+
+fn approx_codes(ycbcr: &YCbCr, codes: &[f64; 3]) -> bool {
+  ycbcr.approx_eq(&YCbCr::new_with_format(codes[0], codes[1], codes[2], Some(ycbcr.alpha()), ycbcr.format()))
+}
+
+#[test]
+fn ycbcr_limits() {
+  // super-white and super-black are kept but clipped in Rgb
+  let super_white = YCbCr::new(250.0, 128.0, 128.0, None);
+  assert_eq!(super_white.y(), 250.0);
+  assert_eq!(Into::<[u8; 3]>::into(Rgb::from(&super_white)), [255, 255, 255]);
+  assert_eq!(Into::<[u8; 3]>::into(Rgb::from(YCbCr::new(1.0, 128.0, 128.0, None))), [0, 0, 0]);
+
+  let mut ycbcr = YCbCr::new(300.0, -5.0, 128.0, None);
+  assert_eq!((ycbcr.y(), ycbcr.cb()), (255.0, 0.0));
+  ycbcr.set_cr(1000.0);
+  assert_eq!(ycbcr.cr(), 255.0);
+
+  let hdr = YCbCrFormat::new(YCbCrMatrix::Bt2020, YCbCrRange::Full, YCbCrBitDepth::Ten);
+  let mut ycbcr = YCbCr::new_with_format(1000.0, 512.0, 2000.0, None, hdr);
+  assert_eq!(ycbcr.cr(), 1023.0);
+  ycbcr.set_y(2000.0);
+  assert_eq!(ycbcr.y(), 1023.0);
+}
+
+#[test]
+fn ycbcr_default() {
+  let ycbcr = YCbCr::default();
+  assert_eq!(round(&ycbcr), [16.0, 128.0, 128.0]);
+  assert_eq!(Into::<[u8; 3]>::into(Rgb::from(&ycbcr)), [0, 0, 0]);
+  assert!(Rgb::from(&ycbcr).approx_eq(&Rgb::default()));
+}
+
+#[test]
+fn ycbcr_approx_eq_format() {
+  let ycbcr = YCbCr::new(100.0, 128.0, 128.0, None);
+  assert!(ycbcr.approx_eq(&YCbCr::new_with_format(100.0, 128.0, 128.0, None, YCbCrFormat::default())));
+  assert!(!ycbcr.approx_eq(&YCbCr::new_with_format(100.0, 128.0, 128.0, None, YCbCrFormat::JPEG)));
+}
@@ -0,0 +1,38 @@
+use crate::{ColorAlpha, Rgb};
+use crate::common::f64_round;
+use crate::consts::RGB_UNIT_MAX;
+use crate::converters::{rgb_to_ycocg, rgb_to_ycocg_r, ycocg_r_to_rgb, ycocg_to_rgb};
+
+use super::{new_ycocg_r_units, new_ycocg_units, YCoCg, YCoCgR};
+
+tuples_def!(YCoCg, f32);
+tuples_def!(YCoCg, f64);
+
+tuples_def!(YCoCgR, f32);
+tuples_def!(YCoCgR, f64);
+tuples_def!(YCoCgR, i32);
+
+from_for_some!(Rgb, YCoCg, rgb, {
+  let [r, g, b]: [f64; 3] = rgb.units.as_ratio().into();
+  let (y, co, cg) = rgb_to_ycocg(&(r, g, b));
+  let mut units = new_ycocg_units(y, co, cg);
+  units.alpha.set(rgb.alpha());
+  YCoCg::from_units(units)
+});
+from_for_some!(YCoCg, Rgb, ycocg, {
+  let (r, g, b) = ycocg_to_rgb(&ycocg.into());
+  Rgb::new(r * RGB_UNIT_MAX, g * RGB_UNIT_MAX, b * RGB_UNIT_MAX, Some(ycocg.alpha()))
+});
+
+from_for_some!(Rgb, YCoCgR, rgb, {
+  let u = &rgb.units;
+  let rgb_i = [f64_round(u[0]) as i32, f64_round(u[1]) as i32, f64_round(u[2]) as i32];
+  let [y, co, cg] = rgb_to_ycocg_r(&rgb_i);
+  let mut units = new_ycocg_r_units(y as f64, co as f64, cg as f64);
+  units.alpha.set(rgb.alpha());
+  YCoCgR::from_units(units)
+});
+from_for_some!(YCoCgR, Rgb, ycocg, {
+  let [r, g, b] = ycocg_r_to_rgb(&ycocg.into());
+  Rgb::new(r as f64, g as f64, b as f64, Some(ycocg.alpha()))
+});
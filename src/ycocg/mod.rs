@@ -0,0 +1,197 @@
+use crate::ColorUnitsIter;
+use crate::common::f64_round;
+use crate::units::{Alpha, GetColorUnits, Unit, Units};
+
+#[cfg(test)]
+mod tests;
+
+mod from;
+
+/// The YCoCg color model.
+///
+/// Luma `y`, orange chroma `co` and green chroma `cg` of gamma-encoded RGB ratios.
+/// The transform uses only additions and divisions by powers of two. For the lossless
+/// integer variant see `YCoCgR`.
+///
+/// Ranges:
+/// * y: 0.0 - 1.0
+/// * co: not restricted, -0.5 - 0.5 for colors of sRGB
+/// * cg: not restricted, -0.5 - 0.5 for colors of sRGB
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{Rgb, YCoCg};
+///
+/// let ycocg = YCoCg::from(Rgb::from([255, 0, 0]));
+/// assert_eq!(ycocg, YCoCg::new(0.25, 0.5, -0.25, None));
+/// assert_eq!(Rgb::from(&ycocg).to_css_string(), "rgb(255,0,0)");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct YCoCg {
+  pub(crate) units: Units,
+}
+
+iter_def!(YCoCg);
+
+pub(crate) fn new_ycocg_units(y: f64, co: f64, cg: f64) -> Units {
+  let ul = [Unit::new_ratio(y), Unit::new_unbounded(co), Unit::new_unbounded(cg), Unit::default()];
+  Units { len: 3, list: ul, alpha: Alpha::default() }
+}
+
+impl YCoCg {
+  pub fn new(y: f64, co: f64, cg: f64, a: Option<f64>) -> YCoCg {
+    let mut units = new_ycocg_units(y, co, cg);
+    units.alpha.set_opt(a);
+    units.restrict();
+    YCoCg { units }
+  }
+
+  pub(crate) fn from_units(u: Units) -> Self { YCoCg { units: u } }
+
+  pub fn y(&self) -> f64 { self.units[0] }
+  pub fn co(&self) -> f64 { self.units[1] }
+  pub fn cg(&self) -> f64 { self.units[2] }
+
+  pub fn set_y(&mut self, val: f64) { self.units.list[0].set(val); }
+  pub fn set_co(&mut self, val: f64) { self.units.list[1].set(val); }
+  pub fn set_cg(&mut self, val: f64) { self.units.list[2].set(val); }
+
+  /// Returns an iterator over three color units and the possibly alpha value.
+  pub fn iter(&self) -> ColorUnitsIter {
+    ColorUnitsIter::from_units(&self.units)
+  }
+}
+
+//
+//
+//
+// Default
+//
+impl Default for YCoCg {
+  fn default() -> YCoCg {
+    YCoCg::from_units(new_ycocg_units(0.0, 0.0, 0.0))
+  }
+}
+
+//
+//
+//
+// AsRef<YCoCg>
+//
+impl AsRef<YCoCg> for YCoCg {
+  fn as_ref(&self) -> &YCoCg {
+    self
+  }
+}
+
+impl GetColorUnits for YCoCg {
+  fn get_units(&self) -> &Units {
+    &self.units
+  }
+  fn get_units_mut(&mut self) -> &mut Units {
+    &mut self.units
+  }
+}
+
+/// The YCoCg-R color model: lossless integer variant of `YCoCg`.
+///
+/// Values are integers computed from integer RGB values (`0..255`) with the lifting
+/// scheme, so conversion back to `Rgb` is exact. Chroma values need 9 bits.
+/// Non-integer values are rounded. Values out of range are replaced with a minimum or
+/// maximum value, but combinations which do not correspond to RGB values are still
+/// possible, they are clipped on conversion to `Rgb`.
+///
+/// Ranges:
+/// * y: 0 - 255
+/// * co: -255 - 255
+/// * cg: -255 - 255
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{Rgb, YCoCgR};
+///
+/// let rgb = Rgb::from([34, 111, 235]);
+/// let ycocg: [i32; 3] = YCoCgR::from(&rgb).into();
+/// assert_eq!(ycocg, [122, -201, -23]);
+///
+/// let back: [u8; 3] = Rgb::from(YCoCgR::from(ycocg)).into();
+/// assert_eq!(back, [34, 111, 235]);
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct YCoCgR {
+  pub(crate) units: Units,
+}
+
+iter_def!(YCoCgR);
+
+// largest absolute value of chroma
+const YCOCG_R_CHROMA_MAX: f64 = 255.0;
+
+pub(crate) fn new_ycocg_r_units(y: f64, co: f64, cg: f64) -> Units {
+  let c = |v: f64| Unit::new_unbounded(round_chroma(v));
+  let ul = [Unit::new_rgb(f64_round(y)), c(co), c(cg), Unit::default()];
+  Units { len: 3, list: ul, alpha: Alpha::default() }
+}
+
+fn round_chroma(v: f64) -> f64 {
+  f64_round(v).clamp(-YCOCG_R_CHROMA_MAX, YCOCG_R_CHROMA_MAX)
+}
+
+impl YCoCgR {
+  pub fn new(y: f64, co: f64, cg: f64, a: Option<f64>) -> YCoCgR {
+    let mut units = new_ycocg_r_units(y, co, cg);
+    units.alpha.set_opt(a);
+    units.restrict();
+    YCoCgR { units }
+  }
+
+  pub(crate) fn from_units(u: Units) -> Self { YCoCgR { units: u } }
+
+  pub fn y(&self) -> f64 { self.units[0] }
+  pub fn co(&self) -> f64 { self.units[1] }
+  pub fn cg(&self) -> f64 { self.units[2] }
+
+  pub fn set_y(&mut self, val: f64) { self.units.list[0].set(f64_round(val)); }
+  pub fn set_co(&mut self, val: f64) { self.units.list[1].set(round_chroma(val)); }
+  pub fn set_cg(&mut self, val: f64) { self.units.list[2].set(round_chroma(val)); }
+
+  /// Returns an iterator over three color units and the possibly alpha value.
+  pub fn iter(&self) -> ColorUnitsIter {
+    ColorUnitsIter::from_units(&self.units)
+  }
+}
+
+//
+//
+//
+// Default
+//
+impl Default for YCoCgR {
+  fn default() -> YCoCgR {
+    YCoCgR::from_units(new_ycocg_r_units(0.0, 0.0, 0.0))
+  }
+}
+
+//
+//
+//
+// AsRef<YCoCgR>
+//
+impl AsRef<YCoCgR> for YCoCgR {
+  fn as_ref(&self) -> &YCoCgR {
+    self
+  }
+}
+
+impl GetColorUnits for YCoCgR {
+  fn get_units(&self) -> &Units {
+    &self.units
+  }
+  fn get_units_mut(&mut self) -> &mut Units {
+    &mut self.units
+  }
+}
@@ -0,0 +1,69 @@
+use crate::{ApproxEq, ColorAlpha, Rgb, YCoCg, YCoCgR};
+
+#[test]
+fn ycocg_from_rgb() {
+  let asserts = [
+    ([255, 0, 0], (0.25, 0.5, -0.25)),
+    ([0, 255, 0], (0.5, 0.0, 0.5)),
+    ([0, 0, 255], (0.25, -0.5, -0.25)),
+    ([255, 255, 255], (1.0, 0.0, 0.0)),
+    ([0, 0, 0], (0.0, 0.0, 0.0)),
+  ];
+  for (rgb_arr, ycocg_t) in asserts.iter() {
+    let rgb = Rgb::from(rgb_arr);
+    let ycocg = YCoCg::from(&rgb);
+    assert!(ycocg.approx_eq_clarify(&YCoCg::from(ycocg_t), 1e-12));
+    assert!(Rgb::from(&ycocg).approx_eq_clarify(&rgb, 1e-9));
+  }
+
+  let ycocg = YCoCg::from(Rgb::new(34.0, 111.0, 235.0, Some(0.3)));
+  assert_eq!(ycocg.alpha(), 0.3);
+  let mut ycocg = YCoCg::new(0.5, 0.0, 0.0, None);
+  ycocg.set_co(0.1);
+  ycocg.set_cg(-0.1);
+  assert_eq!((ycocg.y(), ycocg.co(), ycocg.cg()), (0.5, 0.1, -0.1));
+}
+
+#[test]
+fn ycocg_r_from_rgb() {
+  let asserts = [
+    ([255, 0, 0], [63, 255, -127]),
+    ([0, 255, 0], [127, 0, 255]),
+    ([0, 0, 255], [63, -255, -127]),
+    ([255, 255, 255], [255, 0, 0]),
+    ([0, 0, 0], [0, 0, 0]),
+    ([34, 111, 235], [122, -201, -23]),
+  ];
+  for (rgb_arr, ycocg_arr) in asserts.iter() {
+    let rgb = Rgb::from(rgb_arr);
+    let ycocg = YCoCgR::from(&rgb);
+    assert_eq!(Into::<[i32; 3]>::into(&ycocg), *ycocg_arr);
+    assert_eq!(Rgb::from(&ycocg), rgb);
+  }
+
+  // lossless for all RGB values
+  for r in (0..=255).step_by(3) {
+    for g in (0..=255).step_by(5) {
+      for b in (0..=255).step_by(7) {
+        let rgb = Rgb::new(r as f64, g as f64, b as f64, Some(0.5));
+        assert_eq!(Rgb::from(YCoCgR::from(&rgb)), rgb);
+      }
+    }
+  }
+}
+
+#[test]
+fn ycocg_r_values() {
+  let ycocg = YCoCgR::new(12.4, 300.0, -99.6, None);
+  assert_eq!((ycocg.y(), ycocg.co(), ycocg.cg()), (12.0, 255.0, -100.0));
+
+  let mut ycocg = YCoCgR::default();
+  ycocg.set_y(400.0);
+  ycocg.set_co(-0.6);
+  ycocg.set_cg(-1000.0);
+  assert_eq!((ycocg.y(), ycocg.co(), ycocg.cg()), (255.0, -1.0, -255.0));
+
+  // rounds fractional RGB values
+  let ycocg = YCoCgR::from(Rgb::new(254.6, 0.4, 0.0, None));
+  assert_eq!(Into::<[i32; 3]>::into(ycocg), [63, 255, -127]);
+}
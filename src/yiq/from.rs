@@ -0,0 +1,20 @@
+use crate::{ColorAlpha, Rgb};
+use crate::consts::RGB_UNIT_MAX;
+use crate::converters::{rgb_to_yiq, yiq_to_rgb};
+
+use super::{new_yiq_units, Yiq};
+
+tuples_def!(Yiq, f32);
+tuples_def!(Yiq, f64);
+
+from_for_some!(Rgb, Yiq, rgb, {
+  let [r, g, b]: [f64; 3] = rgb.units.as_ratio().into();
+  let (y, i, q) = rgb_to_yiq(&(r, g, b));
+  let mut units = new_yiq_units(y, i, q);
+  units.alpha.set(rgb.alpha());
+  Yiq::from_units(units)
+});
+from_for_some!(Yiq, Rgb, yiq, {
+  let (r, g, b) = yiq_to_rgb(&yiq.into());
+  Rgb::new(r * RGB_UNIT_MAX, g * RGB_UNIT_MAX, b * RGB_UNIT_MAX, Some(yiq.alpha()))
+});
@@ -0,0 +1,98 @@
+use crate::ColorUnitsIter;
+use crate::units::{Alpha, GetColorUnits, Unit, Units};
+
+#[cfg(test)]
+mod tests;
+
+mod from;
+
+/// The YIQ color model of NTSC analog television.
+///
+/// Luma `y` (BT.601 weights) of gamma-encoded RGB, in-phase `i` and quadrature `q`
+/// chrominance. IQ axes are UV axes of `Yuv` rotated by 33°.
+///
+/// Ranges:
+/// * y: 0.0 - 1.0
+/// * i: not restricted, -0.5959 - 0.5959 for colors of sRGB
+/// * q: not restricted, -0.5227 - 0.5227 for colors of sRGB
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{ApproxEq, Rgb, Yiq};
+///
+/// let yiq = Yiq::from(Rgb::from([255, 0, 0]));
+/// assert!(yiq.approx_eq_clarify(&Yiq::new(0.299, 0.5959, 0.2115, None), 0.0001));
+///
+/// // NTSC-style desaturation of in-phase component
+/// let mut yiq = Yiq::from(Rgb::from([34, 111, 235]));
+/// yiq.set_i(yiq.i() * 0.5);
+/// assert_eq!(Rgb::from(&yiq).to_css_string(), "rgb(75,99,188)");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Yiq {
+  pub(crate) units: Units,
+}
+
+iter_def!(Yiq);
+
+pub(crate) fn new_yiq_units(y: f64, i: f64, q: f64) -> Units {
+  let ul = [Unit::new_ratio(y), Unit::new_unbounded(i), Unit::new_unbounded(q), Unit::default()];
+  Units { len: 3, list: ul, alpha: Alpha::default() }
+}
+
+impl Yiq {
+  pub fn new(y: f64, i: f64, q: f64, a: Option<f64>) -> Yiq {
+    let mut units = new_yiq_units(y, i, q);
+    units.alpha.set_opt(a);
+    units.restrict();
+    Yiq { units }
+  }
+
+  pub(crate) fn from_units(u: Units) -> Self { Yiq { units: u } }
+
+  pub fn y(&self) -> f64 { self.units[0] }
+  pub fn i(&self) -> f64 { self.units[1] }
+  pub fn q(&self) -> f64 { self.units[2] }
+
+  pub fn set_y(&mut self, val: f64) { self.units.list[0].set(val); }
+  pub fn set_i(&mut self, val: f64) { self.units.list[1].set(val); }
+  pub fn set_q(&mut self, val: f64) { self.units.list[2].set(val); }
+
+  /// Returns an iterator over three color units and the possibly alpha value.
+  pub fn iter(&self) -> ColorUnitsIter {
+    ColorUnitsIter::from_units(&self.units)
+  }
+}
+
+//
+//
+//
+// Default
+//
+impl Default for Yiq {
+  fn default() -> Yiq {
+    Yiq::from_units(new_yiq_units(0.0, 0.0, 0.0))
+  }
+}
+
+//
+//
+//
+// AsRef<Yiq>
+//
+impl AsRef<Yiq> for Yiq {
+  fn as_ref(&self) -> &Yiq {
+    self
+  }
+}
+
+impl GetColorUnits for Yiq {
+  fn get_units(&self) -> &Units {
+    &self.units
+  }
+  fn get_units_mut(&mut self) -> &mut Units {
+    &mut self.units
+  }
+}
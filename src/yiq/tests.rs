@@ -0,0 +1,36 @@
+use crate::{ApproxEq, ColorAlpha, Rgb, Yiq, Yuv};
+
+#[test]
+fn yiq_from_rgb() {
+  let asserts = [
+    ([255, 0, 0], (0.299, 0.595_919, 0.211_553)),
+    ([0, 255, 0], (0.587, -0.274_578, -0.522_742)),
+    ([0, 0, 255], (0.114, -0.321_342, 0.311_189)),
+    ([255, 255, 255], (1.0, 0.0, 0.0)),
+    ([0, 0, 0], (0.0, 0.0, 0.0)),
+  ];
+  for (rgb_arr, yiq_t) in asserts.iter() {
+    let rgb = Rgb::from(rgb_arr);
+    let yiq = Yiq::from(&rgb);
+    assert!(yiq.approx_eq_clarify(&Yiq::from(yiq_t), 0.000_001));
+    assert!(Rgb::from(&yiq).approx_eq_clarify(&rgb, 0.000_001));
+  }
+}
+
+#[test]
+fn yiq_conversions() {
+  let rgb = Rgb::new(34.0, 111.0, 235.0, Some(0.6));
+  let yiq = Yiq::from(&rgb);
+  assert_eq!(yiq.alpha(), 0.6);
+
+  let yuv = Yuv::from(&yiq);
+  assert!(yuv.approx_eq(&Yuv::from(&rgb)));
+  assert!(Yiq::from(&yuv).approx_eq(&yiq));
+
+  let mut yiq = Yiq::new(2.0, -3.0, 0.1, None);
+  assert_eq!((yiq.y(), yiq.i(), yiq.q()), (1.0, -3.0, 0.1));
+  yiq.set_y(0.5);
+  yiq.set_i(0.0);
+  yiq.set_q(0.0);
+  assert_eq!(Rgb::from(&yiq).to_css_string(), "rgb(128,128,128)");
+}
@@ -0,0 +1,35 @@
+use crate::{ColorAlpha, Rgb, Yiq};
+use crate::consts::RGB_UNIT_MAX;
+use crate::converters::{rgb_to_yiq, rgb_to_yuv, yiq_to_rgb, yuv_to_rgb};
+use crate::yiq::new_yiq_units;
+
+use super::{new_yuv_units, Yuv};
+
+tuples_def!(Yuv, f32);
+tuples_def!(Yuv, f64);
+
+from_for_some!(Rgb, Yuv, rgb, {
+  let [r, g, b]: [f64; 3] = rgb.units.as_ratio().into();
+  let (y, u, v) = rgb_to_yuv(&(r, g, b));
+  let mut units = new_yuv_units(y, u, v);
+  units.alpha.set(rgb.alpha());
+  Yuv::from_units(units)
+});
+from_for_some!(Yuv, Rgb, yuv, {
+  let (r, g, b) = yuv_to_rgb(&yuv.into());
+  Rgb::new(r * RGB_UNIT_MAX, g * RGB_UNIT_MAX, b * RGB_UNIT_MAX, Some(yuv.alpha()))
+});
+
+// values are not clipped, only rotated
+from_for_some!(Yiq, Yuv, yiq, {
+  let (y, u, v) = rgb_to_yuv(&yiq_to_rgb(&yiq.into()));
+  let mut units = new_yuv_units(y, u, v);
+  units.alpha.set(yiq.alpha());
+  Yuv::from_units(units)
+});
+from_for_some!(Yuv, Yiq, yuv, {
+  let (y, i, q) = rgb_to_yiq(&yuv_to_rgb(&yuv.into()));
+  let mut units = new_yiq_units(y, i, q);
+  units.alpha.set(yuv.alpha());
+  Yiq::from_units(units)
+});
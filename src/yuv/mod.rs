@@ -0,0 +1,96 @@
+use crate::ColorUnitsIter;
+use crate::units::{Alpha, GetColorUnits, Unit, Units};
+
+#[cfg(test)]
+mod tests;
+
+mod from;
+
+/// The YUV color model of PAL analog television.
+///
+/// Luma `y` (BT.601 weights) of gamma-encoded RGB and scaled color differences
+/// `u` (blue - luma) and `v` (red - luma). For digital encodings see `YCbCr`.
+///
+/// Ranges:
+/// * y: 0.0 - 1.0
+/// * u: not restricted, -0.436 - 0.436 for colors of sRGB
+/// * v: not restricted, -0.615 - 0.615 for colors of sRGB
+/// * alpha: 0.0 - 1.0
+///
+/// # Example
+/// ```
+/// use colorsys::{ApproxEq, Rgb, Yuv};
+///
+/// let yuv = Yuv::from(Rgb::from([0, 0, 255]));
+/// assert!(yuv.approx_eq_clarify(&Yuv::new(0.114, 0.436, -0.1, None), 0.0001));
+///
+/// let gray = Yuv::new(0.5, 0.0, 0.0, None);
+/// assert_eq!(Rgb::from(&gray).to_css_string(), "rgb(128,128,128)");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Yuv {
+  pub(crate) units: Units,
+}
+
+iter_def!(Yuv);
+
+pub(crate) fn new_yuv_units(y: f64, u: f64, v: f64) -> Units {
+  let ul = [Unit::new_ratio(y), Unit::new_unbounded(u), Unit::new_unbounded(v), Unit::default()];
+  Units { len: 3, list: ul, alpha: Alpha::default() }
+}
+
+impl Yuv {
+  pub fn new(y: f64, u: f64, v: f64, a: Option<f64>) -> Yuv {
+    let mut units = new_yuv_units(y, u, v);
+    units.alpha.set_opt(a);
+    units.restrict();
+    Yuv { units }
+  }
+
+  pub(crate) fn from_units(u: Units) -> Self { Yuv { units: u } }
+
+  pub fn y(&self) -> f64 { self.units[0] }
+  pub fn u(&self) -> f64 { self.units[1] }
+  pub fn v(&self) -> f64 { self.units[2] }
+
+  pub fn set_y(&mut self, val: f64) { self.units.list[0].set(val); }
+  pub fn set_u(&mut self, val: f64) { self.units.list[1].set(val); }
+  pub fn set_v(&mut self, val: f64) { self.units.list[2].set(val); }
+
+  /// Returns an iterator over three color units and the possibly alpha value.
+  pub fn iter(&self) -> ColorUnitsIter {
+    ColorUnitsIter::from_units(&self.units)
+  }
+}
+
+//
+//
+//
+// Default
+//
+impl Default for Yuv {
+  fn default() -> Yuv {
+    Yuv::from_units(new_yuv_units(0.0, 0.0, 0.0))
+  }
+}
+
+//
+//
+//
+// AsRef<Yuv>
+//
+impl AsRef<Yuv> for Yuv {
+  fn as_ref(&self) -> &Yuv {
+    self
+  }
+}
+
+impl GetColorUnits for Yuv {
+  fn get_units(&self) -> &Units {
+    &self.units
+  }
+  fn get_units_mut(&mut self) -> &mut Units {
+    &mut self.units
+  }
+}
@@ -0,0 +1,33 @@
+use crate::{ApproxEq, ColorAlpha, Rgb, Yuv};
+
+#[test]
+fn yuv_from_rgb() {
+  let asserts = [
+    ([255, 0, 0], (0.299, -0.147_138, 0.615)),
+    ([0, 255, 0], (0.587, -0.288_862, -0.514_986)),
+    ([0, 0, 255], (0.114, 0.436, -0.100_014)),
+    ([255, 255, 255], (1.0, 0.0, 0.0)),
+    ([0, 0, 0], (0.0, 0.0, 0.0)),
+  ];
+  for (rgb_arr, yuv_t) in asserts.iter() {
+    let rgb = Rgb::from(rgb_arr);
+    let yuv = Yuv::from(&rgb);
+    assert!(yuv.approx_eq_clarify(&Yuv::from(yuv_t), 0.000_001));
+    assert!(Rgb::from(&yuv).approx_eq_clarify(&rgb, 0.000_001));
+  }
+}
+
+#[test]
+fn yuv_conversions() {
+  let yuv = Yuv::from(Rgb::new(200.0, 50.0, 100.0, Some(0.2)));
+  assert_eq!(yuv.alpha(), 0.2);
+
+  // out of RGB gamut values are clipped
+  let yuv = Yuv::new(0.9, 0.4, 0.4, None);
+  assert_eq!(Rgb::from(&yuv).to_css_string(), "rgb(255,130,255)");
+
+  let mut yuv = Yuv::default();
+  yuv.set_u(-0.2);
+  yuv.set_v(-1.0);
+  assert_eq!((yuv.y(), yuv.u(), yuv.v()), (0.0, -0.2, -1.0));
+}